@@ -0,0 +1,24 @@
+extern crate chess_engine;
+use chess_engine::*;
+use std::{env, fs};
+
+fn main() -> Result<(), String> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        return Err(format!(
+            "usage: {} <pgn file> <book file> [max plies]",
+            args[0]
+        ));
+    }
+
+    let pgn = fs::read_to_string(&args[1]).map_err(|e| e.to_string())?;
+    let max_plies = match args.get(3) {
+        Some(plies) => plies.parse().map_err(|_| "max plies must be a number")?,
+        None => 20,
+    };
+
+    let book = OpeningBook::from_pgn(&pgn, max_plies)?;
+    fs::write(&args[2], book.to_bytes()).map_err(|e| e.to_string())?;
+    println!("Wrote {} book moves to {}", book.len(), args[2]);
+    Ok(())
+}
@@ -1,5 +1,5 @@
-use chess_gui::{run, best_move, Board};
+use chess_gui::{run, best_move, Variant};
 
 fn main() -> iced::Result {
-    run(best_move, Board::horde())
+    run(best_move, Variant::Horde.starting_board())
 }
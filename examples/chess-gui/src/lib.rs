@@ -4,7 +4,7 @@ use lazy_static::lazy_static;
 
 use std::sync::Mutex;
 use chess_engine::*;
-pub use chess_engine::Board;
+pub use chess_engine::{Board, Variant};
 
 pub fn run(get_cpu_move: fn(&Board) -> Move, starting_board: Board) -> iced::Result {
     {
@@ -204,8 +204,9 @@ impl Sandbox for ChessBoard {
 
     fn title(&self) -> String {
         match self.result {
-            GameResult::Victory(color) => format!("{} wins", color),
-            GameResult::Stalemate => format!("Stalemate"),
+            GameResult::Victory(color, _) => format!("{} wins", color),
+            GameResult::Draw(DrawReason::Stalemate) => format!("Stalemate"),
+            GameResult::Draw(_) => format!("Draw"),
             GameResult::IllegalMove(m) => format!("Illegal move by {}, '{}'", self.board.get_current_player_color(), m),
             _ => String::from("Chess")
         }
@@ -213,7 +214,7 @@ impl Sandbox for ChessBoard {
 
     fn update(&mut self, message: Message) {
         match self.result {
-            GameResult::Victory(_) | GameResult::Stalemate => {
+            GameResult::Victory(_, _) | GameResult::Draw(_) => {
                 self.board = self.starting_board;
                 self.result = GameResult::Continuing(self.board);
             },
@@ -238,12 +239,12 @@ impl Sandbox for ChessBoard {
                                     GameResult::Continuing(board) => {
                                         board
                                     }
-                                    GameResult::Victory(color) => {
-                                        self.result = GameResult::Victory(color);
+                                    result @ GameResult::Victory(_, _) => {
+                                        self.result = result;
                                         self.starting_board
                                     },
-                                    GameResult::Stalemate => {
-                                        self.result = GameResult::Stalemate;
+                                    result @ GameResult::Draw(_) => {
+                                        self.result = result;
                                         self.starting_board
                                     },
                                     GameResult::IllegalMove(m) => {
@@ -252,12 +253,12 @@ impl Sandbox for ChessBoard {
                                     },
                                 }
                             },
-                            GameResult::Victory(color) => {
-                                self.result = GameResult::Victory(color);
+                            result @ GameResult::Victory(_, _) => {
+                                self.result = result;
                                 self.starting_board
                             },
-                            GameResult::Stalemate => {
-                                self.result = GameResult::Stalemate;
+                            result @ GameResult::Draw(_) => {
+                                self.result = result;
                                 self.starting_board
                             },
                             GameResult::IllegalMove(_) => {
@@ -45,6 +45,9 @@ fn get_cpu_move(b: &Board, best: bool) -> Move {
         Move::QueenSideCastle => {
             println!("castle queenside")
         }
+        Move::Drop(piece, to) => {
+            println!("drop {} at {}", piece.get_name(), to)
+        }
         Move::Resign => println!("resign"),
     }
 
@@ -98,7 +101,7 @@ fn main() -> Result<(), String> {
                 history.push(m);
             }
 
-            GameResult::Victory(winner) => {
+            GameResult::Victory(winner, _) => {
                 println!("{}", b);
                 println!("{} loses. {} is victorious.", !winner, winner);
                 break;
@@ -108,7 +111,7 @@ fn main() -> Result<(), String> {
                 eprintln!("{} is an illegal move.", x);
             }
 
-            GameResult::Stalemate => {
+            GameResult::Draw(_) => {
                 println!("Drawn game.");
                 break;
             }
@@ -0,0 +1,39 @@
+extern crate chess_engine;
+use chess_engine::*;
+use std::{env, fs};
+
+fn main() -> Result<(), String> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        return Err(format!(
+            "usage: {} <positions file> <parameter file> [passes]",
+            args[0]
+        ));
+    }
+
+    let text = fs::read_to_string(&args[1]).map_err(|e| e.to_string())?;
+    let positions = parse_tuning_positions(&text)?;
+    let passes = match args.get(3) {
+        Some(passes) => passes.parse().map_err(|_| "passes must be a number")?,
+        None => 100,
+    };
+    println!("Loaded {} positions", positions.len());
+
+    let mut tuner = Tuner::new(positions, DEFAULT_EVAL_PARAMS);
+    println!("Initial error: {}", tuner.get_error());
+    let mut best = tuner.tune_scaling();
+    println!("Error after scaling: {}", best);
+    println!("Scaling constant: {}", tuner.get_scaling());
+    for pass in 1..=passes {
+        let error = tuner.tune(1);
+        println!("Pass {}: error {}", pass, error);
+
+        // Save after every pass so that tuning can be stopped at any time
+        fs::write(&args[2], tuner.get_params().to_string()).map_err(|e| e.to_string())?;
+        if error >= best {
+            break;
+        }
+        best = error;
+    }
+    Ok(())
+}
@@ -2,7 +2,9 @@ extern crate chess_engine;
 use chess_engine::*;
 use std::{
     convert::TryFrom,
+    env, fs,
     io::{stdin, stdout, Write},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 fn input(prompt: impl std::fmt::Display) -> String {
@@ -15,14 +17,53 @@ fn input(prompt: impl std::fmt::Display) -> String {
     s
 }
 
-fn get_cpu_move(b: &Board, best: bool) -> Move {
-    let (m, count, _) = if best {
-        b.get_best_next_move(4)
+fn load_params(path: &str) -> Result<EvalParams, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    parse_eval_params(&text)
+}
+
+fn load_book(path: &str) -> Result<OpeningBook, String> {
+    let bytes = fs::read(path).map_err(|e| e.to_string())?;
+    OpeningBook::from_bytes(&bytes)
+}
+
+fn get_random() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+fn get_cpu_move(
+    b: &Board,
+    params: &EvalParams,
+    pawns: &PawnHashTable,
+    book: &OpeningBook,
+    best: bool,
+) -> Move {
+    let book_move = if best {
+        book.get_weighted_move(b, get_random())
     } else {
-        b.get_worst_next_move(4)
+        None
+    };
+
+    let m = match book_move {
+        Some(m) => {
+            print!("CPU found a book move and chose to ");
+            m
+        }
+        None => {
+            let evaluator = Evaluator::new(*b, params, pawns);
+            let (m, count, _) = if best {
+                evaluator.get_best_next_move(4)
+            } else {
+                evaluator.get_worst_next_move(4)
+            };
+            print!("CPU evaluated {} moves before choosing to ", count);
+            m
+        }
     };
 
-    print!("CPU evaluated {} moves before choosing to ", count);
     match m {
         Move::Piece(from, to) | Move::Promotion(from, to, _) => {
             match (b.get_piece(from), b.get_piece(to)) {
@@ -45,6 +86,9 @@ fn get_cpu_move(b: &Board, best: bool) -> Move {
         Move::QueenSideCastle => {
             println!("castle queenside")
         }
+        Move::Drop(piece, to) => {
+            println!("drop {} at {}", piece.get_name(), to)
+        }
         Move::Resign => println!("resign"),
     }
 
@@ -53,6 +97,12 @@ fn get_cpu_move(b: &Board, best: bool) -> Move {
 
 fn main() -> Result<(), String> {
     let mut b = Board::default();
+    let mut params = match env::args().nth(1) {
+        Some(path) => load_params(&path)?,
+        None => DEFAULT_EVAL_PARAMS,
+    };
+    let pawns = PawnHashTable::default();
+    let mut book = OpeningBook::default();
 
     println!("{}", b);
     let mut history = vec![];
@@ -63,12 +113,35 @@ fn main() -> Result<(), String> {
 
         let m = if s.is_empty() {
             println!("Waiting for CPU to choose best move...");
-            get_cpu_move(&b, true)
+            get_cpu_move(&b, &params, &pawns, &book, true)
         } else if s == "worst" {
             println!("Waiting for CPU to choose worst move...");
-            get_cpu_move(&b, false)
+            get_cpu_move(&b, &params, &pawns, &book, false)
         } else if s == "rate" {
             continue;
+        } else if s == "eval" {
+            println!("{}", params.trace(&b));
+            continue;
+        } else if let Some(path) = s.strip_prefix("load ") {
+            match load_params(path.trim()) {
+                Ok(loaded) => {
+                    params = loaded;
+                    // The cached pawn scores belong to the old parameters
+                    pawns.clear();
+                    println!("Loaded evaluation parameters from {}", path.trim());
+                }
+                Err(e) => eprintln!("{}", e),
+            }
+            continue;
+        } else if let Some(path) = s.strip_prefix("book ") {
+            match load_book(path.trim()) {
+                Ok(loaded) => {
+                    book = loaded;
+                    println!("Loaded {} book moves from {}", book.len(), path.trim());
+                }
+                Err(e) => eprintln!("{}", e),
+            }
+            continue;
         } else if s == "pass" {
             b = b.change_turn();
             continue;
@@ -98,7 +171,7 @@ fn main() -> Result<(), String> {
                 history.push(m);
             }
 
-            GameResult::Victory(winner) => {
+            GameResult::Victory(winner, _) => {
                 println!("{}", b);
                 println!("{} loses. {} is victorious.", !winner, winner);
                 break;
@@ -108,7 +181,7 @@ fn main() -> Result<(), String> {
                 eprintln!("{} is an illegal move.", x);
             }
 
-            GameResult::Stalemate => {
+            GameResult::Draw(_) => {
                 println!("Drawn game.");
                 break;
             }
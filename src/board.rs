@@ -1,4 +1,5 @@
 use super::*;
+use crate::pawns::get_default_pawn_entry;
 
 use core::cmp::Ordering;
 
@@ -56,6 +57,23 @@ impl BoardBuilder {
         self
     }
 
+    /// Place a piece that was promoted from a pawn. In Crazyhouse,
+    /// it reverts to a pawn when it is captured.
+    pub fn promoted_piece(mut self, piece: Piece) -> Self {
+        self = self.piece(piece);
+        self.board.promoted |= Board::square_bit(piece.get_pos());
+        self
+    }
+
+    /// Add a piece to the pocket of the piece's color.
+    pub fn pocket(mut self, piece: Piece) -> Self {
+        match piece.get_color() {
+            WHITE => self.board.white_pocket.add(piece),
+            BLACK => self.board.black_pocket.add(piece),
+        }
+        self
+    }
+
     pub fn enable_castling(mut self) -> Self {
         self.board.black_castling_rights.enable_all();
         self.board.white_castling_rights.enable_all();
@@ -110,6 +128,11 @@ impl BoardBuilder {
         self
     }
 
+    pub fn set_variant(mut self, variant: Variant) -> Self {
+        self.board.variant = variant;
+        self
+    }
+
     pub fn build(self) -> Board {
         self.board
     }
@@ -202,24 +225,38 @@ pub struct Board {
     black_castling_rights: CastlingRights,
 
     turn: Color,
+
+    variant: Variant,
+    white_checks: u8,
+    black_checks: u8,
+
+    white_pocket: Pocket,
+    black_pocket: Pocket,
+    promoted: u64,
 }
 
 impl Evaluate for Board {
     #[inline]
     fn value_for(&self, ally_color: Color) -> f64 {
-        self.squares
-            .iter()
-            .map(|square| match square.get_piece() {
-                Some(piece) => {
-                    if piece.get_color() == ally_color {
-                        piece.get_weighted_value()
-                    } else {
-                        -piece.get_weighted_value()
-                    }
-                }
-                None => 0.0,
-            })
-            .sum()
+        let pawns = get_default_pawn_entry(self);
+        let value = DEFAULT_EVAL_PARAMS.evaluate_with_pawns(self, ally_color, &pawns);
+
+        if self.variant.has_forced_captures() {
+            // Losing material is the goal in Antichess
+            -value
+        } else {
+            value
+        }
+    }
+
+    fn get_terminal_value_for(&self, color: Color) -> f64 {
+        match self.get_variant_result() {
+            Some(GameResult::Victory(winner, _)) if winner == color => 999999.0,
+            Some(GameResult::Victory(_, _)) => -999999.0,
+            Some(_) => 0.0,
+            None if self.turn == color => -999999.0,
+            None => 999999.0,
+        }
     }
 
     #[inline]
@@ -227,6 +264,11 @@ impl Evaluate for Board {
         self.turn
     }
 
+    #[inline]
+    fn get_eval_hash_key(&self) -> Option<u64> {
+        Some(self.get_hash_key())
+    }
+
     #[inline]
     fn apply_eval_move(&self, m: Move) -> Self {
         self.apply_move(m).change_turn()
@@ -234,14 +276,16 @@ impl Evaluate for Board {
 
     #[inline]
     fn get_legal_moves(&self) -> Vec<Move> {
-        let mut result = vec![];
+        if self.get_variant_result().is_some() {
+            return vec![];
+        }
+
         let color = self.get_current_player_color();
-        for square in &self.squares {
-            if let Some(piece) = square.get_piece() {
-                if piece.get_color() == color {
-                    result.extend(piece.get_legal_moves(self))
-                }
-            }
+        let mut result = self.get_legal_piece_moves(color);
+        result.extend(self.get_legal_drops(color));
+
+        if self.variant.has_forced_captures() && result.iter().any(|m| self.is_capture(*m)) {
+            result.retain(|m| self.is_capture(*m));
         }
 
         result
@@ -314,6 +358,15 @@ impl core::fmt::Display for Board {
                 write!(f, " {} to move", self.turn)?;
             } else if row == 4 {
                 write!(f, " [{}]", rating_bar)?;
+            } else if (row == 5 || row == 6) && self.variant.allows_drops() {
+                let color = if row == 5 { WHITE } else { BLACK };
+                let pocket = self.get_pocket(color);
+                write!(f, " {} pocket: ", color)?;
+                for piece in pocket.get_pieces(color) {
+                    for _ in 0..pocket.count(piece) {
+                        write!(f, "{}", piece)?;
+                    }
+                }
             }
             square_color = !square_color;
         }
@@ -325,16 +378,22 @@ impl core::fmt::Display for Board {
 impl Board {
     /// Create the default board for the Horde variant
     pub fn horde() -> Self {
-        BoardBuilder::from(Board::default())
-            .row(Piece::Pawn(WHITE, A1))
-            .row(Piece::Pawn(WHITE, A2))
-            .row(Piece::Pawn(WHITE, A3))
-            .row(Piece::Pawn(WHITE, A4))
-            .piece(Piece::Pawn(WHITE, F5))
-            .piece(Piece::Pawn(WHITE, G5))
-            .piece(Piece::Pawn(WHITE, B5))
-            .piece(Piece::Pawn(WHITE, C5))
-            .build()
+        Variant::Horde.starting_board()
+    }
+
+    /// Create the default board for the King of the Hill variant
+    pub fn king_of_the_hill() -> Self {
+        Variant::KingOfTheHill.starting_board()
+    }
+
+    /// Create the default board for the Three-check variant
+    pub fn three_check() -> Self {
+        Variant::ThreeCheck.starting_board()
+    }
+
+    /// Create the default board for the Racing Kings variant
+    pub fn racing_kings() -> Self {
+        Variant::RacingKings.starting_board()
     }
 
     pub fn empty() -> Self {
@@ -346,6 +405,14 @@ impl Board {
             black_castling_rights: CastlingRights::default(),
 
             turn: WHITE,
+
+            variant: Variant::Standard,
+            white_checks: 0,
+            black_checks: 0,
+
+            white_pocket: Pocket::default(),
+            black_pocket: Pocket::default(),
+            promoted: 0,
         }
     }
 
@@ -388,12 +455,94 @@ impl Board {
         white + &black
     }
 
+    /// Get a hash of the position, for recognising boards that were
+    /// already searched.
+    ///
+    /// This is the Polyglot key, mixed with the variant, the checks
+    /// given, the pockets, and which pieces were promoted, so that boards
+    /// which play differently never share a key on purpose.
+    pub fn get_hash_key(&self) -> u64 {
+        let pocket_pieces = [
+            Piece::Queen(WHITE, A1),
+            Piece::Rook(WHITE, A1),
+            Piece::Bishop(WHITE, A1),
+            Piece::Knight(WHITE, A1),
+            Piece::Pawn(WHITE, A1),
+        ];
+        let mut pockets = 0;
+        for piece in &pocket_pieces {
+            for pocket in &[self.white_pocket, self.black_pocket] {
+                pockets = (pockets << 6) | pocket.count(*piece) as u64;
+            }
+        }
+
+        let mut extra = 0;
+        for value in &[
+            self.variant as u64,
+            ((self.white_checks as u64) << 8) | self.black_checks as u64,
+            pockets,
+            self.promoted,
+        ] {
+            // Mix in every value with the SplitMix64 finalizer
+            let mut x = (extra ^ value).wrapping_add(0x9E37_79B9_7F4A_7C15);
+            x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            extra = x ^ (x >> 31);
+        }
+        get_polyglot_key(self) ^ extra
+    }
+
     /// Get the color of the current player
     #[inline]
     pub fn get_turn_color(&self) -> Color {
         self.turn
     }
 
+    /// Get the variant this board is played under
+    #[inline]
+    pub fn get_variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Get the number of times a player has put their opponent in check.
+    ///
+    /// Checks are only counted for variants where they matter, such as Three-check.
+    #[inline]
+    pub fn get_checks_given(&self, color: Color) -> u8 {
+        match color {
+            WHITE => self.white_checks,
+            BLACK => self.black_checks,
+        }
+    }
+
+    /// Get the pieces a player holds in their pocket in Crazyhouse.
+    #[inline]
+    pub fn get_pocket(&self, color: Color) -> Pocket {
+        match color {
+            WHITE => self.white_pocket,
+            BLACK => self.black_pocket,
+        }
+    }
+
+    #[inline]
+    fn get_pocket_mut(&mut self, color: Color) -> &mut Pocket {
+        match color {
+            WHITE => &mut self.white_pocket,
+            BLACK => &mut self.black_pocket,
+        }
+    }
+
+    /// Was the piece on a square promoted from a pawn?
+    #[inline]
+    pub fn is_promoted(&self, pos: Position) -> bool {
+        pos.is_on_board() && self.promoted & Self::square_bit(pos) != 0
+    }
+
+    #[inline]
+    fn square_bit(pos: Position) -> u64 {
+        1 << ((7 - pos.get_row()) * 8 + pos.get_col())
+    }
+
     /// Get the position of the En-Passant square
     pub fn get_en_passant(&self) -> Option<Position> {
         self.en_passant
@@ -435,6 +584,41 @@ impl Board {
         result
     }
 
+    /// Get the mirror image of this board with the colors swapped.
+    ///
+    /// Every piece moves to the same square on the other side of the
+    /// board and changes color, the players trade their castling rights,
+    /// checks, and pockets, and it becomes the other player's turn. The
+    /// resulting position is the same for the opposite player, so a fair
+    /// evaluation scores it the same for them.
+    pub fn flip_colors(&self) -> Self {
+        let mirror = |pos: Position| Position::new(7 - pos.get_row(), pos.get_col());
+
+        let mut result = *self;
+        result.squares = [EMPTY_SQUARE; 64];
+        for square in &self.squares {
+            if let Some(piece) = square.get_piece() {
+                result.add_piece(
+                    piece
+                        .with_color(!piece.get_color())
+                        .move_to(mirror(piece.get_pos())),
+                );
+            }
+        }
+
+        // Each byte of the bitmask is a rank
+        result.promoted = self.promoted.swap_bytes();
+        result.en_passant = self.en_passant.map(mirror);
+        result.white_castling_rights = self.black_castling_rights;
+        result.black_castling_rights = self.white_castling_rights;
+        result.white_checks = self.black_checks;
+        result.black_checks = self.white_checks;
+        result.white_pocket = self.black_pocket;
+        result.black_pocket = self.white_pocket;
+        result.turn = !self.turn;
+        result
+    }
+
     /// Get the value of the material advantage of a certain player
     #[inline]
     pub fn get_material_advantage(&self, color: Color) -> i32 {
@@ -453,6 +637,36 @@ impl Board {
             .sum()
     }
 
+    /// Get how far the game is from the endgame, judged by the pieces
+    /// other than pawns that are left on the board.
+    ///
+    /// This is 1.0 with all of the starting pieces on the board,
+    /// and 0.0 when only kings and pawns are left.
+    pub fn get_game_phase(&self) -> f64 {
+        let phase: i32 = self
+            .squares
+            .iter()
+            .map(|square| match square.get_piece() {
+                Some(Piece::Queen(_, _)) => 4,
+                Some(Piece::Rook(_, _)) => 2,
+                Some(Piece::Bishop(_, _)) | Some(Piece::Knight(_, _)) => 1,
+                _ => 0,
+            })
+            .sum();
+        (phase as f64 / 24.0).min(1.0)
+    }
+
+    /// Get the number of pieces a player has on the board, including the king
+    pub fn get_material_count(&self, color: Color) -> usize {
+        self.squares
+            .iter()
+            .filter(|square| match square.get_piece() {
+                Some(piece) => piece.get_color() == color,
+                None => false,
+            })
+            .count()
+    }
+
     #[inline]
     fn get_square(&mut self, pos: Position) -> &mut Square {
         &mut self.squares[((7 - pos.get_row()) * 8 + pos.get_col()) as usize]
@@ -552,13 +766,389 @@ impl Board {
         false
     }
 
+    /// Get all of the pieces of a given color that attack a square.
+    ///
+    /// Unlike `is_threatened`, this also counts the pieces that defend
+    /// a piece of their own color standing on the square.
+    pub fn get_attackers(&self, pos: Position, color: Color) -> Vec<Piece> {
+        let mut board = *self;
+        if pos.is_on_board() {
+            *board.get_square(pos) = EMPTY_SQUARE;
+        }
+
+        board
+            .squares
+            .iter()
+            .filter_map(|square| square.get_piece())
+            .filter(|piece| piece.get_color() == color && piece.is_legal_attack(pos, &board))
+            .collect()
+    }
+
+    /// Get all of the enemy pieces giving check to the king of a given color.
+    pub fn get_checkers(&self, color: Color) -> Vec<Piece> {
+        if !self.is_in_check(color) {
+            return vec![];
+        }
+
+        match self.get_king_pos(color) {
+            Some(king_pos) => self.get_attackers(king_pos, !color),
+            None => vec![],
+        }
+    }
+
+    /// Get all of the pieces of a given color that are absolutely
+    /// pinned to their king, along with the enemy pieces pinning them.
+    pub fn get_pins(&self, color: Color) -> Vec<Pin> {
+        self.get_blockers(color, color)
+    }
+
+    /// Get all of the pieces of a given color that would give a
+    /// discovered check to the enemy king by moving out of the way
+    /// of one of their own sliding pieces.
+    pub fn get_discovered_check_candidates(&self, color: Color) -> Vec<Piece> {
+        self.get_blockers(!color, color)
+            .iter()
+            .map(Pin::get_pinned)
+            .collect()
+    }
+
+    /// Get every piece of a given color that is the only piece standing
+    /// between the king of `king_color` and a sliding piece of the
+    /// opposite color that would otherwise attack the king.
+    fn get_blockers(&self, king_color: Color, blocker_color: Color) -> Vec<Pin> {
+        let king_pos = match self.get_king_pos(king_color) {
+            Some(pos) => pos,
+            None => return vec![],
+        };
+
+        let mut result = vec![];
+        for square in &self.squares {
+            let slider = match square.get_piece() {
+                Some(piece) if piece.get_color() == !king_color => piece,
+                _ => continue,
+            };
+            let pos = slider.get_pos();
+            if pos == king_pos {
+                continue;
+            }
+
+            let ray = if (slider.is_rook() || slider.is_queen()) && king_pos.is_orthogonal_to(pos) {
+                king_pos.orthogonals_to(pos)
+            } else if (slider.is_bishop() || slider.is_queen()) && king_pos.is_diagonal_to(pos) {
+                king_pos.diagonals_to(pos)
+            } else {
+                continue;
+            };
+
+            let mut between = ray[..ray.len() - 1]
+                .iter()
+                .filter_map(|pos| self.get_piece(*pos));
+            if let (Some(blocker), None) = (between.next(), between.next()) {
+                if blocker.get_color() == blocker_color {
+                    result.push(Pin::new(blocker, slider, ray));
+                }
+            }
+        }
+        result
+    }
+
     /// Get whether or not the king of a given color is in check.
+    ///
+    /// In Atomic, kings that are touching each other are never in check,
+    /// and in Antichess, the king is an ordinary piece that cannot be checked.
     #[inline]
     pub fn is_in_check(&self, color: Color) -> bool {
-        if let Some(king_pos) = self.get_king_pos(color) {
-            self.is_threatened(king_pos, color)
-        } else {
-            false
+        match (
+            self.variant,
+            self.get_king_pos(color),
+            self.get_king_pos(!color),
+        ) {
+            (Variant::Antichess, _, _) => false,
+            (Variant::Atomic, Some(king_pos), Some(enemy_king_pos))
+                if king_pos.is_adjacent_to(enemy_king_pos) =>
+            {
+                false
+            }
+            (_, Some(king_pos), _) => self.is_threatened(king_pos, color),
+            (_, None, _) => false,
+        }
+    }
+
+    /// Does a move capture an enemy piece?
+    ///
+    /// This includes en-passant captures, even though the
+    /// en-passant square itself does not contain any piece.
+    pub fn is_capture(&self, m: Move) -> bool {
+        match m {
+            Move::Piece(from, to) | Move::Promotion(from, to, _) => match self.get_piece(from) {
+                Some(piece) => self.has_enemy_piece(to, piece.get_color()) || self.is_en_passant(m),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Does a move capture a pawn en-passant?
+    pub fn is_en_passant(&self, m: Move) -> bool {
+        match (m, self.en_passant) {
+            (Move::Piece(from, to), Some(en_passant)) => {
+                to == en_passant
+                    && from.get_col() != to.get_col()
+                    && matches!(self.get_piece(from), Some(piece) if piece.is_pawn())
+            }
+            _ => false,
+        }
+    }
+
+    /// Statically evaluate the exchange of pieces started by a move.
+    ///
+    /// Every piece that attacks the destination square, including x-ray
+    /// attackers hidden behind sliding pieces, recaptures in order of least
+    /// valuable first, and either side may stop capturing whenever continuing
+    /// would lose material. The result is the material gained by the moving
+    /// player, measured with `Piece::get_material_value`, so a winning capture
+    /// is positive and a move that hangs a piece is negative.
+    ///
+    /// Pins are not considered, and moves that do not move a piece on the
+    /// board (castling, drops and resigning) are given a value of zero.
+    pub fn see(&self, m: Move) -> i32 {
+        let (from, to, promotion) = match m {
+            Move::Piece(from, to) => (from, to, None),
+            Move::Promotion(from, to, piece) => (from, to, Some(piece)),
+            _ => return 0,
+        };
+        let piece = match self.get_piece(from) {
+            Some(piece) => piece,
+            None => return 0,
+        };
+        let color = piece.get_color();
+
+        // The board the exchange is played out on. Each capturing piece is
+        // removed from its square, which uncovers any x-ray attackers behind it.
+        let mut board = *self;
+        // A promoted piece is captured at its full value, even though
+        // it goes back into the capturing player's pocket as a pawn
+        let mut gains = vec![match self.get_captured_piece(m) {
+            Some(captured) => match self.get_piece(to) {
+                Some(piece) => piece.get_material_value(),
+                None => captured.get_material_value(),
+            },
+            None => 0,
+        }];
+        if piece.is_pawn() && Some(to) == self.en_passant && self.has_no_piece(to) {
+            *board.get_square(to.pawn_back(color)) = EMPTY_SQUARE;
+        }
+        board.en_passant = None;
+
+        let mut occupant = match promotion {
+            Some(promotion) => {
+                gains[0] += promotion.get_material_value() - piece.get_material_value();
+                promotion.with_color(color).move_to(to)
+            }
+            None => piece.move_to(to),
+        };
+        *board.get_square(from) = EMPTY_SQUARE;
+        board.add_piece(occupant);
+
+        let mut side = !color;
+        while let Some(attacker) = board.get_least_valuable_attacker(to, side) {
+            // A king can only recapture if the square is no longer defended
+            let mut after = board;
+            *after.get_square(attacker.get_pos()) = EMPTY_SQUARE;
+            after.add_piece(attacker.move_to(to));
+            if attacker.is_king() && after.get_least_valuable_attacker(to, !side).is_some() {
+                break;
+            }
+
+            let gain = occupant.get_material_value() - gains[gains.len() - 1];
+            gains.push(gain);
+            occupant = attacker.move_to(to);
+            board = after;
+            side = !side;
+        }
+
+        // Walk back through the exchange, letting each side stop
+        // capturing whenever the capture would not pay off.
+        while gains.len() > 1 {
+            let gain = gains.pop().unwrap();
+            let last = gains.len() - 1;
+            gains[last] = -core::cmp::max(-gains[last], gain);
+        }
+        gains[0]
+    }
+
+    /// Get the least valuable piece of a given color that attacks a square.
+    fn get_least_valuable_attacker(&self, pos: Position, color: Color) -> Option<Piece> {
+        self.get_attackers(pos, color)
+            .into_iter()
+            .min_by_key(|piece| piece.get_material_value())
+    }
+
+    /// Get the piece captured by a move, if there is one.
+    ///
+    /// In Crazyhouse, this is the piece that goes into the capturing
+    /// player's pocket, so promoted pieces are returned as pawns.
+    fn get_captured_piece(&self, m: Move) -> Option<Piece> {
+        match m {
+            Move::Piece(from, to) | Move::Promotion(from, to, _) if self.is_capture(m) => {
+                match self.get_piece(to) {
+                    Some(piece) if self.is_promoted(to) => Some(Piece::Pawn(piece.get_color(), to)),
+                    Some(piece) => Some(piece),
+                    // The pawn captured en-passant is behind the en-passant square
+                    None => self
+                        .get_piece(from)
+                        .map(|piece| Piece::Pawn(!piece.get_color(), to)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Get all of the legal moves that drop a piece from the
+    /// current player's pocket onto the board.
+    fn get_legal_drops(&self, color: Color) -> Vec<Move> {
+        let mut result = vec![];
+        if !self.variant.allows_drops() || self.get_pocket(color).is_empty() {
+            return result;
+        }
+
+        // Dropping a piece can never put the player's own king
+        // in check, so the drop only needs to be verified if it
+        // has to block a check.
+        let is_in_check = self.is_in_check(color);
+        for piece in self.get_pocket(color).get_pieces(color) {
+            for row in 0..8 {
+                for col in 0..8 {
+                    let m = Move::Drop(piece, Position::new(row, col));
+                    if self.is_pseudo_legal_move(m, color)
+                        && (!is_in_check || self.variant.allows_move(self, m, color))
+                    {
+                        result.push(m);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Get all of the legal moves of the pieces of a given color.
+    ///
+    /// Moves that would leave the player's king in check are never generated:
+    /// only the king may move out of a double check, a single check must be
+    /// captured or blocked, and pinned pieces can only move along their pin.
+    /// Drops from a pocket are not included, and in variants with compulsory
+    /// captures, this does not consider whether the player must capture instead.
+    pub(crate) fn get_legal_piece_moves(&self, color: Color) -> Vec<Move> {
+        let mut result = vec![];
+        for square in &self.squares {
+            if let Some(piece) = square.get_piece() {
+                if piece.get_color() == color {
+                    result.extend(piece.get_pseudo_legal_moves(self))
+                }
+            }
+        }
+
+        let king_pos = match self.get_king_pos(color) {
+            Some(king_pos) if self.variant.has_standard_king_safety() => king_pos,
+            // Variants with their own rules about the king need to try each move
+            _ => {
+                result.retain(|m| self.variant.allows_move(self, *m, color));
+                return result;
+            }
+        };
+
+        // The squares that a piece other than the king can move to in order to
+        // get out of check: capturing the checking piece, or blocking its path.
+        let evasions = match self.get_checkers(color).as_slice() {
+            [] => None,
+            [checker] => {
+                let pos = checker.get_pos();
+                Some(if checker.is_knight() || checker.is_pawn() {
+                    vec![pos]
+                } else if king_pos.is_orthogonal_to(pos) {
+                    king_pos.orthogonals_to(pos)
+                } else {
+                    king_pos.diagonals_to(pos)
+                })
+            }
+            _ => Some(vec![]),
+        };
+        let pins = self.get_pins(color);
+
+        // The king cannot hide from a sliding piece on the squares behind itself
+        let mut without_king = *self;
+        *without_king.get_square(king_pos) = EMPTY_SQUARE;
+
+        result.retain(|m| match *m {
+            Move::Piece(from, to) | Move::Promotion(from, to, _) => {
+                if from == king_pos {
+                    without_king.get_attackers(to, !color).is_empty()
+                } else if self.is_en_passant(*m) {
+                    // Capturing en-passant removes two pieces from the same rank,
+                    // which can uncover an attack on the king along that rank
+                    !self.apply_move(*m).is_in_check(color)
+                } else {
+                    evasions
+                        .as_ref()
+                        .map_or(true, |squares| squares.contains(&to))
+                        && pins
+                            .iter()
+                            .find(|pin| pin.get_pinned().get_pos() == from)
+                            .map_or(true, |pin| pin.allows(to))
+                }
+            }
+            // Castling is checked for attacked squares when it is generated
+            _ => true,
+        });
+        result
+    }
+
+    /// Does the respective player have any capture available?
+    fn has_capture(&self, color: Color) -> bool {
+        self.get_legal_piece_moves(color)
+            .into_iter()
+            .any(|m| self.is_capture(m))
+    }
+
+    /// Does the current player have at least one legal move?
+    pub(crate) fn has_legal_moves(&self) -> bool {
+        !self.get_legal_piece_moves(self.turn).is_empty()
+    }
+
+    /// Count the number of move sequences of a given length from this position.
+    ///
+    /// This is used to verify the move generator against
+    /// positions with well known numbers of move sequences.
+    pub fn perft(&self, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let moves = self.get_legal_moves();
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        moves
+            .into_iter()
+            .map(|m| self.apply_eval_move(m).perft(depth - 1))
+            .sum()
+    }
+
+    /// Remove the piece on a square, along with every surrounding
+    /// piece that is not a pawn. This is how captures work in Atomic.
+    fn explode(&mut self, pos: Position) {
+        *self.get_square(pos) = EMPTY_SQUARE;
+        for row in pos.get_row() - 1..=pos.get_row() + 1 {
+            for col in pos.get_col() - 1..=pos.get_col() + 1 {
+                let neighbor = Position::new(row, col);
+                if let Some(piece) = self.get_piece(neighbor) {
+                    if !piece.is_pawn() {
+                        *self.get_square(neighbor) = EMPTY_SQUARE;
+                    }
+                }
+            }
         }
     }
 
@@ -574,11 +1164,17 @@ impl Board {
         if let Some(mut piece) = from_square.get_piece() {
             *from_square = EMPTY_SQUARE;
 
+            let mut is_promoted = result.is_promoted(from);
+            result.promoted &= !(Self::square_bit(from) | Self::square_bit(to));
+
             if piece.is_pawn() && (to.get_row() == 0 || to.get_row() == 7) {
+                is_promoted = true;
                 piece = match promotion {
                     // promotion only required to specify piece type
                     Some(promotion) => {
-                        if promotion.is_king() || promotion.is_pawn() {
+                        if promotion.is_pawn()
+                            || (promotion.is_king() && !self.variant.allows_king_promotion())
+                        {
                             // invalid promotion, use default
                             Piece::Queen(piece.get_color(), piece.get_pos())
                         } else {
@@ -597,6 +1193,9 @@ impl Board {
             }
 
             result.add_piece(piece.move_to(to));
+            if is_promoted {
+                result.promoted |= Self::square_bit(to);
+            }
 
             let castling_rights = match piece.get_color() {
                 WHITE => &mut result.white_castling_rights,
@@ -610,6 +1209,17 @@ impl Board {
             } else if piece.is_kingside_rook() {
                 castling_rights.disable_kingside();
             }
+
+            // capturing a rook on its starting square takes away the right to castle with it
+            let enemy_castling_rights = match piece.get_color() {
+                WHITE => &mut result.black_castling_rights,
+                BLACK => &mut result.white_castling_rights,
+            };
+            if to == Position::new(Position::king_pos(!piece.get_color()).get_row(), 0) {
+                enemy_castling_rights.disable_queenside();
+            } else if to == Position::new(Position::king_pos(!piece.get_color()).get_row(), 7) {
+                enemy_castling_rights.disable_kingside();
+            }
         }
 
         result
@@ -654,6 +1264,7 @@ impl Board {
                     && self.white_castling_rights.can_queenside_castle()
                     && !self.is_in_check(color)
                     && !self.is_threatened(Position::queen_pos(color), color)
+                    && !self.is_threatened(Position::queen_pos(color).next_left(), color)
             }
             BLACK => {
                 self.has_no_piece(Position::new(7, 1))
@@ -664,6 +1275,7 @@ impl Board {
                     && self.black_castling_rights.can_queenside_castle()
                     && !self.is_in_check(color)
                     && !self.is_threatened(Position::queen_pos(color), color)
+                    && !self.is_threatened(Position::queen_pos(color).next_left(), color)
             }
         }
     }
@@ -676,27 +1288,28 @@ impl Board {
     }
 
     pub(crate) fn is_legal_move(&self, m: Move, player_color: Color) -> bool {
+        self.is_legal_move_ignoring_captures(m, player_color)
+            && (!self.variant.has_forced_captures()
+                || m == Move::Resign
+                || self.is_capture(m)
+                || !self.has_capture(player_color))
+    }
+
+    /// Is a move legal, without considering whether the variant
+    /// forces the player to make a capture instead?
+    pub(crate) fn is_legal_move_ignoring_captures(&self, m: Move, player_color: Color) -> bool {
+        self.is_pseudo_legal_move(m, player_color)
+            && self.variant.allows_move(self, m, player_color)
+    }
+
+    /// Does a move follow the movement rules of the piece, without considering
+    /// whether it leaves the player's king in check?
+    fn is_pseudo_legal_move(&self, m: Move, player_color: Color) -> bool {
         match m {
             Move::KingSideCastle => self.can_kingside_castle(player_color),
             Move::QueenSideCastle => self.can_queenside_castle(player_color),
             Move::Piece(from, to) => match self.get_piece(from) {
-                Some(Piece::Pawn(c, pos)) => {
-                    let piece = Piece::Pawn(c, pos);
-                    ((if let Some(en_passant) = self.en_passant {
-                        (en_passant == from.pawn_up(player_color).next_left()
-                            || en_passant == from.pawn_up(player_color).next_right()
-                                && en_passant == to)
-                            && c == player_color
-                    } else {
-                        false
-                    }) || piece.is_legal_move(to, self) && piece.get_color() == player_color)
-                        && !self.apply_move(m).is_in_check(player_color)
-                }
-                Some(piece) => {
-                    piece.is_legal_move(to, self)
-                        && piece.get_color() == player_color
-                        && !self.apply_move(m).is_in_check(player_color)
-                }
+                Some(piece) => piece.is_legal_move(to, self) && piece.get_color() == player_color,
                 _ => false,
             },
             Move::Promotion(from, to, promotion) => {
@@ -705,52 +1318,32 @@ impl Board {
                         // promotion specific checks
                         piece.is_pawn()
                             && (to.get_row() == 0 || to.get_row() == 7)
-                            && !(promotion.is_king() || promotion.is_pawn())
+                            && !promotion.is_pawn()
+                            && (!promotion.is_king() || self.variant.allows_king_promotion())
                             // regular piece checks
                             && piece.is_legal_move(to, self)
                             && piece.get_color() == player_color
-                            && !self.apply_move(m).is_in_check(player_color)
                     }
                     _ => false,
                 }
             }
+            Move::Drop(piece, to) => {
+                self.variant.allows_drops()
+                    && to.is_on_board()
+                    && self.has_no_piece(to)
+                    && self.get_pocket(player_color).count(piece) > 0
+                    // pawns cannot be dropped onto the first or last rank
+                    && !(piece.is_pawn() && (to.get_row() == 0 || to.get_row() == 7))
+            }
             Move::Resign => true,
         }
     }
 
-    /// Does the respective player have sufficient material?
+    /// Does the respective player have sufficient material to win
+    /// under the rules of the board's variant?
+    #[inline]
     pub fn has_sufficient_material(&self, color: Color) -> bool {
-        let mut pieces = vec![];
-        for square in &self.squares {
-            if let Some(piece) = square.get_piece() {
-                if piece.get_color() == color {
-                    pieces.push(piece);
-                }
-            }
-        }
-
-        pieces.sort();
-
-        if pieces.is_empty() {
-            false
-        } else if pieces.len() == 1 && pieces[0].is_king() {
-            false
-        } else if pieces.len() == 2 && pieces[0].is_king() && pieces[1].is_knight() {
-            false
-        } else if pieces.len() == 2 && pieces[0].is_king() && pieces[1].is_bishop() {
-            false
-        } else if pieces.len() == 3
-            && pieces[0].is_king()
-            && pieces[1].is_knight()
-            && pieces[2].is_knight()
-        {
-            false
-        } else {
-            !(pieces.len() == 3
-                && pieces[0].is_king()
-                && pieces[1].is_bishop()
-                && pieces[2].is_bishop())
-        }
+        self.variant.has_sufficient_material(self, color)
     }
 
     /// Does the respective player have insufficient material?
@@ -761,9 +1354,24 @@ impl Board {
 
     /// Is the current player in stalemate?
     pub fn is_stalemate(&self) -> bool {
-        (self.get_legal_moves().is_empty() && !self.is_in_check(self.get_current_player_color()))
-            || (self.has_insufficient_material(self.turn)
-                && self.has_insufficient_material(!self.turn))
+        self.get_legal_moves().is_empty() && !self.is_in_check(self.get_current_player_color())
+    }
+
+    /// Is the game drawn because neither player can win anymore?
+    ///
+    /// In regular chess, this is a dead position under the FIDE rules,
+    /// where no series of legal moves can lead to a checkmate.
+    pub fn is_dead_position(&self) -> bool {
+        self.has_insufficient_material(self.turn) && self.has_insufficient_material(!self.turn)
+    }
+
+    /// Has the game ended because of a rule specific to the board's variant?
+    ///
+    /// For example, in King of the Hill, this will return the victory
+    /// of the player whose king has reached the center of the board.
+    #[inline]
+    pub fn get_variant_result(&self) -> Option<GameResult> {
+        self.variant.get_result(self)
     }
 
     /// Is the current player in checkmate?
@@ -778,8 +1386,15 @@ impl Board {
         self
     }
 
-    fn apply_move(&self, m: Move) -> Self {
-        match m {
+    pub(crate) fn apply_move(&self, m: Move) -> Self {
+        let explodes = self.variant == Variant::Atomic && self.is_capture(m);
+        let captured = if self.variant.allows_drops() {
+            self.get_captured_piece(m)
+        } else {
+            None
+        };
+
+        let mut result = match m {
             Move::KingSideCastle => {
                 if let Some(king_pos) = self.get_king_pos(self.turn) {
                     let rook_pos = match self.turn {
@@ -824,8 +1439,34 @@ impl Board {
                 result
             }
             Move::Promotion(from, to, promotion) => self.move_piece(from, to, Some(promotion)),
+            Move::Drop(piece, to) => {
+                let mut result = *self;
+                result.en_passant = None;
+                result.get_pocket_mut(self.turn).remove(piece);
+                result.add_piece(piece.with_color(self.turn).move_to(to));
+                result
+            }
             Move::Resign => self.remove_all(self.turn).queen_all(!self.turn),
+        };
+
+        if let Some(piece) = captured {
+            result.get_pocket_mut(!piece.get_color()).add(piece);
         }
+
+        if explodes {
+            if let Move::Piece(_, to) | Move::Promotion(_, to, _) = m {
+                result.explode(to);
+            }
+        }
+
+        if self.variant == Variant::ThreeCheck && result.is_in_check(!self.turn) {
+            match self.turn {
+                WHITE => result.white_checks += 1,
+                BLACK => result.black_checks += 1,
+            }
+        }
+
+        result
     }
 
     /// Play a move and confirm it is legal.
@@ -833,13 +1474,17 @@ impl Board {
         let current_color = self.get_turn_color();
 
         if m == Move::Resign {
-            GameResult::Victory(!current_color)
+            GameResult::Victory(!current_color, WinReason::Resignation)
         } else if self.is_legal_move(m, current_color) {
             let next_turn = self.apply_move(m).change_turn();
-            if next_turn.is_checkmate() {
-                GameResult::Victory(current_color)
+            if let Some(result) = next_turn.get_variant_result() {
+                result
+            } else if next_turn.is_checkmate() {
+                GameResult::Victory(current_color, WinReason::Checkmate)
             } else if next_turn.is_stalemate() {
-                GameResult::Stalemate
+                GameResult::Draw(DrawReason::Stalemate)
+            } else if next_turn.is_dead_position() {
+                GameResult::Draw(DrawReason::DeadPosition)
             } else {
                 GameResult::Continuing(next_turn)
             }
@@ -848,3 +1493,295 @@ impl Board {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::*;
+    use crate::util::parse_fen;
+    use crate::Move;
+
+    #[test]
+    fn test_see() {
+        // A free pawn, defended by nothing
+        let board = parse_fen("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E4, D5)), 1);
+
+        // Rook takes a pawn defended by a pawn
+        let board = parse_fen("4k3/8/2p5/3p4/8/8/8/3RK3 w - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(D1, D5)), -4);
+
+        // Knight takes a pawn defended by a rook, backed up by a rook behind a queen
+        let board = parse_fen("3rk3/3r4/8/3p4/8/4N3/8/3QKR2 w - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E3, D5)), -2);
+
+        // With only a single defender, the knight wins the pawn
+        let board = parse_fen("4k3/3r4/8/3p4/8/4N3/3R4/3QK3 w - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E3, D5)), 1);
+
+        // Quiet moves are only bad if they move onto an attacked square
+        let board = parse_fen("8/4k3/4p3/8/4N3/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E4, C5)), 0);
+        assert_eq!(board.see(Move::Piece(E4, D6)), -3);
+
+        // A king can only recapture on an undefended square
+        let board = parse_fen("8/8/8/8/4b3/8/k7/1RK5 b - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E4, B1)), 5);
+        let board = parse_fen("8/8/8/8/k3b3/8/8/1RK5 b - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E4, B1)), 2);
+
+        // A promoted queen in Crazyhouse is worth a queen on the board
+        let board = parse_fen("4k3/8/8/3q~4/4P3/8/8/4K3[] w - - 0 1").unwrap();
+        assert_eq!(board.see(Move::Piece(E4, D5)), 9);
+    }
+
+    #[test]
+    fn test_attack_queries() {
+        use crate::{Color, Piece};
+
+        // The knight on e5 is attacked by the pawn and the queen, defended by
+        // the pawn on d6, and pinned to its king by the queen
+        let board = parse_fen("4k3/8/3p4/4n3/3P4/8/4Q3/4K3 b - - 0 1").unwrap();
+        assert_eq!(
+            board.get_attackers(E5, Color::White),
+            vec![
+                Piece::Pawn(Color::White, D4),
+                Piece::Queen(Color::White, E2)
+            ]
+        );
+        assert_eq!(
+            board.get_attackers(E5, Color::Black),
+            vec![Piece::Pawn(Color::Black, D6)]
+        );
+        assert_eq!(
+            board.get_attackers(F3, Color::Black),
+            vec![Piece::Knight(Color::Black, E5)]
+        );
+        assert_eq!(board.get_checkers(Color::Black), vec![]);
+
+        let pins = board.get_pins(Color::Black);
+        assert_eq!(pins.len(), 1);
+        assert_eq!(pins[0].get_pinned(), Piece::Knight(Color::Black, E5));
+        assert_eq!(pins[0].get_pinner(), Piece::Queen(Color::White, E2));
+        assert_eq!(pins[0].get_ray(), &[E7, E6, E5, E4, E3, E2][..]);
+        assert!(pins[0].allows(E3));
+        assert!(!pins[0].allows(F3));
+        assert!(board.get_pins(Color::White).is_empty());
+
+        // Moving the pawn on d4 uncovers a check from the bishop
+        let board = parse_fen("8/8/8/8/3P4/2B5/8/k3K3 w - - 0 1").unwrap();
+        assert!(board
+            .get_discovered_check_candidates(Color::White)
+            .is_empty());
+        let board = parse_fen("7k/8/8/8/3P4/2B5/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(
+            board.get_discovered_check_candidates(Color::White),
+            vec![Piece::Pawn(Color::White, D4)]
+        );
+
+        // A double check
+        let board = parse_fen("4k3/8/5N2/8/8/8/8/4RK2 b - - 0 1").unwrap();
+        assert_eq!(board.get_checkers(Color::Black).len(), 2);
+        assert!(board.get_pins(Color::Black).is_empty());
+    }
+
+    #[test]
+    fn test_flip_colors() {
+        let board = Board::default();
+        assert_eq!(board.flip_colors(), board.set_turn(BLACK));
+
+        let board =
+            parse_fen("r1bqkb1r/pppp1ppp/2n2n2/4p3/3PP3/5N2/PPP2PPP/RNBQKB1R[Pn] b Kq d3 0 1")
+                .unwrap();
+        let mirror = board.flip_colors();
+        assert_eq!(mirror.flip_colors(), board);
+        assert_eq!(mirror.get_turn_color(), WHITE);
+        assert_eq!(mirror.get_piece(D5), Some(Piece::Pawn(BLACK, D5)));
+        assert_eq!(mirror.get_piece(C3), Some(Piece::Knight(WHITE, C3)));
+        assert_eq!(mirror.get_en_passant(), Some(D6));
+        assert_eq!(mirror.get_pocket(WHITE), board.get_pocket(BLACK));
+        assert!(mirror.get_castling_rights(WHITE).can_queenside_castle());
+        assert!(!mirror.get_castling_rights(WHITE).can_kingside_castle());
+        assert!(mirror.get_castling_rights(BLACK).can_kingside_castle());
+
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::King(BLACK, E8))
+            .promoted_piece(Piece::Queen(WHITE, B7))
+            .build();
+        assert!(board.flip_colors().is_promoted(B2));
+    }
+
+    #[test]
+    fn test_multi_pv() {
+        let board =
+            parse_fen("r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 0 1")
+                .unwrap();
+        let (lines, _) = board.get_best_next_moves(1, 4);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].get_move(), Move::Piece(H5, F7));
+        assert_eq!(lines[0].get_value(), 999999.0);
+
+        // Every line has the same rating as searching its move on its own
+        let mut values = board
+            .get_legal_moves()
+            .into_iter()
+            .map(|m| {
+                board
+                    .apply_eval_move(m)
+                    .minimax(1, -1000000.0, 1000000.0, false, WHITE, &mut 0)
+            })
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        for (line, value) in lines.iter().zip(values) {
+            assert_eq!(line.get_value(), value);
+            assert!(!line.get_moves().is_empty() && line.get_moves().len() <= 2);
+        }
+        assert_ne!(lines[1].get_move(), lines[2].get_move());
+
+        // There are only two legal moves
+        let board = parse_fen("7k/8/8/8/8/3n4/8/K7 w - - 0 1").unwrap();
+        let (lines, _) = board.get_best_next_moves(2, 3);
+        assert_eq!(lines.len(), 2);
+        assert_ne!(lines[0].get_move(), lines[1].get_move());
+    }
+
+    #[test]
+    fn test_search_monitor() {
+        struct Recorder {
+            depths: Vec<i32>,
+            stop_after: usize,
+        }
+
+        impl SearchMonitor for Recorder {
+            fn should_stop(&mut self) -> bool {
+                self.depths.len() >= self.stop_after
+            }
+
+            fn report(&mut self, progress: &SearchProgress) {
+                assert!(!progress.get_line().get_moves().is_empty());
+                self.depths.push(progress.get_depth());
+            }
+        }
+
+        let board =
+            parse_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3").unwrap();
+        let mut recorder = Recorder {
+            depths: vec![],
+            stop_after: 10,
+        };
+        let (_, _, value) = board.get_best_next_move_with(2, &mut recorder);
+        assert_eq!(recorder.depths, vec![0, 1, 2]);
+        assert_eq!(value, board.get_best_next_move(2).2);
+
+        // Stopping early plays the move from the deepest finished depth
+        let mut recorder = Recorder {
+            depths: vec![],
+            stop_after: 1,
+        };
+        let result = board.get_best_next_move_with(3, &mut recorder);
+        assert_eq!(recorder.depths, vec![0]);
+        assert_eq!(result.0, board.get_best_next_move_with(0, &mut ()).0);
+
+        // The first depth is finished even if the search is stopped at once
+        let stop = core::sync::atomic::AtomicBool::new(true);
+        let (m, _, _) = board.get_best_next_move_with(4, &mut &stop);
+        assert!(board.get_legal_moves().contains(&m));
+    }
+
+    #[test]
+    fn test_ponder() {
+        struct Hitter<'a> {
+            ponder: &'a Ponder,
+            hit_at: i32,
+            depths: Vec<i32>,
+        }
+
+        impl SearchMonitor for Hitter<'_> {
+            fn should_stop(&mut self) -> bool {
+                // Play as soon as the opponent has moved
+                true
+            }
+
+            fn report(&mut self, progress: &SearchProgress) {
+                self.depths.push(progress.get_depth());
+                if progress.get_depth() == self.hit_at {
+                    self.ponder.ponderhit();
+                }
+            }
+        }
+
+        let board = Board::default().apply_eval_move(Move::Piece(E2, E4));
+        let reply = Move::Piece(E7, E5);
+        let after_reply = board.apply_eval_move(reply);
+
+        // Before the ponderhit, the monitor can not stop the search
+        let ponder = Ponder::new();
+        let mut hitter = Hitter {
+            ponder: &ponder,
+            hit_at: 2,
+            depths: vec![],
+        };
+        let (m, _, _) = board.ponder(reply, 4, &ponder, &mut hitter).unwrap();
+        assert!(ponder.is_hit());
+        assert_eq!(hitter.depths, vec![0, 1, 2]);
+        assert_eq!(m, after_reply.get_best_next_move_with(2, &mut ()).0);
+
+        // An unexpected reply throws the search away
+        let ponder = Ponder::new();
+        ponder.miss();
+        ponder.ponderhit();
+        assert!(!ponder.is_pondering() && !ponder.is_hit());
+        assert_eq!(board.ponder(reply, 3, &ponder, &mut ()), None);
+
+        let (lines, _) = Board::default().get_best_next_moves(1, 1);
+        assert!(lines[0].get_ponder_move().is_some());
+    }
+
+    #[test]
+    fn test_perft() {
+        let board = Board::default();
+        assert_eq!(board.perft(1), 20);
+        assert_eq!(board.perft(2), 400);
+        assert_eq!(board.perft(3), 8902);
+
+        // Castling, pins and promotions
+        let kiwipete =
+            parse_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+        assert_eq!(kiwipete.perft(1), 48);
+        assert_eq!(kiwipete.perft(2), 2039);
+
+        // En-passant captures that would expose the king along the rank
+        let board = parse_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+        assert_eq!(board.perft(1), 14);
+        assert_eq!(board.perft(2), 191);
+        assert_eq!(board.perft(3), 2812);
+
+        // Underpromotions and castling out of check
+        let board =
+            parse_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1").unwrap();
+        assert_eq!(board.perft(1), 6);
+        assert_eq!(board.perft(2), 264);
+        assert_eq!(board.perft(3), 9467);
+
+        let board = parse_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8").unwrap();
+        assert_eq!(board.perft(1), 44);
+        assert_eq!(board.perft(2), 1486);
+    }
+
+    #[test]
+    fn test_special_move_legality() {
+        // The pawn can only capture en-passant onto the square it passed
+        let board = parse_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        assert!(board.is_legal_move(Move::Piece(E5, D6), WHITE));
+        assert!(!board.is_legal_move(Move::Piece(E5, F6), WHITE));
+        assert!(!board.is_legal_move(Move::Piece(E5, A1), WHITE));
+
+        // Castling queenside is not allowed onto an attacked square
+        let board = parse_fen("r3k3/8/8/8/8/8/8/4K2R b q - 0 1").unwrap();
+        assert!(board.is_legal_move(Move::QueenSideCastle, BLACK));
+        let board = parse_fen("r3k3/8/8/8/8/8/8/2R1K3 b q - 0 1").unwrap();
+        assert!(!board.is_legal_move(Move::QueenSideCastle, BLACK));
+    }
+}
@@ -0,0 +1,356 @@
+use super::Color;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// How a player is given time back for the moves they make.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bonus {
+    /// No time is given back.
+    None,
+    /// A Fischer increment, which adds this much time to the
+    /// player's clock after every move.
+    Increment(Duration),
+    /// A simple delay, where the player's clock only starts
+    /// counting down after this much time has passed on every move.
+    Delay(Duration),
+    /// A Bronstein delay, which gives back the time spent on every
+    /// move, up to this much time.
+    Bronstein(Duration),
+}
+
+/// One period of a time control, such as the first 40 moves
+/// in 90 minutes of a classical game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimePeriod {
+    time: Duration,
+    moves: Option<u32>,
+    bonus: Bonus,
+}
+
+impl TimePeriod {
+    /// Create a period that gives each player this much time for
+    /// the rest of the game, with no time given back for their moves.
+    pub fn new(time: Duration) -> Self {
+        Self {
+            time,
+            moves: None,
+            bonus: Bonus::None,
+        }
+    }
+
+    /// End this period after each player makes this many moves.
+    pub fn moves(mut self, moves: u32) -> Self {
+        self.moves = Some(moves);
+        self
+    }
+
+    /// Give time back for every move made in this period.
+    pub fn bonus(mut self, bonus: Bonus) -> Self {
+        self.bonus = bonus;
+        self
+    }
+
+    /// Get the time added to each player's clock at the start of this period.
+    #[inline]
+    pub fn get_time(&self) -> Duration {
+        self.time
+    }
+
+    /// Get the number of moves in this period, or `None` if it lasts
+    /// for the rest of the game.
+    #[inline]
+    pub fn get_moves(&self) -> Option<u32> {
+        self.moves
+    }
+
+    /// Get how time is given back for moves made in this period.
+    #[inline]
+    pub fn get_bonus(&self) -> Bonus {
+        self.bonus
+    }
+}
+
+/// The time each player has to make their moves.
+///
+/// A time control is a list of periods. When a player finishes the
+/// moves of a period, the time of the next period is added to their
+/// clock. If the last period has a number of moves, it is repeated.
+///
+/// ```
+/// use chess_engine::{Bonus, TimeControl, TimePeriod};
+/// use core::time::Duration;
+///
+/// // 40 moves in 90 minutes, then 30 minutes for the rest of the
+/// // game, with 30 seconds added per move from the first move.
+/// let bonus = Bonus::Increment(Duration::from_secs(30));
+/// let classical = TimeControl::new(
+///     TimePeriod::new(Duration::from_secs(90 * 60))
+///         .moves(40)
+///         .bonus(bonus),
+/// )
+/// .then(TimePeriod::new(Duration::from_secs(30 * 60)).bonus(bonus));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeControl {
+    periods: Vec<TimePeriod>,
+}
+
+impl TimeControl {
+    /// Create a time control that starts with the given period.
+    pub fn new(period: TimePeriod) -> Self {
+        Self {
+            periods: vec![period],
+        }
+    }
+
+    /// Add another period after the previous ones.
+    pub fn then(mut self, period: TimePeriod) -> Self {
+        self.periods.push(period);
+        self
+    }
+
+    /// A time control where each player has a fixed amount
+    /// of time for the whole game.
+    pub fn sudden_death(time: Duration) -> Self {
+        Self::new(TimePeriod::new(time))
+    }
+
+    /// A time control with a Fischer increment after every move.
+    pub fn increment(time: Duration, increment: Duration) -> Self {
+        Self::new(TimePeriod::new(time).bonus(Bonus::Increment(increment)))
+    }
+
+    /// A time control with a simple delay before every move.
+    pub fn delay(time: Duration, delay: Duration) -> Self {
+        Self::new(TimePeriod::new(time).bonus(Bonus::Delay(delay)))
+    }
+
+    /// A time control with a Bronstein delay after every move.
+    pub fn bronstein(time: Duration, delay: Duration) -> Self {
+        Self::new(TimePeriod::new(time).bonus(Bonus::Bronstein(delay)))
+    }
+
+    /// Get the periods of this time control, in order.
+    #[inline]
+    pub fn get_periods(&self) -> &[TimePeriod] {
+        &self.periods
+    }
+}
+
+/// A chess clock, which keeps track of the time both players
+/// have left under a time control.
+///
+/// This does not read the time itself. Instead, every method that
+/// depends on the time takes the current reading of a monotonic clock
+/// supplied by the caller, such as the time since the program started.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Clock {
+    control: TimeControl,
+    remaining: [Duration; 2],
+    /// The moves each player has made in their current period.
+    moves: [u32; 2],
+    period: [usize; 2],
+    /// The player whose clock is running, and when it was started.
+    running: Option<(Color, Duration)>,
+}
+
+impl Clock {
+    /// Create a clock for a time control, which is not running yet.
+    pub fn new(control: TimeControl) -> Self {
+        let time = control.periods[0].time;
+        Self {
+            control,
+            remaining: [time; 2],
+            moves: [0; 2],
+            period: [0; 2],
+            running: None,
+        }
+    }
+
+    /// Get the time control this clock follows.
+    #[inline]
+    pub fn get_time_control(&self) -> &TimeControl {
+        &self.control
+    }
+
+    /// Get the player whose clock is running, if any.
+    #[inline]
+    pub fn get_running_color(&self) -> Option<Color> {
+        self.running.map(|(color, _)| color)
+    }
+
+    /// Is either player's clock running?
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    /// Start a player's clock, stopping the other player's clock.
+    pub fn start(&mut self, color: Color, now: Duration) {
+        self.stop(now);
+        self.running = Some((color, now));
+    }
+
+    /// Stop the running clock, without giving back any time
+    /// for the move in progress.
+    pub fn stop(&mut self, now: Duration) {
+        if let Some((color, _)) = self.running {
+            let i = color as usize;
+            self.remaining[i] = self.remaining[i].saturating_sub(self.get_used(color, now));
+            self.running = None;
+        }
+    }
+
+    /// End the move of the player whose clock is running, and
+    /// start their opponent's clock.
+    ///
+    /// This gives back time for the move under the current period,
+    /// and adds the time of the next period once the player has
+    /// made all of the moves in this one. A player who has already
+    /// run out of time gets nothing back.
+    pub fn press(&mut self, now: Duration) {
+        let (color, start) = match self.running {
+            Some(running) => running,
+            None => return,
+        };
+        let period = self.get_period(color);
+        self.stop(now);
+
+        let i = color as usize;
+        if self.remaining[i] > Duration::from_secs(0) {
+            match period.bonus {
+                Bonus::Increment(increment) => self.remaining[i] += increment,
+                Bonus::Bronstein(delay) => {
+                    self.remaining[i] += delay.min(now.saturating_sub(start))
+                }
+                Bonus::None | Bonus::Delay(_) => {}
+            }
+
+            self.moves[i] += 1;
+            if period.moves == Some(self.moves[i]) {
+                self.moves[i] = 0;
+                self.period[i] += 1;
+                self.remaining[i] += self.get_period(color).time;
+            }
+        }
+
+        self.running = Some((!color, now));
+    }
+
+    /// Get the time a player has left.
+    pub fn get_remaining(&self, color: Color, now: Duration) -> Duration {
+        self.remaining[color as usize].saturating_sub(self.get_used(color, now))
+    }
+
+    /// Has a player run out of time?
+    pub fn is_flagged(&self, color: Color, now: Duration) -> bool {
+        self.get_remaining(color, now) == Duration::from_secs(0)
+    }
+
+    /// Get the number of moves a player has to make before
+    /// the next period starts, or `None` if there is no next period.
+    pub fn get_moves_to_go(&self, color: Color) -> Option<u32> {
+        self.get_period(color)
+            .moves
+            .map(|moves| moves - self.moves[color as usize])
+    }
+
+    /// Get how time is given back for a player's next move.
+    pub fn get_bonus(&self, color: Color) -> Bonus {
+        self.get_period(color).bonus
+    }
+
+    /// Get the period a player is currently in.
+    fn get_period(&self, color: Color) -> TimePeriod {
+        let periods = &self.control.periods;
+        periods[self.period[color as usize].min(periods.len() - 1)]
+    }
+
+    /// Get the time that counts against a player for the move
+    /// they are making.
+    fn get_used(&self, color: Color, now: Duration) -> Duration {
+        match self.running {
+            Some((running, start)) if running == color => {
+                let elapsed = now.saturating_sub(start);
+                match self.get_period(color).bonus {
+                    Bonus::Delay(delay) => elapsed.saturating_sub(delay),
+                    _ => elapsed,
+                }
+            }
+            _ => Duration::from_secs(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BLACK, WHITE};
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_sudden_death() {
+        let mut clock = Clock::new(TimeControl::sudden_death(secs(60)));
+        assert_eq!(clock.get_remaining(WHITE, secs(100)), secs(60));
+        clock.start(WHITE, secs(100));
+        assert_eq!(clock.get_remaining(WHITE, secs(110)), secs(50));
+        clock.press(secs(120));
+        assert_eq!(clock.get_running_color(), Some(BLACK));
+        assert_eq!(clock.get_remaining(WHITE, secs(200)), secs(40));
+        assert_eq!(clock.get_remaining(BLACK, secs(150)), secs(30));
+        assert!(!clock.is_flagged(BLACK, secs(179)));
+        assert!(clock.is_flagged(BLACK, secs(180)));
+        assert_eq!(clock.get_moves_to_go(WHITE), None);
+    }
+
+    #[test]
+    fn test_bonus() {
+        let mut clock = Clock::new(TimeControl::increment(secs(60), secs(2)));
+        clock.start(WHITE, secs(0));
+        clock.press(secs(10));
+        assert_eq!(clock.get_remaining(WHITE, secs(10)), secs(52));
+
+        let mut clock = Clock::new(TimeControl::delay(secs(60), secs(5)));
+        clock.start(WHITE, secs(0));
+        assert_eq!(clock.get_remaining(WHITE, secs(3)), secs(60));
+        clock.press(secs(10));
+        assert_eq!(clock.get_remaining(WHITE, secs(10)), secs(55));
+        assert!(clock.is_flagged(BLACK, secs(75)));
+
+        let mut clock = Clock::new(TimeControl::bronstein(secs(60), secs(5)));
+        clock.start(WHITE, secs(0));
+        assert_eq!(clock.get_remaining(WHITE, secs(3)), secs(57));
+        clock.press(secs(3));
+        assert_eq!(clock.get_remaining(WHITE, secs(3)), secs(60));
+        clock.press(secs(13));
+        clock.press(secs(23));
+        assert_eq!(clock.get_remaining(WHITE, secs(23)), secs(55));
+
+        // A clock reading from before the move started gives nothing back
+        clock.press(secs(20));
+        assert_eq!(clock.get_remaining(BLACK, secs(20)), secs(55));
+    }
+
+    #[test]
+    fn test_periods() {
+        let bonus = Bonus::Increment(secs(30));
+        let control = TimeControl::new(TimePeriod::new(secs(90 * 60)).moves(2).bonus(bonus))
+            .then(TimePeriod::new(secs(30 * 60)).bonus(bonus));
+        let mut clock = Clock::new(control);
+        clock.start(WHITE, secs(0));
+        assert_eq!(clock.get_moves_to_go(WHITE), Some(2));
+        clock.press(secs(60));
+        clock.press(secs(60));
+        assert_eq!(clock.get_moves_to_go(WHITE), Some(1));
+        clock.press(secs(120));
+        assert_eq!(clock.get_moves_to_go(WHITE), None);
+        assert_eq!(
+            clock.get_remaining(WHITE, secs(120)),
+            secs(120 * 60 - 120 + 60)
+        );
+        assert_eq!(clock.get_bonus(WHITE), bonus);
+    }
+}
@@ -0,0 +1,1324 @@
+use super::{Board, Color, Piece, Position};
+use alloc::vec::Vec;
+
+/// The value of an ending the stronger side is known to win,
+/// before adding the terms that guide it towards checkmate.
+const KNOWN_WIN: f64 = 1000.0;
+
+/// The number of positions in the KPK bitbase: the pawn on one of
+/// 24 squares, either side to move, and both kings anywhere.
+const KPK_SIZE: usize = 24 * 2 * 64 * 64;
+
+/// Both players' pieces, each with the king first.
+///
+/// The pieces are collected once for every evaluation, and shared
+/// by all of the checks for endings that are evaluated by hand.
+pub(crate) struct PieceLists {
+    pieces: [Vec<Piece>; 2],
+}
+
+impl PieceLists {
+    /// Collect the pieces on a board.
+    pub(crate) fn new(board: &Board) -> Self {
+        let mut pieces = [vec![], vec![]];
+        for row in 0..8 {
+            for col in 0..8 {
+                if let Some(piece) = board.get_piece(Position::new(row, col)) {
+                    pieces[piece.get_color() as usize].push(piece);
+                }
+            }
+        }
+        for list in &mut pieces {
+            list.sort();
+        }
+        Self { pieces }
+    }
+
+    /// Get a player's pieces, with the king first.
+    #[inline]
+    fn get(&self, color: Color) -> &[Piece] {
+        &self.pieces[color as usize]
+    }
+}
+
+/// Could a board be one of the endings that are evaluated or scaled by hand?
+///
+/// This only counts the pieces, so that most boards in a search can
+/// skip collecting the `PieceLists` for the checks that need them.
+pub(crate) fn could_be_known_ending(board: &Board) -> bool {
+    // Every piece but the king, and the pawns, rooks and queens among them
+    let mut pieces = [0; 2];
+    let mut pawns_and_majors = [0; 2];
+    for row in 0..8 {
+        for col in 0..8 {
+            match board.get_piece(Position::new(row, col)) {
+                Some(piece) if !piece.is_king() => {
+                    let color = piece.get_color() as usize;
+                    pieces[color] += 1;
+                    if piece.is_pawn() || piece.is_rook() || piece.is_queen() {
+                        pawns_and_majors[color] += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // A lone king, a player who may not be able to checkmate,
+    // or a single minor piece each that could be bishops on opposite colors
+    let is_minor_ending = |color: usize| pieces[color] - pawns_and_majors[color] == 1;
+    (0..2).any(|color| pieces[color] == 0 || pawns_and_majors[color] == 0)
+        || (is_minor_ending(0) && is_minor_ending(1))
+}
+
+/// Does a player have enough pieces to checkmate on their own?
+///
+/// A player can not checkmate with only:
+/// 1. A king
+/// 2. A king and a knight
+/// 3. A king and two knights
+/// 4. A king and a bishop
+/// 5. A king and two bishops
+fn has_mating_material(pieces: &[Piece]) -> bool {
+    let is_insufficient = pieces.is_empty()
+        || (pieces.len() == 1 && pieces[0].is_king())
+        || (pieces.len() == 2 && pieces[0].is_king() && pieces[1].is_knight())
+        || (pieces.len() == 2 && pieces[0].is_king() && pieces[1].is_bishop())
+        || (pieces.len() == 3
+            && pieces[0].is_king()
+            && pieces[1].is_knight()
+            && pieces[2].is_knight())
+        || (pieces.len() == 3
+            && pieces[0].is_king()
+            && pieces[1].is_bishop()
+            && pieces[2].is_bishop());
+
+    !is_insufficient
+}
+
+/// Could a player checkmate by any series of legal moves, even
+/// with the help of their opponent?
+///
+/// This is the FIDE rule for dead positions and for running out of
+/// time, so a king and two knights can still checkmate, but a king
+/// and a bishop can never checkmate a king and a bishop on squares
+/// of the same color. Positions that are dead only because the pawns
+/// are blocked are not recognised.
+pub(crate) fn can_checkmate(board: &Board, color: Color) -> bool {
+    let lists = PieceLists::new(board);
+    let (pieces, enemies) = (lists.get(color), lists.get(!color));
+    if pieces
+        .iter()
+        .any(|p| p.is_pawn() || p.is_rook() || p.is_queen())
+    {
+        return true;
+    }
+
+    let knights = pieces.iter().filter(|p| p.is_knight()).count();
+    let bishop_colors = pieces
+        .iter()
+        .filter(|p| p.is_bishop())
+        .map(|p| is_light_square(p.get_pos()))
+        .collect::<Vec<_>>();
+    let has_light_bishop = bishop_colors.contains(&true);
+    let has_dark_bishop = bishop_colors.contains(&false);
+
+    match (knights, bishop_colors.len()) {
+        (0, 0) => false,
+        // The enemy king has to be boxed in by its own pieces, and a
+        // queen next to its king would always defend the knight.
+        (1, 0) => enemies.iter().any(|p| !p.is_king() && !p.is_queen()),
+        (0, _) if !(has_light_bishop && has_dark_bishop) => {
+            // Bishops on one color can only checkmate a king that is
+            // blocked by a piece that could stand on the other color.
+            enemies.iter().any(|p| {
+                p.is_pawn()
+                    || p.is_knight()
+                    || (p.is_bishop() && is_light_square(p.get_pos()) != has_light_bishop)
+            })
+        }
+        _ => true,
+    }
+}
+
+/// Get the value of an ending that is evaluated by hand, rather
+/// than by the evaluation function, for a given player.
+///
+/// This knows how to win with a king and pawn, a king, bishop
+/// and knight, or a king and a queen or rook against a lone king,
+/// and that positions where neither player can checkmate are drawn.
+pub(crate) fn get_endgame_value(board: &Board, lists: &PieceLists, color: Color) -> Option<f64> {
+    if !has_mating_material(lists.get(Color::White))
+        && !has_mating_material(lists.get(Color::Black))
+    {
+        return Some(0.0);
+    }
+
+    for strong in &[Color::White, Color::Black] {
+        if lists.get(!*strong).len() != 1 {
+            continue;
+        }
+        let pieces = lists.get(*strong);
+        let value = match &pieces[1..] {
+            [Piece::Pawn(_, _)] => get_kpk_value(board, lists, *strong),
+            [Piece::Bishop(_, _), Piece::Knight(_, _)] => get_kbnk_value(board, lists, *strong),
+            _ if pieces.iter().any(|p| p.is_queen() || p.is_rook()) => {
+                get_kxk_value(board, lists, *strong)
+            }
+            _ => continue,
+        };
+        return Some(if *strong == color { value } else { -value });
+    }
+    None
+}
+
+/// Get what an evaluation that favors a player should be multiplied
+/// by, for endings that are harder to win than the material suggests.
+///
+/// A player who can not checkmate can not win, and neither can a
+/// bishop with rook pawns that can not help them promote. Endings
+/// with bishops on opposite colored squares are often drawn too.
+pub(crate) fn get_draw_scale(lists: &PieceLists, strong: Color) -> f64 {
+    if !has_mating_material(lists.get(strong)) || is_wrong_rook_pawn(lists, strong) {
+        return 0.0;
+    }
+
+    let (white, black) = (lists.get(Color::White), lists.get(Color::Black));
+    let only_bishops = |pieces: &[Piece]| {
+        pieces[1..].iter().filter(|p| !p.is_pawn()).count() == 1
+            && pieces.iter().any(|p| p.is_bishop())
+    };
+    if only_bishops(white) && only_bishops(black) {
+        let square_color = |pieces: &[Piece]| {
+            pieces
+                .iter()
+                .find(|p| p.is_bishop())
+                .map(|p| is_light_square(p.get_pos()))
+        };
+        if square_color(white) != square_color(black) {
+            return 0.5;
+        }
+    }
+    1.0
+}
+
+/// Is a player left with only pawns on one rook file, and maybe bishops
+/// that can not cover the square they promote on, with the enemy king
+/// already guarding that square?
+fn is_wrong_rook_pawn(lists: &PieceLists, strong: Color) -> bool {
+    let (pieces, weak) = (lists.get(strong), lists.get(!strong));
+    if weak.len() != 1 || !pieces.iter().any(|p| p.is_pawn()) {
+        return false;
+    }
+
+    let col = match pieces.iter().find(|p| p.is_pawn()) {
+        Some(pawn) => pawn.get_pos().get_col(),
+        None => return false,
+    };
+    let promotion = Position::new(
+        match strong {
+            Color::White => 7,
+            Color::Black => 0,
+        },
+        col,
+    );
+    (col == 0 || col == 7)
+        && pieces[1..].iter().all(|p| match p {
+            Piece::Pawn(_, pos) => pos.get_col() == col,
+            Piece::Bishop(_, pos) => is_light_square(*pos) != is_light_square(promotion),
+            _ => false,
+        })
+        && get_distance(weak[0].get_pos(), promotion) <= 1
+}
+
+/// King and pawn against king is looked up in the bitbase.
+fn get_kpk_value(board: &Board, lists: &PieceLists, strong: Color) -> f64 {
+    // The bitbase has the stronger side moving up the board
+    let square = |pos: Position| {
+        let row = match strong {
+            Color::White => pos.get_row(),
+            Color::Black => 7 - pos.get_row(),
+        };
+        (row * 8 + pos.get_col()) as usize
+    };
+    let pieces = lists.get(strong);
+    let (king, pawn) = (square(pieces[0].get_pos()), square(pieces[1].get_pos()));
+    let weak_king = square(lists.get(!strong)[0].get_pos());
+
+    if is_kpk_win(king, pawn, weak_king, board.get_turn_color() == strong) {
+        KNOWN_WIN + 10.0 + (pawn >> 3) as f64
+    } else {
+        0.0
+    }
+}
+
+/// A king and queen or rook against a lone king wins by driving
+/// the lone king to the edge of the board with both kings close.
+fn get_kxk_value(board: &Board, lists: &PieceLists, strong: Color) -> f64 {
+    let strong_king = lists.get(strong)[0].get_pos();
+    let weak_king = lists.get(!strong)[0].get_pos();
+    KNOWN_WIN
+        + board.get_material_advantage(strong) as f64 * 10.0
+        + get_push_to_edge(weak_king)
+        + get_push_close(strong_king, weak_king)
+}
+
+/// A king, bishop and knight can only checkmate a lone king
+/// in one of the corners the bishop can reach.
+fn get_kbnk_value(board: &Board, lists: &PieceLists, strong: Color) -> f64 {
+    let pieces = lists.get(strong);
+    let strong_king = pieces[0].get_pos();
+    let weak_king = lists.get(!strong)[0].get_pos();
+
+    let corners = if is_light_square(pieces[1].get_pos()) {
+        [Position::new(0, 7), Position::new(7, 0)]
+    } else {
+        [Position::new(0, 0), Position::new(7, 7)]
+    };
+    let corner_distance = corners
+        .iter()
+        .map(|corner| get_distance(weak_king, *corner))
+        .min()
+        .unwrap_or(0);
+
+    KNOWN_WIN
+        + board.get_material_advantage(strong) as f64 * 10.0
+        + (7 - corner_distance) as f64 * 10.0
+        + get_push_close(strong_king, weak_king)
+}
+
+/// A bonus for a king being near the edge of the board.
+fn get_push_to_edge(pos: Position) -> f64 {
+    let from_center = |x: i32| (2 * x - 7).abs() as f64;
+    (from_center(pos.get_row()) + from_center(pos.get_col())) * 3.0
+}
+
+/// A bonus for the two kings being close together.
+fn get_push_close(a: Position, b: Position) -> f64 {
+    (7 - get_distance(a, b)) as f64 * 2.0
+}
+
+/// The number of king moves between two squares.
+fn get_distance(a: Position, b: Position) -> i32 {
+    (a.get_row() - b.get_row())
+        .abs()
+        .max((a.get_col() - b.get_col()).abs())
+}
+
+#[inline]
+fn is_light_square(pos: Position) -> bool {
+    (pos.get_row() + pos.get_col()) % 2 == 1
+}
+
+/// Is a king and pawn against king position won for the
+/// player with the pawn, who is moving up the board?
+///
+/// Squares are numbered from 0 for A1 to 63 for H8.
+fn is_kpk_win(king: usize, pawn: usize, weak_king: usize, to_move: bool) -> bool {
+    // The bitbase only has pawns on files A to D
+    let (king, pawn, weak_king) = if pawn & 7 > 3 {
+        (king ^ 7, pawn ^ 7, weak_king ^ 7)
+    } else {
+        (king, pawn, weak_king)
+    };
+    if !(8..56).contains(&pawn) {
+        return false;
+    }
+    let index = get_kpk_index(to_move, king, weak_king, pawn);
+    KPK_BITBASE[index / 64] & (1 << (index % 64)) != 0
+}
+
+fn get_kpk_index(to_move: bool, king: usize, weak_king: usize, pawn: usize) -> usize {
+    let pawn_index = ((pawn >> 3) - 1) * 4 + (pawn & 7);
+    ((pawn_index * 2 + to_move as usize) * 64 + king) * 64 + weak_king
+}
+
+/// The KPK bitbase, with one bit for every position that is won for
+/// the player with the pawn. This is generated by `generate_kpk`.
+#[rustfmt::skip]
+static KPK_BITBASE: [u64; KPK_SIZE / 64] = [
+    0x80808080808080fc, 0x80808080808080f8, 0x80808080808080f0, 0x80808080808080e0,
+    0x80808080808080c4, 0x808080808080808c, 0x808080808080001c, 0x808080808080003c,
+    0x0000000000000000, 0x808080808080f8f8, 0x808080808080f0f0, 0x808080808080e0e0,
+    0x808080808080c0c4, 0x808080808080808c, 0x808080808000001c, 0x808080808000003c,
+    0x8080808080f0f0ff, 0x8080808080f8f8ff, 0x8080808080f0f0fc, 0x8080808080e0e0fc,
+    0x8080808080c0c0fc, 0x80808080808080fc, 0x80808080000000fc, 0x80808080000000fc,
+    0xc0c0c0c0f0f0fcfc, 0xc0c0c0c0f8f8fcfc, 0xc0c0c0c0f0f0fcfc, 0xc0c0c0c0e0e0fcfc,
+    0xc0c0c0c0c0c0f0fc, 0x808080808080e0fc, 0x808080000000c0fc, 0x80808000000080fc,
+    0xe0e0e0f0f0f0fcfc, 0xe0e0e0f8f8fcfcfc, 0xe0e0e0f0f0fcfcfc, 0xe0e0e0e0e0f8fcfc,
+    0xc0c0c0c0c0f0f0fc, 0x8080808080e0e0fc, 0x8080000000c0c0fc, 0x80800000008080fc,
+    0xf0f0f0f0f0f0fcfc, 0xf0f0f8f8f8f8fcfc, 0xf0f0f0f0f0f0fcfc, 0xe0e0e0e0e0e0fcfc,
+    0xc0c0c0c0c0c0f0fc, 0x808080808080e0fc, 0x800000008080c0fc, 0x80000000808080fc,
+    0xf0f0f0f0f0f0f0fc, 0xf8f8f0f0f0f0f0fc, 0xf0f0f0f0f0f0f0fc, 0xe0e0e0e0e0e0e0fc,
+    0xc0c0c0c0c0c0c0fc, 0x80808080808080fc, 0x00000080808080fc, 0x00000080808080fc,
+    0xf0e0e0e0e0e0e0fc, 0xf8e0e0e0e0e0e0fc, 0xf0e0e0e0e0e0e0fc, 0xe0e0e0e0e0e0e0fc,
+    0xc0c0c0c0c0c0c0fc, 0x80808080808080fc, 0x00008080808080fc, 0x00008080808080fc,
+    0xc0c0c0c0c0c0fcfc, 0xc0c0c0c0c0c0f8f8, 0xc0c0c0c0c0c0f0f1, 0xc0c0c0c0c0c0e2e3,
+    0xc0c0c0c0c0c0c6c7, 0xc0c0c0c0c0c08e8f, 0xc0c0c0c0c0c01e1f, 0xc0c0c0c0c0c03e3f,
+    0x0000000000000000, 0xc0c0c0c0c0f8f8f8, 0xc0c0c0c0c0f0f0f1, 0xc0c0c0c0c0e0e2e3,
+    0xc0c0c0c0c0c0c6c7, 0xc0c0c0c0c0808e8f, 0xc0c0c0c0c0001e1f, 0xc0c0c0c0c0003e3f,
+    0xc0c0c0c0f8f8fcff, 0xc0c0c0c0f8f8f8ff, 0xc0c0c0c0f0f0f0ff, 0xc0c0c0c0e0e0e2ff,
+    0xc0c0c0c0c0c0c6ff, 0xc0c0c0c080808eff, 0xc0c0c0c000001eff, 0xc0c0c0c000003eff,
+    0xe0e0e0f8f8fcfeff, 0xe0e0e0f8f8f8feff, 0xe0e0e0f0f0f0feff, 0xe0e0e0e0e0e0feff,
+    0xe0e0e0c0c0c0feff, 0xc0c0c0808080feff, 0xc0c0c0000000feff, 0xc0c0c0000000feff,
+    0xf0f0f8f8f8fcfeff, 0xf0f0f8f8f8fcfeff, 0xf0f0f0f0f0fcfeff, 0xf0f0e0e0e0fcfeff,
+    0xe0e0c0c0c0f8feff, 0xc0c0808080f0feff, 0xc0c0000000e0feff, 0xc0c0000000c0feff,
+    0xf8f8f8f8f8fcfeff, 0xf8f8f8f8fcfcfeff, 0xf8f0f0f0f8fcfeff, 0xf0e0e0e0f0fcfeff,
+    0xe0c0c0c0e0f8feff, 0xc0808080c0f0feff, 0xc0000000c0e0feff, 0xc0000000c0c0feff,
+    0xf8f8f8f8f8f8feff, 0xf8f8f8f8f8f8feff, 0xf0f0f0f8f8f8feff, 0xe0e0e0f0f0f0feff,
+    0xc0c0c0e0e0e0feff, 0x808080c0c0c0feff, 0x000000c0c0c0feff, 0x000000c0c0c0feff,
+    0xf8f8f0f0f0f0feff, 0xf8f8f0f0f0f0feff, 0xf0f0f0f0f0f0feff, 0xe0e0f0f0f0f0feff,
+    0xc0c0e0e0e0e0feff, 0x8080c0c0c0c0feff, 0x0000c0c0c0c0feff, 0x0000c0c0c0c0feff,
+    0xf0f0f0f0f0f0f8fc, 0xf0f0f0f0f0f0f8f8, 0xe0e0e0e0e0e0e0f1, 0xe0e0e0e0e0e0e0e0,
+    0xc0c0c0c0c0c0c0c0, 0x8080808080808088, 0x0000000000000018, 0x0000000000000038,
+    0xfff8f8f8f8f8fcfc, 0x0000000000000000, 0xfff0f0f0f0f0f1f1, 0xffe0e0e0e0e0e0e0,
+    0xffc0c0c0c0c0c0c0, 0xe080808080808088, 0xc000000000000018, 0x8000000000000038,
+    0xfffffcfdfcfcfcff, 0xfffff8faf8f8f8ff, 0xfffff1f5f1f1f1ff, 0xffffe0e8e0e0e0f8,
+    0xfff0c0d0c0c0c0f8, 0xe0e080a0808080f8, 0xc0c00040000000f8, 0x80808080000000f8,
+    0xfffffffcfcf8f8f8, 0xfffffff8f8f8f8f8, 0xfffffff1f1f0f8f8, 0xfffff8e0e0e0f8f8,
+    0xfff0f0c0c0c0f8f8, 0xe0e0e0808080e0f8, 0xc0c0c0000000c0f8, 0x80808000000080f8,
+    0xfffffcfcf8f8f8f8, 0xfffff8f8f8f8f8f8, 0xfffff1f1f0f8f8f8, 0xffffe0e0e0f8f8f8,
+    0xfff0c0c0c0f0f8f8, 0xe0e0808080e0e0f8, 0xc0c0000000c0c0f8, 0x80800000008080f8,
+    0xfffcfcf0f0f0f8f8, 0xfff8f8f0f0f0f8f8, 0xfff1f1f0f0f0f8f8, 0xffe0e0e0e0e0f8f8,
+    0xffc0c0c0c0c0f8f8, 0xe08080808080e0f8, 0xc00000000000c0f8, 0x80000000000080f8,
+    0xfcfce0e0e0e0e0f8, 0xf8f8e0e0e0e0e0f8, 0xf1f1e0e0e0e0e0f8, 0xe0e0e0e0e0e0e0f8,
+    0xc0c0c0c0c0c0c0f8, 0x80808080808080f8, 0x00000000000000f8, 0x00000000000000f8,
+    0xfcc0c0c0c0c0c0f8, 0xf8c0c0c0c0c0c0f8, 0xf1c0c0c0c0c0c0f8, 0xe0c0c0c0c0c0c0f8,
+    0xc0c0c0c0c0c0c0f8, 0x80808080808080f8, 0x00000000000000f8, 0x00000000000000f8,
+    0xfff8f8f8f8f8fcfc, 0xfff8f8f8f8f8f8f8, 0xfff0f0f0f0f0f1f1, 0xfff0f0f0f0f0e1e3,
+    0xffe0e0e0e0e0c5c7, 0xffc0c0c0c0c08d8f, 0xe080808080801d1f, 0xc080808080803d3f,
+    0xfffffcfffcf8fcfc, 0x0000000000000000, 0xfffff9fff9f0f1f1, 0xfffff1fdf1e0e1e3,
+    0xffffe0f8e0c0c5c7, 0xfff0c0f0c0808d8f, 0xe0e080e080001d1f, 0xc0c080c080003d3f,
+    0xfffffffefcf8fcff, 0xfffffffdf8f8f8ff, 0xfffffffbf1f0f1ff, 0xfffffff5e1e0e1ff,
+    0xfffff8e8c0c0c5ff, 0xfff0f0d080808dff, 0xe0e0e0a000001dff, 0xc0c0c0c000003dff,
+    0xfffffffcfcf8fdff, 0xfffffff8f8f8fdff, 0xfffffff1f1f0fdff, 0xffffffe1e1e0fdff,
+    0xfffff8c0c0c0fdff, 0xfff0f0808080fdff, 0xe0e0e0000000fdff, 0xc0c0c0000000fdff,
+    0xfffffcfcfcf8fdff, 0xfffff8f8f8f8fdff, 0xfffff1f1f1f8fdff, 0xffffe3e1e1f8fdff,
+    0xffffc0c0c0f8fdff, 0xfff0808080f0fdff, 0xe0e0000000e0fdff, 0xc0c0000000c0fdff,
+    0xfffcfcfcf8f8fdff, 0xfff8f8f8f8f8fdff, 0xfff1f1f1f8f8fdff, 0xffe3e1e1f0f8fdff,
+    0xffc7c0c0e0f8fdff, 0xff808080c0f0fdff, 0xe000000080e0fdff, 0xc000000080c0fdff,
+    0xfcfcfcf0f0f0fdff, 0xf8f8f8f0f0f0fdff, 0xf1f1f1f0f0f0fdff, 0xe3e1e1f0f0f0fdff,
+    0xc7c0c0e0e0e0fdff, 0x8f8080c0c0c0fdff, 0x000000808080fdff, 0x000000808080fdff,
+    0xfcfce0e0e0e0fdff, 0xf8f8e0e0e0e0fdff, 0xf1f1e0e0e0e0fdff, 0xe1e1e0e0e0e0fdff,
+    0xc0c0e0e0e0e0fdff, 0x8080c0c0c0c0fdff, 0x000080808080fdff, 0x000080808080fdff,
+    0xe0e0e0e0e0e0f0f0, 0xe0e0e0e0e0e0f0f8, 0xe0e0e0e0e0e0f1f1, 0xc0c0c0c0c0c0c1e3,
+    0xc0c0c0c0c0c0c1c1, 0x8080808080808181, 0x0000000000000011, 0x0000000000000031,
+    0xfff0f0f0f0f0f0f0, 0xfff0f0f0f0f0f8f8, 0x0000000000000000, 0xffe1e1e1e1e1e3e3,
+    0xffc1c1c1c1c1c1c1, 0xff80808080808181, 0xc000000000000011, 0x8000000000000031,
+    0xfffff0f1f0f0f0f1, 0xfffff8faf8f8f8ff, 0xfffff1f5f1f1f1ff, 0xffffe3ebe3e3e3ff,
+    0xffffc1d1c1c1c1f1, 0xffe080a0808081f1, 0xc0c00040000000f1, 0x80800080000000f1,
+    0xfffff1f0f0f0f1f1, 0xfffffff8f8f0f1f1, 0xfffffff1f1f1f1f1, 0xffffffe3e3e1f1f1,
+    0xfffff1c1c1c1f1f1, 0xffe0e0808080f1f1, 0xc0c0c0000000c0f1, 0x80808000000080f1,
+    0xfffff0f0f0f1f1f1, 0xfffff8f8f0f1f1f1, 0xfffff1f1f1f1f1f1, 0xffffe3e3e1f1f1f1,
+    0xffffc1c1c1f1f1f1, 0xffe0808080e0f1f1, 0xc0c0000000c0c0f1, 0x80800000008080f1,
+    0xfff0f0e0e0e0f1f1, 0xfff8f8e0e0e0f1f1, 0xfff1f1e0e0e0f1f1, 0xffe3e3e0e0e0f1f1,
+    0xffc1c1c0c0c0f1f1, 0xff8080808080f1f1, 0xc00000000000c0f1, 0x80000000000080f1,
+    0xf0f0c0c0c0c0c0f1, 0xf8f8c0c0c0c0c0f1, 0xf1f1c0c0c0c0c0f1, 0xe3e3c0c0c0c0c0f1,
+    0xc1c1c0c0c0c0c0f1, 0x80808080808080f1, 0x00000000000000f1, 0x00000000000000f1,
+    0xf0808080808080f1, 0xf8808080808080f1, 0xf1808080808080f1, 0xe3808080808080f1,
+    0xc1808080808080f1, 0x80808080808080f1, 0x00000000000000f1, 0x00000000000000f1,
+    0xfff0f0f0f0f0f8fc, 0xfff0f0f0f0f0f8f8, 0xfff1f1f1f1f1f1f1, 0xffe1e1e1e1e1e3e3,
+    0xffe1e1e1e1e1c3c7, 0xffc1c1c1c1c18b8f, 0xff80808080801b1f, 0xc000000000003b3f,
+    0xfffff8fbf8f0f8fc, 0xfffff9fff9f0f8f8, 0x0000000000000000, 0xfffff3fff3e1e3e3,
+    0xffffe3fbe3c1c3c7, 0xffffc1f1c1818b8f, 0xffe080e080001b1f, 0xc0c000c000003b3f,
+    0xfffffffaf8f0f8ff, 0xfffffffdf8f0f8ff, 0xfffffffbf1f1f1ff, 0xfffffff7e3e1e3ff,
+    0xffffffebc3c1c3ff, 0xfffff1d181818bff, 0xffe0e0a000001bff, 0xc0c0c04000003bff,
+    0xfffffff8f8f0fbff, 0xfffffff8f8f0fbff, 0xfffffff1f1f1fbff, 0xffffffe3e3e1fbff,
+    0xffffffc3c3c1fbff, 0xfffff1818181fbff, 0xffe0e0000000fbff, 0xc0c0c0000000fbff,
+    0xfffffcf8f8f1fbff, 0xfffff8f8f8f1fbff, 0xfffff1f1f1f1fbff, 0xffffe3e3e3f1fbff,
+    0xffffc7c3c3f1fbff, 0xffff818181f1fbff, 0xffe0000000e0fbff, 0xc0c0000000c0fbff,
+    0xfffcf8f8f0f1fbff, 0xfff8f8f8f1f1fbff, 0xfff1f1f1f1f1fbff, 0xffe3e3e3f1f1fbff,
+    0xffc7c3c3e1f1fbff, 0xff8f8181c1f1fbff, 0xff00000080e0fbff, 0xc000000000c0fbff,
+    0xfcf8f8e0e0e0fbff, 0xf8f8f8e0e0e0fbff, 0xf1f1f1e0e0e0fbff, 0xe3e3e3e0e0e0fbff,
+    0xc7c3c3e0e0e0fbff, 0x8f8181c0c0c0fbff, 0x1f0000808080fbff, 0x000000000000fbff,
+    0xf8f8c0c0c0c0fbff, 0xf8f8c0c0c0c0fbff, 0xf1f1c0c0c0c0fbff, 0xe3e3c0c0c0c0fbff,
+    0xc3c3c0c0c0c0fbff, 0x8181c0c0c0c0fbff, 0x000080808080fbff, 0x000000000000fbff,
+    0xc0c0c0c0c0c0e0e0, 0xc0c0c0c0c0c0e0e0, 0xc0c0c0c0c0c0e0f1, 0xc1c1c1c1c1c1e3e3,
+    0x81818181818183c7, 0x8181818181818383, 0x0101010101010303, 0x0000000000000023,
+    0xffc0c0c0c0c0e0e0, 0xffe0e0e0e0e0e0e0, 0xffe1e1e1e1e1f1f1, 0x0000000000000000,
+    0xffc3c3c3c3c3c7c7, 0xff83838383838383, 0xff01010101010303, 0x8000000000000023,
+    0xffc1c0c1c0c0e0e3, 0xffffe0e2e0e0e0e3, 0xfffff1f5f1f1f1ff, 0xffffe3ebe3e3e3ff,
+    0xffffc7d7c7c7c7ff, 0xffff83a3838383e3, 0xffc10141010103e3, 0x80800080000000e3,
+    0xffc1c1c0c0c0e3e3, 0xffffe3e0e0e0e3e3, 0xfffffff1f1e1e3e3, 0xffffffe3e3e3e3e3,
+    0xffffffc7c7c3e3e3, 0xffffe3838383e3e3, 0xffc1c1010101e3e3, 0x80808000000080e3,
+    0xffc1c0c0c0c1e3e3, 0xffffe0e0e0e3e3e3, 0xfffff1f1e1e3e3e3, 0xffffe3e3e3e3e3e3,
+    0xffffc7c7c3e3e3e3, 0xffff838383e3e3e3, 0xffc1010101c1e3e3, 0x80800000008080e3,
+    0xffc0c0c0c0c0e3e3, 0xffe0e0c0c0c0e3e3, 0xfff1f1c1c1c1e3e3, 0xffe3e3c1c1c1e3e3,
+    0xffc7c7c1c1c1e3e3, 0xff8383818181e3e3, 0xff0101010101e3e3, 0x80000000000080e3,
+    0xc0c08080808080e3, 0xe0e08080808080e3, 0xf1f18080808080e3, 0xe3e38080808080e3,
+    0xc7c78080808080e3, 0x83838080808080e3, 0x01010000000000e3, 0x00000000000000e3,
+    0xc0000000000000e3, 0xe0000000000000e3, 0xf1000000000000e3, 0xe3000000000000e3,
+    0xc7000000000000e3, 0x83000000000000e3, 0x01000000000000e3, 0x00000000000000e3,
+    0xffe0e0e0e0e0f4fc, 0xffe1e1e1e1e1f0f8, 0xffe1e1e1e1e1f1f1, 0xffe3e3e3e3e3e3e3,
+    0xffc3c3c3c3c3c7c7, 0xffc3c3c3c3c3878f, 0xff8383838383171f, 0xff0101010101373f,
+    0xffffe0e3e0e0f4fc, 0xfffff1f7f1e0f0f8, 0xfffff3fff3e1f1f1, 0x0000000000000000,
+    0xffffe7ffe7c3c7c7, 0xffffc7f7c783878f, 0xffff83e38303171f, 0xffc101c10101373f,
+    0xffffe3e2e0e0f4ff, 0xfffffff5f0e0f0ff, 0xfffffffbf1e1f1ff, 0xfffffff7e3e3e3ff,
+    0xffffffefc7c3c7ff, 0xffffffd7878387ff, 0xffffe3a3030317ff, 0xffc1c141010137ff,
+    0xffffe3e0e0e0f7ff, 0xfffffff0f0e0f7ff, 0xfffffff1f1e1f7ff, 0xffffffe3e3e3f7ff,
+    0xffffffc7c7c3f7ff, 0xffffff878783f7ff, 0xffffe3030303f7ff, 0xffc1c1010101f7ff,
+    0xffffe0e0e0e3f7ff, 0xfffff8f0f0e3f7ff, 0xfffff1f1f1e3f7ff, 0xffffe3e3e3e3f7ff,
+    0xffffc7c7c7e3f7ff, 0xffff8f8787e3f7ff, 0xffff030303e3f7ff, 0xffc1010101c1f7ff,
+    0xfffce0e0e0e3f7ff, 0xfff8f0f0e1e3f7ff, 0xfff1f1f1e3e3f7ff, 0xffe3e3e3e3e3f7ff,
+    0xffc7c7c7e3e3f7ff, 0xff8f8787c3e3f7ff, 0xff1f030383e3f7ff, 0xff01010101c1f7ff,
+    0xfce0e0c0c0c0f7ff, 0xf8f0f0c1c1c1f7ff, 0xf1f1f1c1c1c1f7ff, 0xe3e3e3c1c1c1f7ff,
+    0xc7c7c7c1c1c1f7ff, 0x8f8787c1c1c1f7ff, 0x1f0303818181f7ff, 0x3f0101010101f7ff,
+    0xe0e080808080f7ff, 0xf0f080808080f7ff, 0xf1f180808080f7ff, 0xe3e380808080f7ff,
+    0xc7c780808080f7ff, 0x878780808080f7ff, 0x030380808080f7ff, 0x010100000000f7ff,
+    0x80808080808080fc, 0x80808080808080f8, 0x80808080808080f1, 0x80808080808080e3,
+    0x80808080808080c7, 0x808080808080808f, 0x808080808080001f, 0x808080808080003f,
+    0x808080808080f0fc, 0x808080808080f8f8, 0x808080808080f0f1, 0x808080808080e0e3,
+    0x808080808080c0c7, 0x808080808080808f, 0x808080808000001f, 0x808080808000003f,
+    0x0000000000000000, 0x8080808080f8f8ff, 0x8080808080f0f0ff, 0x8080808080e0e0ff,
+    0x8080808080c0c0ff, 0x80808080808080ff, 0x80808080000000ff, 0x80808080000000ff,
+    0xc0c0c0c0f0f0ffff, 0xc0c0c0c0f8f8ffff, 0xc0c0c0c0f0f0fcff, 0xc0c0c0c0e0e0f8ff,
+    0xc0c0c0c0c0c0f0ff, 0x808080808080e0ff, 0x808080000000c0ff, 0x80808000000080ff,
+    0xe0e0e0f0f0f0fcff, 0xe0e0e0f8f8fcfcff, 0xe0e0e0f0f0f8f8ff, 0xe0e0e0e0e0f0f0ff,
+    0xc0c0c0c0c0e0e0ff, 0x8080808080c0c0ff, 0x80800000008080ff, 0x80800000008080ff,
+    0xf0f0f0f0f0f0f8ff, 0xf0f0f8f8f8f8f8ff, 0xf0f0f0f0f0f0f8ff, 0xe0e0e0e0e0e0f0ff,
+    0xc0c0c0c0c0c0e0ff, 0x808080808080c0ff, 0x80000000808080ff, 0x80000000808080ff,
+    0xf0f0f0f0f0f0f0ff, 0xf8f8f0f0f0f0f0ff, 0xf0f0f0f0f0f0f0ff, 0xe0e0e0e0e0e0e0ff,
+    0xc0c0c0c0c0c0c0ff, 0x80808080808080ff, 0x00000080808080ff, 0x00000080808080ff,
+    0xf0e0e0e0e0e0e0ff, 0xf8e0e0e0e0e0e0ff, 0xf0e0e0e0e0e0e0ff, 0xe0e0e0e0e0e0e0ff,
+    0xc0c0c0c0c0c0c0ff, 0x80808080808080ff, 0x00008080808080ff, 0x00008080808080ff,
+    0xc0c0c0c0c0c0fcfc, 0xc0c0c0c0c0c0f8f8, 0xc0c0c0c0c0c0f1f1, 0xc0c0c0c0c0c0e3e3,
+    0xc0c0c0c0c0c0c7c7, 0xc0c0c0c0c0c08f8f, 0xc0c0c0c0c0c01f1f, 0xc0c0c0c0c0c03f3f,
+    0xc0c0c0c0c0f8fcfc, 0xc0c0c0c0c0f8f8f8, 0xc0c0c0c0c0f0f1f1, 0xc0c0c0c0c0e0e3e3,
+    0xc0c0c0c0c0c0c7c7, 0xc0c0c0c0c0808f8f, 0xc0c0c0c0c0001f1f, 0xc0c0c0c0c0003f3f,
+    0x0000000000000000, 0xc0c0c0c0f8f8f8ff, 0xc0c0c0c0f0f0f1ff, 0xc0c0c0c0e0e0e3ff,
+    0xc0c0c0c0c0c0c7ff, 0xc0c0c0c080808fff, 0xc0c0c0c000001fff, 0xc0c0c0c000003fff,
+    0xe0e0e0f8f8fcffff, 0xe0e0e0f8f8f8ffff, 0xe0e0e0f0f0f0ffff, 0xe0e0e0e0e0e0ffff,
+    0xe0e0e0c0c0c0ffff, 0xc0c0c0808080ffff, 0xc0c0c0000000ffff, 0xc0c0c0000000ffff,
+    0xf0f0f8f8f8feffff, 0xf0f0f8f8f8feffff, 0xf0f0f0f0f0fcffff, 0xf0f0e0e0e0f8ffff,
+    0xe0e0c0c0c0f0ffff, 0xc0c0808080e0ffff, 0xc0c0000000c0ffff, 0xc0c0000000c0ffff,
+    0xf8f8f8f8f8fcffff, 0xf8f8f8f8fcfcffff, 0xf8f0f0f0f8fcffff, 0xf0e0e0e0f0f8ffff,
+    0xe0c0c0c0e0f0ffff, 0xc0808080c0e0ffff, 0xc0000000c0c0ffff, 0xc0000000c0c0ffff,
+    0xf8f8f8f8f8f8ffff, 0xf8f8f8f8f8f8ffff, 0xf0f0f0f8f8f8ffff, 0xe0e0e0f0f0f0ffff,
+    0xc0c0c0e0e0e0ffff, 0x808080c0c0c0ffff, 0x000000c0c0c0ffff, 0x000000c0c0c0ffff,
+    0xf8f8f0f0f0f0ffff, 0xf8f8f0f0f0f0ffff, 0xf0f0f0f0f0f0ffff, 0xe0e0f0f0f0f0ffff,
+    0xc0c0e0e0e0e0ffff, 0x8080c0c0c0c0ffff, 0x0000c0c0c0c0ffff, 0x0000c0c0c0c0ffff,
+    0xe0e0e0e0e0e0f0fc, 0xe0e0e0e0e0e0f0f8, 0xe0e0e0e0e0e0f0f1, 0xc0c0c0c0c0c0c0e3,
+    0xc0c0c0c0c0c0c0c7, 0x808080808080808f, 0x000000000000001f, 0x000000000000003f,
+    0xf0f0f0f0f0f8fcfc, 0xf0f0f0f0f0f8f8f8, 0xe0e0e0e0e0e0f1f1, 0xe0e0e0e0e0e0e0e3,
+    0xc0c0c0c0c0c0c0c7, 0x808080808080808f, 0x000000000000001f, 0x000000000000003f,
+    0xf8f8f8f8f8fcfcff, 0x0000000000000000, 0xf0f0f0f0f0f1f1ff, 0xe0e0e0e0e0e0e0ff,
+    0xc0c0c0c0c0c0c0ff, 0x80808080808080ff, 0x00000000000000ff, 0x00000000000000ff,
+    0xfffcfdfcfcfcffff, 0xfff8faf8f8f8ffff, 0xfff1f5f1f1f1ffff, 0xffe0e8e0e0e0f8ff,
+    0xf0c0d0c0c0c0f0ff, 0xe080a0808080e0ff, 0xc00040000000c0ff, 0x80808000000080ff,
+    0xfffffcfcf8f8f8ff, 0xfffff8f8f8f8f8ff, 0xfffff1f1f0f8f8ff, 0xfff8e0e0e0f0f0ff,
+    0xf0f0c0c0c0e0e0ff, 0xe0e0808080c0c0ff, 0xc0c00000008080ff, 0x80800000000000ff,
+    0xfffcfcf8f0f0f0ff, 0xfff8f8f8f0f0f0ff, 0xfff1f1f0f0f0f0ff, 0xffe0e0e0e0e0f0ff,
+    0xf0c0c0c0c0c0e0ff, 0xe08080808080c0ff, 0xc0000000000080ff, 0x80000000000000ff,
+    0xfcfcf0e0e0e0e0ff, 0xf8f8f0e0e0e0e0ff, 0xf1f1f0e0e0e0e0ff, 0xe0e0e0e0e0e0e0ff,
+    0xc0c0c0c0c0c0c0ff, 0x80808080808080ff, 0x00000000000000ff, 0x00000000000000ff,
+    0xfce0c0c0c0c0c0ff, 0xf8e0c0c0c0c0c0ff, 0xf1e0c0c0c0c0c0ff, 0xe0e0c0c0c0c0c0ff,
+    0xc0c0c0c0c0c0c0ff, 0x80808080808080ff, 0x00000000000000ff, 0x00000000000000ff,
+    0xf0f0f0f0f0f8fcfc, 0xf0f0f0f0f0f8f8f8, 0xf0f0f0f0f0f8f1f1, 0xe0e0e0e0e0e0e3e3,
+    0xe0e0e0e0e0e0c7c7, 0xc0c0c0c0c0c08f8f, 0x8080808080801f1f, 0x8080808080803f3f,
+    0xf8f8f8f8f8fcfcfc, 0xf8f8f8f8f8f8f8f8, 0xf0f0f0f0f0f1f1f1, 0xf0f0f0f0f0e1e3e3,
+    0xe0e0e0e0e0c0c7c7, 0xc0c0c0c0c0808f8f, 0x8080808080001f1f, 0x8080808080003f3f,
+    0xfffcfffcf8fcfcff, 0x0000000000000000, 0xfff9fff9f0f1f1ff, 0xfff1fdf1e0e1e3ff,
+    0xffe0f8e0c0c0c7ff, 0xf0c0f0c080808fff, 0xe080e08000001fff, 0xc080c08000003fff,
+    0xfffffefcf8fcffff, 0xfffffdf8f8f8ffff, 0xfffffbf1f0f1ffff, 0xfffff5e1e0e1ffff,
+    0xfff8e8c0c0c0ffff, 0xf0f0d0808080ffff, 0xe0e0a0000000ffff, 0xc0c0c0000000ffff,
+    0xfffffcfcf8fdffff, 0xfffff8f8f8fdffff, 0xfffff1f1f0fdffff, 0xffffe1e1e0f9ffff,
+    0xfff8c0c0c0f0ffff, 0xf0f0808080e0ffff, 0xe0e0000000c0ffff, 0xc0c000000080ffff,
+    0xfffcfcfcf8f8ffff, 0xfff8f8f8f8f8ffff, 0xfff1f1f1f8f8ffff, 0xffe3e1e1f0f8ffff,
+    0xffc0c0c0e0f0ffff, 0xf0808080c0e0ffff, 0xe000000080c0ffff, 0xc00000008080ffff,
+    0xfcfcfcf8f0f0ffff, 0xf8f8f8f8f0f0ffff, 0xf1f1f1f8f0f0ffff, 0xe3e1e1f0f0f0ffff,
+    0xc7c0c0e0e0e0ffff, 0x808080c0c0c0ffff, 0x000000808080ffff, 0x000000808080ffff,
+    0xfcfcf0e0e0e0ffff, 0xf8f8f0e0e0e0ffff, 0xf1f1f0e0e0e0ffff, 0xe1e1f0e0e0e0ffff,
+    0xc0c0e0e0e0e0ffff, 0x8080c0c0c0c0ffff, 0x000080808080ffff, 0x000080808080ffff,
+    0xc0c0c0c0c0c0e0fc, 0xc0c0c0c0c0c0e0f8, 0xc0c0c0c0c0c0e0f1, 0xc0c0c0c0c0c0e0e3,
+    0x80808080808080c7, 0x808080808080808f, 0x000000000000001f, 0x000000000000003f,
+    0xe0e0e0e0e0f0f0fc, 0xe0e0e0e0e0f0f8f8, 0xe0e0e0e0e0f1f1f1, 0xc0c0c0c0c0c1e3e3,
+    0xc0c0c0c0c0c1c1c7, 0x808080808080808f, 0x000000000000001f, 0x000000000000003f,
+    0xf0f0f0f0f0f0f0ff, 0xf0f0f0f0f0f8f8ff, 0x0000000000000000, 0xe1e1e1e1e1e3e3ff,
+    0xc1c1c1c1c1c1c1ff, 0x80808080808080ff, 0x00000000000000ff, 0x00000000000000ff,
+    0xfff0f1f0f0f0f1ff, 0xfff8faf8f8f8ffff, 0xfff1f5f1f1f1ffff, 0xffe3ebe3e3e3ffff,
+    0xffc1d1c1c1c1f1ff, 0xe080a0808080e0ff, 0xc00040000000c0ff, 0x80008000000080ff,
+    0xfff1f0f0f0f0f0ff, 0xfffff8f8f0f1f1ff, 0xfffff1f1f1f1f1ff, 0xffffe3e3e1f1f1ff,
+    0xfff1c1c1c1e1e1ff, 0xe0e0808080c0c0ff, 0xc0c00000008080ff, 0x80800000000000ff,
+    0xfff0f0f0e0e0e0ff, 0xfff8f8f0e0e0e0ff, 0xfff1f1f1e0e0e0ff, 0xffe3e3e1e0e0e0ff,
+    0xffc1c1c1c0c0e0ff, 0xe08080808080c0ff, 0xc0000000000080ff, 0x80000000000000ff,
+    0xf0f0e0c0c0c0c0ff, 0xf8f8e0c0c0c0c0ff, 0xf1f1e0c0c0c0c0ff, 0xe3e3e0c0c0c0c0ff,
+    0xc1c1c0c0c0c0c0ff, 0x80808080808080ff, 0x00000000000000ff, 0x00000000000000ff,
+    0xf0c08080808080ff, 0xf8c08080808080ff, 0xf1c08080808080ff, 0xe3c08080808080ff,
+    0xc1c08080808080ff, 0x80808080808080ff, 0x00000000000000ff, 0x00000000000000ff,
+    0xe0e0e0e0e0f0fcfc, 0xe0e0e0e0e0f1f8f8, 0xe0e0e0e0e0f1f1f1, 0xe0e0e0e0e0f1e3e3,
+    0xc0c0c0c0c0c1c7c7, 0xc0c0c0c0c0c18f8f, 0x8080808080801f1f, 0x0000000000003f3f,
+    0xf0f0f0f0f0f8fcfc, 0xf0f0f0f0f0f8f8f8, 0xf1f1f1f1f1f1f1f1, 0xe1e1e1e1e1e3e3e3,
+    0xe1e1e1e1e1c3c7c7, 0xc1c1c1c1c1818f8f, 0x8080808080001f1f, 0x0000000000003f3f,
+    0xfff8fbf8f0f8fcff, 0xfff9fff9f0f8f8ff, 0x0000000000000000, 0xfff3fff3e1e3e3ff,
+    0xffe3fbe3c1c3c7ff, 0xffc1f1c181818fff, 0xe080e08000001fff, 0xc000c00000003fff,
+    0xfffffaf8f0f8ffff, 0xfffffdf8f0f8ffff, 0xfffffbf1f1f1ffff, 0xfffff7e3e1e3ffff,
+    0xffffebc3c1c3ffff, 0xfff1d1818181ffff, 0xe0e0a0000000ffff, 0xc0c040000000ffff,
+    0xfffff8f8f0f9ffff, 0xfffff8f8f0fbffff, 0xfffff1f1f1fbffff, 0xffffe3e3e1fbffff,
+    0xffffc3c3c1f3ffff, 0xfff1818181e1ffff, 0xe0e0000000c0ffff, 0xc0c000000080ffff,
+    0xfffcf8f8f0f1ffff, 0xfff8f8f8f1f1ffff, 0xfff1f1f1f1f1ffff, 0xffe3e3e3f1f1ffff,
+    0xffc7c3c3e1f1ffff, 0xff818181c1e1ffff, 0xe000000080c0ffff, 0xc00000000080ffff,
+    0xfcf8f8f0e0e0ffff, 0xf8f8f8f1e0e0ffff, 0xf1f1f1f1e0e0ffff, 0xe3e3e3f1e0e0ffff,
+    0xc7c3c3e1e0e0ffff, 0x8f8181c1c0c0ffff, 0x000000808080ffff, 0x000000000000ffff,
+    0xf8f8e0c0c0c0ffff, 0xf8f8e0c0c0c0ffff, 0xf1f1e0c0c0c0ffff, 0xe3e3e0c0c0c0ffff,
+    0xc3c3e0c0c0c0ffff, 0x8181c0c0c0c0ffff, 0x000080808080ffff, 0x000000000000ffff,
+    0x808080808080c0fc, 0x808080808080c0f8, 0x808080808080c1f1, 0x808080808080c1e3,
+    0x808080808080c1c7, 0x000000000000018f, 0x000000000000011f, 0x000000000000003f,
+    0xc0c0c0c0c0c0c0fc, 0xc0c0c0c0c0e0e0f8, 0xc0c0c0c0c0e0f1f1, 0xc1c1c1c1c1e3e3e3,
+    0x818181818183c7c7, 0x818181818183838f, 0x010101010101011f, 0x000000000000003f,
+    0xc0c0c0c0c0c0c0ff, 0xe0e0e0e0e0e0e0ff, 0xe1e1e1e1e1f1f1ff, 0x0000000000000000,
+    0xc3c3c3c3c3c7c7ff, 0x83838383838383ff, 0x01010101010101ff, 0x00000000000000ff,
+    0xc1c0c1c0c0c0c1ff, 0xffe0e2e0e0e0e3ff, 0xfff1f5f1f1f1ffff, 0xffe3ebe3e3e3ffff,
+    0xffc7d7c7c7c7ffff, 0xff83a3838383e3ff, 0xc10141010101c1ff, 0x80008000000080ff,
+    0xc1c1c0c0c0c0c0ff, 0xffe3e0e0e0e1e1ff, 0xfffff1f1e1e3e3ff, 0xffffe3e3e3e3e3ff,
+    0xffffc7c7c3e3e3ff, 0xffe3838383c3c3ff, 0xc1c10101018181ff, 0x80800000000000ff,
+    0xc1c0c0c0c0c0c0ff, 0xffe0e0e0c0c0c1ff, 0xfff1f1e1c1c1c1ff, 0xffe3e3e3c1c1c1ff,
+    0xffc7c7c3c1c1c1ff, 0xff8383838181c1ff, 0xc1010101010181ff, 0x80000000000000ff,
+    0xc0c0c080808080ff, 0xe0e0c080808080ff, 0xf1f1c180808080ff, 0xe3e3c180808080ff,
+    0xc7c7c180808080ff, 0x83838180808080ff, 0x01010100000000ff, 0x00000000000000ff,
+    0xc0800000000000ff, 0xe0800000000000ff, 0xf1800000000000ff, 0xe3800000000000ff,
+    0xc7800000000000ff, 0x83800000000000ff, 0x01000000000000ff, 0x00000000000000ff,
+    0xc0c0c0c0c0e0fcfc, 0xc0c0c0c0c0e0f8f8, 0xc1c1c1c1c1e3f1f1, 0xc1c1c1c1c1e3e3e3,
+    0xc1c1c1c1c1e3c7c7, 0x8181818181838f8f, 0x8181818181831f1f, 0x0101010101013f3f,
+    0xe0e0e0e0e0e0fcfc, 0xe1e1e1e1e1f0f8f8, 0xe1e1e1e1e1f1f1f1, 0xe3e3e3e3e3e3e3e3,
+    0xc3c3c3c3c3c7c7c7, 0xc3c3c3c3c3878f8f, 0x8383838383031f1f, 0x0101010101013f3f,
+    0xffe0e3e0e0e0fcff, 0xfff1f7f1e0f0f8ff, 0xfff3fff3e1f1f1ff, 0x0000000000000000,
+    0xffe7ffe7c3c7c7ff, 0xffc7f7c783878fff, 0xff83e38303031fff, 0xc101c10101013fff,
+    0xffe3e2e0e0e0ffff, 0xfffff5f0e0f0ffff, 0xfffffbf1e1f1ffff, 0xfffff7e3e3e3ffff,
+    0xffffefc7c3c7ffff, 0xffffd7878387ffff, 0xffe3a3030303ffff, 0xc1c141010101ffff,
+    0xffe3e0e0e0e1ffff, 0xfffff0f0e0f3ffff, 0xfffff1f1e1f7ffff, 0xffffe3e3e3f7ffff,
+    0xffffc7c7c3f7ffff, 0xffff878783e7ffff, 0xffe3030303c3ffff, 0xc1c101010181ffff,
+    0xffe0e0e0e0e1ffff, 0xfff8f0f0e1e3ffff, 0xfff1f1f1e3e3ffff, 0xffe3e3e3e3e3ffff,
+    0xffc7c7c7e3e3ffff, 0xff8f8787c3e3ffff, 0xff03030383c3ffff, 0xc10101010181ffff,
+    0xfce0e0e0c0c0ffff, 0xf8f0f0e1c1c1ffff, 0xf1f1f1e3c1c1ffff, 0xe3e3e3e3c1c1ffff,
+    0xc7c7c7e3c1c1ffff, 0x8f8787c3c1c1ffff, 0x1f0303838181ffff, 0x010101010101ffff,
+    0xe0e0c0808080ffff, 0xf0f0c1808080ffff, 0xf1f1c1808080ffff, 0xe3e3c1808080ffff,
+    0xc7c7c1808080ffff, 0x8787c1808080ffff, 0x030381808080ffff, 0x010101000000ffff,
+    0xc0c0c0c0c0c0fcfc, 0xc0c0c0c0c0c0f8f8, 0xc0c0c0c0c0c0f1f1, 0xc0c0c0c0c0c0e3e3,
+    0xc0c0c0c0c0c0c7c7, 0xc0c0c0c0c0c08f8f, 0xc0c0c0c0c0c01f1f, 0xc0c0c0c0c0c03f3f,
+    0xc0c0c0c0c0c0fcfc, 0xc0c0c0c0c0c0f8f8, 0xc0c0c0c0c0c0f1f1, 0xc0c0c0c0c0c0e3e3,
+    0xc0c0c0c0c0c0c7c7, 0xc0c0c0c0c0808f8f, 0xc0c0c0c0c0001f1f, 0xc0c0c0c0c0003f3f,
+    0xc0c0c0c0c0f0fcff, 0xc0c0c0c0c0f8f8ff, 0xc0c0c0c0c0f0f1ff, 0xc0c0c0c0c0e0e3ff,
+    0xc0c0c0c0c0c0c7ff, 0xc0c0c0c080808fff, 0xc0c0c0c000001fff, 0xc0c0c0c000003fff,
+    0x0000000000000000, 0xc0c0c0c0f8f8ffff, 0xc0c0c0c0f0f0ffff, 0xc0c0c0c0e0e0ffff,
+    0xc0c0c0c0c0c0ffff, 0xc0c0c0808080ffff, 0xc0c0c0000000ffff, 0xc0c0c0000000ffff,
+    0xe0e0e0f0f0ffffff, 0xe0e0e0f8f8ffffff, 0xe0e0e0f0f0fcffff, 0xe0e0e0e0e0f8ffff,
+    0xc0c0c0c0c0f0ffff, 0xc0c0808080e0ffff, 0xc0c0000000c0ffff, 0xc0c0000000c0ffff,
+    0xf0f0f0f0f0fcffff, 0xf0f0f8f8fcfcffff, 0xf0f0f0f0f8f8ffff, 0xe0e0e0e0f0f0ffff,
+    0xc0c0c0c0e0e0ffff, 0xc0808080c0c0ffff, 0xc0000000c0c0ffff, 0xc0000000c0c0ffff,
+    0xf0f0f0f0f0f8ffff, 0xf8f8f8f8f8f8ffff, 0xf0f0f0f0f0f8ffff, 0xe0e0e0e0e0f0ffff,
+    0xc0c0c0c0c0e0ffff, 0x808080c0c0c0ffff, 0x000000c0c0c0ffff, 0x000000c0c0c0ffff,
+    0xf0f0f0f0f0f0ffff, 0xf8f0f0f0f0f0ffff, 0xf0f0f0f0f0f0ffff, 0xe0e0e0e0e0e0ffff,
+    0xc0c0c0c0c0c0ffff, 0x8080c0c0c0c0ffff, 0x0000c0c0c0c0ffff, 0x0000c0c0c0c0ffff,
+    0xe0e0e0e0e0fffcfc, 0xe0e0e0e0e0fff8f8, 0xe0e0e0e0e0fff1f1, 0xe0e0e0e0e0ffe3e3,
+    0xe0e0e0e0e0ffc7c7, 0xe0e0e0e0e0ff8f8f, 0xe0e0e0e0e0ff1f1f, 0xe0e0e0e0e0ff3f3f,
+    0xe0e0e0e0e0fcfcfc, 0xe0e0e0e0e0f8f8f8, 0xe0e0e0e0e0f1f1f1, 0xe0e0e0e0e0e3e3e3,
+    0xe0e0e0e0e0c7c7c7, 0xe0e0e0e0e08f8f8f, 0xe0e0e0e0e01f1f1f, 0xe0e0e0e0e03f3f3f,
+    0xe0e0e0e0f8fcfcff, 0xe0e0e0e0f8f8f8ff, 0xe0e0e0e0f0f1f1ff, 0xe0e0e0e0e0e3e3ff,
+    0xe0e0e0e0c0c7c7ff, 0xe0e0e0e0808f8fff, 0xe0e0e0e0001f1fff, 0xe0e0e0e0203f3fff,
+    0x0000000000000000, 0xe0e0e0f8f8f8ffff, 0xe0e0e0f0f0f1ffff, 0xe0e0e0e0e0e3ffff,
+    0xe0e0e0c0c0c7ffff, 0xe0e0e080808fffff, 0xe0e0e000001fffff, 0xe0e0e020203fffff,
+    0xf0f0f8f8fcffffff, 0xf0f0f8f8f8ffffff, 0xf0f0f0f0f0ffffff, 0xf0f0e0e0e0ffffff,
+    0xe0e0c0c0c0ffffff, 0xe0e0808080ffffff, 0xe0e0000000ffffff, 0xe0e0202020ffffff,
+    0xf8f8f8f8feffffff, 0xf8f8f8f8feffffff, 0xf8f0f0f0fcffffff, 0xf0e0e0e0f8ffffff,
+    0xe0c0c0c0f0ffffff, 0xe0808080e0ffffff, 0xe0000000e0ffffff, 0xe0202020e0ffffff,
+    0xf8f8f8f8fcffffff, 0xf8f8f8fcfcffffff, 0xf0f0f0f8fcffffff, 0xe0e0e0f0f8ffffff,
+    0xc0c0c0e0f0ffffff, 0x808080e0e0ffffff, 0x000000e0e0ffffff, 0x202020e0e0ffffff,
+    0xf8f8f8f8f8ffffff, 0xf8f8f8f8f8ffffff, 0xf0f0f8f8f8ffffff, 0xe0e0f0f0f0ffffff,
+    0xc0c0e0e0e0ffffff, 0x8080e0e0e0ffffff, 0x0000e0e0e0ffffff, 0x2020e0e0e0ffffff,
+    0xc0c0c0c0c0c0fcfc, 0xc0c0c0c0c0c0f8f8, 0xc0c0c0c0c0c0f1f1, 0xc0c0c0c0c0c0e3e3,
+    0x808080808080c7c7, 0x8080808080808f8f, 0x8080808080801f1f, 0x8080808080803f3f,
+    0xe0e0e0e0e0f0fcfc, 0xe0e0e0e0e0f0f8f8, 0xe0e0e0e0e0f0f1f1, 0xc0c0c0c0c0c0e3e3,
+    0xc0c0c0c0c0c0c7c7, 0x8080808080808f8f, 0x8080808080001f1f, 0x8080808080003f3f,
+    0xf0f0f0f0f8fcfcff, 0xf0f0f0f0f8f8f8ff, 0xe0e0e0e0e0f1f1ff, 0xe0e0e0e0e0e0e3ff,
+    0xc0c0c0c0c0c0c7ff, 0x8080808080808fff, 0x8080808000001fff, 0x8080808000003fff,
+    0xf8f8f8f8fcfcffff, 0x0000000000000000, 0xf0f0f0f0f1f1ffff, 0xe0e0e0e0e0e0ffff,
+    0xc0c0c0c0c0c0ffff, 0x808080808080ffff, 0x808080000000ffff, 0x808080000000ffff,
+    0xfcfdfcfcfcffffff, 0xf8faf8f8f8ffffff, 0xf1f5f1f1f1ffffff, 0xe0e8e0e0e0f8ffff,
+    0xc0d0c0c0c0f0ffff, 0x80a0808080e0ffff, 0xc0c0000000c0ffff, 0x808000000080ffff,
+    0xfffcfcf8f8f8ffff, 0xfff8f8f8f8f8ffff, 0xfff1f1f0f8f8ffff, 0xf8e0e0e0f0f0ffff,
+    0xf0c0c0c0e0e0ffff, 0xe0808080c0c0ffff, 0xc00000008080ffff, 0x800000008080ffff,
+    0xfcfcf8f0f0f0ffff, 0xf8f8f8f0f0f0ffff, 0xf1f1f0f0f0f0ffff, 0xe0e0e0e0e0f0ffff,
+    0xc0c0c0c0c0e0ffff, 0x8080808080c0ffff, 0x000000808080ffff, 0x000000808080ffff,
+    0xfcf0e0e0e0e0ffff, 0xf8f0e0e0e0e0ffff, 0xf1f0e0e0e0e0ffff, 0xe0e0e0e0e0e0ffff,
+    0xc0c0c0c0c0c0ffff, 0x808080808080ffff, 0x000080808080ffff, 0x000080808080ffff,
+    0xe0e0e0e0e0fffcfc, 0xe0e0e0e0e0fff8f8, 0xe0e0e0e0e0fff1f1, 0xe0e0e0e0e0ffe3e3,
+    0xc0c0c0c0c0ffc7c7, 0xc0c0c0c0c0ff8f8f, 0xc0c0c0c0c0ff1f1f, 0xc0c0c0c0c0ff3f3f,
+    0xf0f0f0f0f8fcfcfc, 0xf0f0f0f0f8f8f8f8, 0xf0f0f0f0f8f1f1f1, 0xe0e0e0e0e0e3e3e3,
+    0xe0e0e0e0e0c7c7c7, 0xc0c0c0c0c08f8f8f, 0xc0c0c0c0c01f1f1f, 0xc0c0c0c0c03f3f3f,
+    0xf8f8f8f8fcfcfcff, 0xf8f8f8f8f8f8f8ff, 0xf0f0f0f0f1f1f1ff, 0xf0f0f0f0e1e3e3ff,
+    0xe0e0e0e0c0c7c7ff, 0xc0c0c0c0808f8fff, 0xc0c0c0c0001f1fff, 0xc0c0c0c0003f3fff,
+    0xfcfffcf8fcfcffff, 0x0000000000000000, 0xf9fff9f0f1f1ffff, 0xf1fdf1e0e1e3ffff,
+    0xe0f8e0c0c0c7ffff, 0xc0f0c080808fffff, 0xc0e0c000001fffff, 0xc0c0c000003fffff,
+    0xfffefcf8fcffffff, 0xfffdf8f8f8ffffff, 0xfffbf1f0f1ffffff, 0xfff5e1e0e1ffffff,
+    0xf8e8c0c0c0ffffff, 0xf0d0808080ffffff, 0xe0e0000000ffffff, 0xc0c0000000ffffff,
+    0xfffcfcf8fdffffff, 0xfff8f8f8fdffffff, 0xfff1f1f0fdffffff, 0xffe1e1e0f9ffffff,
+    0xf8c0c0c0f0ffffff, 0xf0808080e0ffffff, 0xe0000000c0ffffff, 0xc0000000c0ffffff,
+    0xfcfcfcf8f8ffffff, 0xf8f8f8f8f8ffffff, 0xf1f1f1f8f8ffffff, 0xe3e1e1f0f8ffffff,
+    0xc0c0c0e0f0ffffff, 0x808080c0e0ffffff, 0x000000c0c0ffffff, 0x000000c0c0ffffff,
+    0xfcfcf8f0f0ffffff, 0xf8f8f8f0f0ffffff, 0xf1f1f8f0f0ffffff, 0xe1e1f0f0f0ffffff,
+    0xc0c0e0e0e0ffffff, 0x8080c0c0c0ffffff, 0x0000c0c0c0ffffff, 0x0000c0c0c0ffffff,
+    0x808080808080fcfc, 0x808080808080f8f8, 0x808080808080f1f1, 0x808080808080e3e3,
+    0x808080808080c7c7, 0x0000000000008f8f, 0x0000000000001f1f, 0x0000000000003f3f,
+    0xc0c0c0c0c0e0fcfc, 0xc0c0c0c0c0e0f8f8, 0xc0c0c0c0c0e0f1f1, 0xc0c0c0c0c0e0e3e3,
+    0x808080808080c7c7, 0x8080808080808f8f, 0x0000000000001f1f, 0x0000000000003f3f,
+    0xe0e0e0e0f0f0fcff, 0xe0e0e0e0f0f8f8ff, 0xe0e0e0e0f1f1f1ff, 0xc0c0c0c0c1e3e3ff,
+    0xc0c0c0c0c1c1c7ff, 0x8080808080808fff, 0x0000000000001fff, 0x0000000000003fff,
+    0xf0f0f0f0f0f0ffff, 0xf0f0f0f0f8f8ffff, 0x0000000000000000, 0xe1e1e1e1e3e3ffff,
+    0xc1c1c1c1c1c1ffff, 0x808080808080ffff, 0x000000000000ffff, 0x000000000000ffff,
+    0xf0f1f0f0f0f1ffff, 0xf8faf8f8f8ffffff, 0xf1f5f1f1f1ffffff, 0xe3ebe3e3e3ffffff,
+    0xc1d1c1c1c1f1ffff, 0x80a0808080e0ffff, 0x0040000000c0ffff, 0x808000000080ffff,
+    0xf1f0f0f0f0f0ffff, 0xfff8f8f0f1f1ffff, 0xfff1f1f1f1f1ffff, 0xffe3e3e1f1f1ffff,
+    0xf1c1c1c1e1e1ffff, 0xe0808080c0c0ffff, 0xc00000008080ffff, 0x800000000000ffff,
+    0xf0f0f0e0e0e0ffff, 0xf8f8f0e0e0e0ffff, 0xf1f1f1e0e0e0ffff, 0xe3e3e1e0e0e0ffff,
+    0xc1c1c1c0c0e0ffff, 0x8080808080c0ffff, 0x000000000080ffff, 0x000000000000ffff,
+    0xf0e0c0c0c0c0ffff, 0xf8e0c0c0c0c0ffff, 0xf1e0c0c0c0c0ffff, 0xe3e0c0c0c0c0ffff,
+    0xc1c0c0c0c0c0ffff, 0x808080808080ffff, 0x000000000000ffff, 0x000000000000ffff,
+    0xc0c0c0c0c0fffcfc, 0xc0c0c0c0c0fff8f8, 0xc0c0c0c0c0fff1f1, 0xc0c0c0c0c0ffe3e3,
+    0xc0c0c0c0c0ffc7c7, 0x8080808080ff8f8f, 0x8080808080ff1f1f, 0x8080808080ff3f3f,
+    0xe0e0e0e0f0fcfcfc, 0xe0e0e0e0f1f8f8f8, 0xe0e0e0e0f1f1f1f1, 0xe0e0e0e0f1e3e3e3,
+    0xc0c0c0c0c1c7c7c7, 0xc0c0c0c0c18f8f8f, 0x80808080801f1f1f, 0x80808080803f3f3f,
+    0xf0f0f0f0f8fcfcff, 0xf0f0f0f0f8f8f8ff, 0xf1f1f1f1f1f1f1ff, 0xe1e1e1e1e3e3e3ff,
+    0xe1e1e1e1c3c7c7ff, 0xc1c1c1c1818f8fff, 0x80808080001f1fff, 0x80808080003f3fff,
+    0xf8fbf8f0f8fcffff, 0xf9fff9f0f8f8ffff, 0x0000000000000000, 0xf3fff3e1e3e3ffff,
+    0xe3fbe3c1c3c7ffff, 0xc1f1c181818fffff, 0x80e08000001fffff, 0x80c08000003fffff,
+    0xfffaf8f0f8ffffff, 0xfffdf8f0f8ffffff, 0xfffbf1f1f1ffffff, 0xfff7e3e1e3ffffff,
+    0xffebc3c1c3ffffff, 0xf1d1818181ffffff, 0xe0a0000000ffffff, 0xc0c0000000ffffff,
+    0xfff8f8f0f9ffffff, 0xfff8f8f0fbffffff, 0xfff1f1f1fbffffff, 0xffe3e3e1fbffffff,
+    0xffc3c3c1f3ffffff, 0xf1818181e1ffffff, 0xe0000000c0ffffff, 0xc000000080ffffff,
+    0xfcf8f8f0f1ffffff, 0xf8f8f8f1f1ffffff, 0xf1f1f1f1f1ffffff, 0xe3e3e3f1f1ffffff,
+    0xc7c3c3e1f1ffffff, 0x818181c1e1ffffff, 0x00000080c0ffffff, 0x0000008080ffffff,
+    0xf8f8f0e0e0ffffff, 0xf8f8f1e0e0ffffff, 0xf1f1f1e0e0ffffff, 0xe3e3f1e0e0ffffff,
+    0xc3c3e1e0e0ffffff, 0x8181c1c0c0ffffff, 0x0000808080ffffff, 0x0000808080ffffff,
+    0x000000000000fcfc, 0x000000000000f8f8, 0x000000000000f1f1, 0x000000000000e3e3,
+    0x000000000000c7c7, 0x0000000000008f8f, 0x0000000000001f1f, 0x0000000000003f3f,
+    0x8080808080c0fcfc, 0x8080808080c0f8f8, 0x8080808080c1f1f1, 0x8080808080c1e3e3,
+    0x8080808080c1c7c7, 0x0000000000018f8f, 0x0000000000011f1f, 0x0000000000003f3f,
+    0xc0c0c0c0c0c0fcff, 0xc0c0c0c0e0e0f8ff, 0xc0c0c0c0e0f1f1ff, 0xc1c1c1c1e3e3e3ff,
+    0x8181818183c7c7ff, 0x8181818183838fff, 0x0101010101011fff, 0x0000000000003fff,
+    0xc0c0c0c0c0c0ffff, 0xe0e0e0e0e0e0ffff, 0xe1e1e1e1f1f1ffff, 0x0000000000000000,
+    0xc3c3c3c3c7c7ffff, 0x838383838383ffff, 0x010101010101ffff, 0x000000000000ffff,
+    0xc0c1c0c0c0c1ffff, 0xe0e2e0e0e0e3ffff, 0xf1f5f1f1f1ffffff, 0xe3ebe3e3e3ffffff,
+    0xc7d7c7c7c7ffffff, 0x83a3838383e3ffff, 0x0141010101c1ffff, 0x008000000080ffff,
+    0xc1c0c0c0c0c0ffff, 0xe3e0e0e0e1e1ffff, 0xfff1f1e1e3e3ffff, 0xffe3e3e3e3e3ffff,
+    0xffc7c7c3e3e3ffff, 0xe3838383c3c3ffff, 0xc10101018181ffff, 0x800000000000ffff,
+    0xc0c0c0c0c0c0ffff, 0xe0e0e0c0c0c1ffff, 0xf1f1e1c1c1c1ffff, 0xe3e3e3c1c1c1ffff,
+    0xc7c7c3c1c1c1ffff, 0x8383838181c1ffff, 0x010101010181ffff, 0x000000000000ffff,
+    0xc0c080808080ffff, 0xe0c080808080ffff, 0xf1c180808080ffff, 0xe3c180808080ffff,
+    0xc7c180808080ffff, 0x838180808080ffff, 0x010100000000ffff, 0x000000000000ffff,
+    0x8080808080fffcfc, 0x8080808080fff8f8, 0x8080808080fff1f1, 0x8080808080ffe3e3,
+    0x8080808080ffc7c7, 0x8080808080ff8f8f, 0x0000000000ff1f1f, 0x0000000000ff3f3f,
+    0xc0c0c0c0e0fcfcfc, 0xc0c0c0c0e0f8f8f8, 0xc1c1c1c1e3f1f1f1, 0xc1c1c1c1e3e3e3e3,
+    0xc1c1c1c1e3c7c7c7, 0x81818181838f8f8f, 0x81818181831f1f1f, 0x01010101013f3f3f,
+    0xe0e0e0e0e0fcfcff, 0xe1e1e1e1f0f8f8ff, 0xe1e1e1e1f1f1f1ff, 0xe3e3e3e3e3e3e3ff,
+    0xc3c3c3c3c7c7c7ff, 0xc3c3c3c3878f8fff, 0x83838383031f1fff, 0x01010101013f3fff,
+    0xe0e3e0e0e0fcffff, 0xf1f7f1e0f0f8ffff, 0xf3fff3e1f1f1ffff, 0x0000000000000000,
+    0xe7ffe7c3c7c7ffff, 0xc7f7c783878fffff, 0x83e38303031fffff, 0x01c10101013fffff,
+    0xe3e2e0e0e0ffffff, 0xfff5f0e0f0ffffff, 0xfffbf1e1f1ffffff, 0xfff7e3e3e3ffffff,
+    0xffefc7c3c7ffffff, 0xffd7878387ffffff, 0xe3a3030303ffffff, 0xc141010101ffffff,
+    0xe3e0e0e0e1ffffff, 0xfff0f0e0f3ffffff, 0xfff1f1e1f7ffffff, 0xffe3e3e3f7ffffff,
+    0xffc7c7c3f7ffffff, 0xff878783e7ffffff, 0xe3030303c3ffffff, 0xc101010181ffffff,
+    0xe0e0e0e0e1ffffff, 0xf8f0f0e1e3ffffff, 0xf1f1f1e3e3ffffff, 0xe3e3e3e3e3ffffff,
+    0xc7c7c7e3e3ffffff, 0x8f8787c3e3ffffff, 0x03030383c3ffffff, 0x0101010181ffffff,
+    0xe0e0e0c0c0ffffff, 0xf0f0e1c1c1ffffff, 0xf1f1e3c1c1ffffff, 0xe3e3e3c1c1ffffff,
+    0xc7c7e3c1c1ffffff, 0x8787c3c1c1ffffff, 0x0303838181ffffff, 0x0101010101ffffff,
+    0xe0e0e0e0e0fffcfc, 0xe0e0e0e0e0fff8f8, 0xe0e0e0e0e0fff1f1, 0xe0e0e0e0e0ffe3e3,
+    0xe0e0e0e0e0ffc7c7, 0xe0e0e0e0e0ff8f8f, 0xe0e0e0e0e0ff1f1f, 0xe0e0e0e0e0ff3f3f,
+    0xe0e0e0e0e0fcfcfc, 0xe0e0e0e0e0f8f8f8, 0xe0e0e0e0e0f1f1f1, 0xe0e0e0e0e0e3e3e3,
+    0xe0e0e0e0e0c7c7c7, 0xe0e0e0e0e08f8f8f, 0xe0e0e0e0e01f1f1f, 0xe0e0e0e0e03f3f3f,
+    0xe0e0e0e0e0fcfcff, 0xe0e0e0e0e0f8f8ff, 0xe0e0e0e0e0f1f1ff, 0xe0e0e0e0e0e3e3ff,
+    0xe0e0e0e0c0c7c7ff, 0xe0e0e0e0808f8fff, 0xe0e0e0e0001f1fff, 0xe0e0e0e0203f3fff,
+    0xe0e0e0e0f0fcffff, 0xe0e0e0e0f8f8ffff, 0xe0e0e0e0f0f1ffff, 0xe0e0e0e0e0e3ffff,
+    0xe0e0e0c0c0c7ffff, 0xe0e0e080808fffff, 0xe0e0e000001fffff, 0xe0e0e020203fffff,
+    0x0000000000000000, 0xe0e0e0f8f8ffffff, 0xe0e0e0f0f0ffffff, 0xe0e0e0e0e0ffffff,
+    0xe0e0c0c0c0ffffff, 0xe0e0808080ffffff, 0xe0e0000000ffffff, 0xe0e0202020ffffff,
+    0xf0f0f0f0ffffffff, 0xf0f0f8f8ffffffff, 0xf0f0f0f0fcffffff, 0xe0e0e0e0f8ffffff,
+    0xe0c0c0c0f0ffffff, 0xe0808080e0ffffff, 0xe0000000e0ffffff, 0xe0202020e0ffffff,
+    0xf0f0f0f0fcffffff, 0xf8f8f8fcfcffffff, 0xf0f0f0f8f8ffffff, 0xe0e0e0f0f0ffffff,
+    0xc0c0c0e0e0ffffff, 0x808080e0e0ffffff, 0x000000e0e0ffffff, 0x202020e0e0ffffff,
+    0xf0f0f0f0f8ffffff, 0xf8f8f8f8f8ffffff, 0xf0f0f0f0f8ffffff, 0xe0e0e0e0f0ffffff,
+    0xc0c0e0e0e0ffffff, 0x8080e0e0e0ffffff, 0x0000e0e0e0ffffff, 0x2020e0e0e0ffffff,
+    0xf0f0f0f0fffffcfc, 0xf0f0f0f0fffff8f8, 0xf0f0f0f0fffff1f1, 0xf0f0f0f0ffffe3e3,
+    0xf0f0f0f0ffffc7c7, 0xf0f0f0f0ffff8f8f, 0xf0f0f0f0ffff1f1f, 0xf0f0f0f0ffff3f3f,
+    0xf0f0f0f0fffcfcfc, 0xf0f0f0f0fff8f8f8, 0xf0f0f0f0fff1f1f1, 0xf0f0f0f0ffe3e3e3,
+    0xf0f0f0f0ffc7c7c7, 0xf0f0f0f0ff8f8f8f, 0xf0f0f0f0ff1f1f1f, 0xf0f0f0f0ff3f3f3f,
+    0xf0f0f0f0fcfcfcff, 0xf0f0f0f0f8f8f8ff, 0xf0f0f0f0f1f1f1ff, 0xf0f0f0f0e3e3e3ff,
+    0xf0f0f0f0c7c7c7ff, 0xf0f0f0f08f8f8fff, 0xf0f0f0f01f1f1fff, 0xf0f0f0f03f3f3fff,
+    0xf0f0f0f8fcfcffff, 0xf0f0f0f8f8f8ffff, 0xf0f0f0f0f1f1ffff, 0xf0f0f0e0e3e3ffff,
+    0xf0f0f0c0c7c7ffff, 0xf0f0f0808f8fffff, 0xf0f0f0101f1fffff, 0xf0f0f0303f3fffff,
+    0x0000000000000000, 0xf0f0f8f8f8ffffff, 0xf0f0f0f0f1ffffff, 0xf0f0e0e0e3ffffff,
+    0xf0f0c0c0c7ffffff, 0xf0f080808fffffff, 0xf0f010101fffffff, 0xf0f030303fffffff,
+    0xf8f8f8fcffffffff, 0xf8f8f8f8ffffffff, 0xf8f0f0f0ffffffff, 0xf0e0e0e0ffffffff,
+    0xf0c0c0c0ffffffff, 0xf0808080ffffffff, 0xf0101010ffffffff, 0xf0303030ffffffff,
+    0xf8f8f8feffffffff, 0xf8f8f8feffffffff, 0xf0f0f0fcffffffff, 0xe0e0e0f8ffffffff,
+    0xc0c0c0f0ffffffff, 0x808080f0ffffffff, 0x101010f0ffffffff, 0x303030f0ffffffff,
+    0xf8f8f8fcffffffff, 0xf8f8fcfcffffffff, 0xf0f0f8fcffffffff, 0xe0e0f0f8ffffffff,
+    0xc0c0f0f0ffffffff, 0x8080f0f0ffffffff, 0x1010f0f0ffffffff, 0x3030f0f0ffffffff,
+    0xc0c0c0c0c0fffcfc, 0xc0c0c0c0c0fff8f8, 0xc0c0c0c0c0fff1f1, 0xc0c0c0c0c0ffe3e3,
+    0xc0c0c0c0c0ffc7c7, 0xc0c0c0c0c0ff8f8f, 0xc0c0c0c0c0ff1f1f, 0xc0c0c0c0c0ff3f3f,
+    0xc0c0c0c0c0fcfcfc, 0xc0c0c0c0c0f8f8f8, 0xc0c0c0c0c0f1f1f1, 0xc0c0c0c0c0e3e3e3,
+    0xc0c0c0c0c0c7c7c7, 0xc0c0c0c0c08f8f8f, 0xc0c0c0c0c01f1f1f, 0xc0c0c0c0c03f3f3f,
+    0xe0e0e0e0f0fcfcff, 0xe0e0e0e0f0f8f8ff, 0xe0e0e0e0f0f1f1ff, 0xc0c0c0c0c0e3e3ff,
+    0xc0c0c0c0c0c7c7ff, 0xc0c0c0c0808f8fff, 0xc0c0c0c0001f1fff, 0xc0c0c0c0003f3fff,
+    0xf0f0f0f8fcfcffff, 0xf0f0f0f8f8f8ffff, 0xe0e0e0e0f1f1ffff, 0xe0e0e0e0e0e3ffff,
+    0xe0c0c0c0c0c7ffff, 0xc0c0c080808fffff, 0xc0c0c000001fffff, 0xc0c0c000003fffff,
+    0xf8f8f8fcfcffffff, 0x0000000000000000, 0xf0f0f0f1f1ffffff, 0xf0f0e0e0e0ffffff,
+    0xe0e0c0c0c0ffffff, 0xc0c0808080ffffff, 0xc0c0000000ffffff, 0xc0c0000000ffffff,
+    0xfffcfcfcffffffff, 0xfff8f8f8ffffffff, 0xfff1f1f1ffffffff, 0xf8e0e0e0f8ffffff,
+    0xf0c0c0c0f0ffffff, 0xe0808080e0ffffff, 0xc0000000c0ffffff, 0xc0000000c0ffffff,
+    0xfcfcf8f8f8ffffff, 0xf8f8f8f8f8ffffff, 0xf1f1f0f8f8ffffff, 0xe0e0e0f0f0ffffff,
+    0xc0c0c0e0e0ffffff, 0x808080c0c0ffffff, 0x000000c0c0ffffff, 0x000000c0c0ffffff,
+    0xfcf8f0f0f0ffffff, 0xf8f8f0f0f0ffffff, 0xf1f0f0f0f0ffffff, 0xe0e0e0e0f0ffffff,
+    0xc0c0c0c0e0ffffff, 0x8080c0c0c0ffffff, 0x0000c0c0c0ffffff, 0x0000c0c0c0ffffff,
+    0xe0e0e0e0fffffcfc, 0xe0e0e0e0fffff8f8, 0xe0e0e0e0fffff1f1, 0xe0e0e0e0ffffe3e3,
+    0xe0e0e0e0ffffc7c7, 0xe0e0e0e0ffff8f8f, 0xe0e0e0e0ffff1f1f, 0xe0e0e0e0ffff3f3f,
+    0xe0e0e0e0fffcfcfc, 0xe0e0e0e0fff8f8f8, 0xe0e0e0e0fff1f1f1, 0xe0e0e0e0ffe3e3e3,
+    0xe0e0e0e0ffc7c7c7, 0xe0e0e0e0ff8f8f8f, 0xe0e0e0e0ff1f1f1f, 0xe0e0e0e0ff3f3f3f,
+    0xf0f0f0f8fcfcfcff, 0xf0f0f0f8f8f8f8ff, 0xf0f0f0f8f1f1f1ff, 0xe0e0e0e0e3e3e3ff,
+    0xe0e0e0e0c7c7c7ff, 0xe0e0e0e08f8f8fff, 0xe0e0e0e01f1f1fff, 0xe0e0e0e03f3f3fff,
+    0xf8f8f8fcfcfcffff, 0xf8f8f8f8f8f8ffff, 0xf0f0f0f1f1f1ffff, 0xf0f0f0e1e3e3ffff,
+    0xf0f0e0c0c7c7ffff, 0xe0e0e0808f8fffff, 0xe0e0e0001f1fffff, 0xe0e0e0203f3fffff,
+    0xfffcf8fcfcffffff, 0x0000000000000000, 0xfff9f0f1f1ffffff, 0xfff9e0e1e3ffffff,
+    0xf8f0c0c0c7ffffff, 0xf0e080808fffffff, 0xe0e000001fffffff, 0xe0e020203fffffff,
+    0xfffcf8fcffffffff, 0xfff8f8f8ffffffff, 0xfff1f0f1ffffffff, 0xffe1e0e1ffffffff,
+    0xf8c0c0c0ffffffff, 0xf0808080ffffffff, 0xe0000000ffffffff, 0xe0202020ffffffff,
+    0xfcfcf8fdffffffff, 0xf8f8f8fdffffffff, 0xf1f1f0fdffffffff, 0xe3e1e0f9ffffffff,
+    0xc0c0c0f0ffffffff, 0x808080e0ffffffff, 0x000000e0ffffffff, 0x202020e0ffffffff,
+    0xfcfcf8f8ffffffff, 0xf8f8f8f8ffffffff, 0xf1f1f8f8ffffffff, 0xe1e1f0f8ffffffff,
+    0xc0c0e0f0ffffffff, 0x8080e0e0ffffffff, 0x0000e0e0ffffffff, 0x2020e0e0ffffffff,
+    0x8080808080fffcfc, 0x8080808080fff8f8, 0x8080808080fff1f1, 0x8080808080ffe3e3,
+    0x8080808080ffc7c7, 0x8080808080ff8f8f, 0x8080808080ff1f1f, 0x8080808080ff3f3f,
+    0x8080808080fcfcfc, 0x8080808080f8f8f8, 0x8080808080f1f1f1, 0x8080808080e3e3e3,
+    0x8080808080c7c7c7, 0x80808080808f8f8f, 0x80808080801f1f1f, 0x80808080803f3f3f,
+    0xc0c0c0c0e0fcfcff, 0xc0c0c0c0e0f8f8ff, 0xc0c0c0c0e0f1f1ff, 0xc0c0c0c0e0e3e3ff,
+    0x8080808080c7c7ff, 0x80808080808f8fff, 0x80808080001f1fff, 0x80808080003f3fff,
+    0xe0e0e0f0f0fcffff, 0xe0e0e0f0f8f8ffff, 0xe0e0e0f1f1f1ffff, 0xc0c0c0c1e3e3ffff,
+    0xc0c0c0c1c1c7ffff, 0xc0808080808fffff, 0x80808000001fffff, 0x80808000003fffff,
+    0xf0f0f0f0f0ffffff, 0xf0f0f0f8f8ffffff, 0x0000000000000000, 0xe1e1e1e3e3ffffff,
+    0xe1e1c1c1c1ffffff, 0xc0c0808080ffffff, 0x8080000000ffffff, 0x8080000000ffffff,
+    0xf1f0f0f0f1ffffff, 0xfff8f8f8ffffffff, 0xfff1f1f1ffffffff, 0xffe3e3e3ffffffff,
+    0xf1c1c1c1f1ffffff, 0xe0808080e0ffffff, 0xc0000000c0ffffff, 0x8000000080ffffff,
+    0xf0f0f0f0f0ffffff, 0xf8f8f0f1f1ffffff, 0xf1f1f1f1f1ffffff, 0xe3e3e1f1f1ffffff,
+    0xc1c1c1e1e1ffffff, 0x808080c0c0ffffff, 0x0000008080ffffff, 0x0000008080ffffff,
+    0xf0f0e0e0e0ffffff, 0xf8f0e0e0e0ffffff, 0xf1f1e0e0e0ffffff, 0xe3e1e0e0e0ffffff,
+    0xc1c1c0c0e0ffffff, 0x80808080c0ffffff, 0x0000808080ffffff, 0x0000808080ffffff,
+    0xc0c0c0c0fffffcfc, 0xc0c0c0c0fffff8f8, 0xc0c0c0c0fffff1f1, 0xc0c0c0c0ffffe3e3,
+    0xc0c0c0c0ffffc7c7, 0xc0c0c0c0ffff8f8f, 0xc0c0c0c0ffff1f1f, 0xc0c0c0c0ffff3f3f,
+    0xc0c0c0c0fffcfcfc, 0xc0c0c0c0fff8f8f8, 0xc0c0c0c0fff1f1f1, 0xc0c0c0c0ffe3e3e3,
+    0xc0c0c0c0ffc7c7c7, 0xc0c0c0c0ff8f8f8f, 0xc0c0c0c0ff1f1f1f, 0xc0c0c0c0ff3f3f3f,
+    0xe0e0e0f0fcfcfcff, 0xe0e0e0f1f8f8f8ff, 0xe0e0e0f1f1f1f1ff, 0xe0e0e0f1e3e3e3ff,
+    0xc0c0c0c1c7c7c7ff, 0xc0c0c0c18f8f8fff, 0xc0c0c0c01f1f1fff, 0xc0c0c0c03f3f3fff,
+    0xf0f0f0f8fcfcffff, 0xf0f0f0f8f8f8ffff, 0xf1f1f1f1f1f1ffff, 0xe1e1e1e3e3e3ffff,
+    0xe1e1e1c3c7c7ffff, 0xe1e1c1818f8fffff, 0xc0c0c0001f1fffff, 0xc0c0c0003f3fffff,
+    0xfff9f0f8fcffffff, 0xfff9f0f8f8ffffff, 0x0000000000000000, 0xfff3e1e3e3ffffff,
+    0xfff3c1c3c7ffffff, 0xf1e181818fffffff, 0xe0c000001fffffff, 0xc0c000003fffffff,
+    0xfff8f0f8ffffffff, 0xfff8f0f8ffffffff, 0xfff1f1f1ffffffff, 0xffe3e1e3ffffffff,
+    0xffc3c1c3ffffffff, 0xf1818181ffffffff, 0xe0000000ffffffff, 0xc0000000ffffffff,
+    0xfcf8f0f9ffffffff, 0xf8f8f0fbffffffff, 0xf1f1f1fbffffffff, 0xe3e3e1fbffffffff,
+    0xc7c3c1f3ffffffff, 0x818181e1ffffffff, 0x000000c0ffffffff, 0x000000c0ffffffff,
+    0xf8f8f0f1ffffffff, 0xf8f8f1f1ffffffff, 0xf1f1f1f1ffffffff, 0xe3e3f1f1ffffffff,
+    0xc3c3e1f1ffffffff, 0x8181c1e1ffffffff, 0x0000c0c0ffffffff, 0x0000c0c0ffffffff,
+    0x0000000000fffcfc, 0x0000000000fff8f8, 0x0000000000fff1f1, 0x0000000000ffe3e3,
+    0x0000000000ffc7c7, 0x0000000000ff8f8f, 0x0000000000ff1f1f, 0x0000000000ff3f3f,
+    0x0000000000fcfcfc, 0x0000000000f8f8f8, 0x0000000000f1f1f1, 0x0000000000e3e3e3,
+    0x0000000000c7c7c7, 0x00000000008f8f8f, 0x00000000001f1f1f, 0x00000000003f3f3f,
+    0x80808080c0fcfcff, 0x80808080c0f8f8ff, 0x80808080c1f1f1ff, 0x80808080c1e3e3ff,
+    0x80808080c1c7c7ff, 0x00000000018f8fff, 0x00000000011f1fff, 0x00000000003f3fff,
+    0xc0c0c0c0c0fcffff, 0xc0c0c0e0e0f8ffff, 0xc0c0c0e0f1f1ffff, 0xc1c1c1e3e3e3ffff,
+    0x81818183c7c7ffff, 0x81818183838fffff, 0x81010101011fffff, 0x00000000003fffff,
+    0xc0c0c0c0c0ffffff, 0xe1e1e0e0e0ffffff, 0xe1e1e1f1f1ffffff, 0x0000000000000000,
+    0xc3c3c3c7c7ffffff, 0xc3c3838383ffffff, 0x8181010101ffffff, 0x0000000000ffffff,
+    0xc1c0c0c0c1ffffff, 0xe3e0e0e0e3ffffff, 0xfff1f1f1ffffffff, 0xffe3e3e3ffffffff,
+    0xffc7c7c7ffffffff, 0xe3838383e3ffffff, 0xc1010101c1ffffff, 0x8000000080ffffff,
+    0xc0c0c0c0c0ffffff, 0xe0e0e0e1e1ffffff, 0xf1f1e1e3e3ffffff, 0xe3e3e3e3e3ffffff,
+    0xc7c7c3e3e3ffffff, 0x838383c3c3ffffff, 0x0101018181ffffff, 0x0000000000ffffff,
+    0xc0c0c0c0c0ffffff, 0xe0e0c0c0c1ffffff, 0xf1e1c1c1c1ffffff, 0xe3e3c1c1c1ffffff,
+    0xc7c3c1c1c1ffffff, 0x83838181c1ffffff, 0x0101010181ffffff, 0x0000000000ffffff,
+    0x80808080fffffcfc, 0x80808080fffff8f8, 0x80808080fffff1f1, 0x80808080ffffe3e3,
+    0x80808080ffffc7c7, 0x80808080ffff8f8f, 0x80808080ffff1f1f, 0x80808080ffff3f3f,
+    0x80808080fffcfcfc, 0x80808080fff8f8f8, 0x80808080fff1f1f1, 0x80808080ffe3e3e3,
+    0x80808080ffc7c7c7, 0x80808080ff8f8f8f, 0x80808080ff1f1f1f, 0x80808080ff3f3f3f,
+    0xc0c0c0e0fcfcfcff, 0xc0c0c0e0f8f8f8ff, 0xc1c1c1e3f1f1f1ff, 0xc1c1c1e3e3e3e3ff,
+    0xc1c1c1e3c7c7c7ff, 0x818181838f8f8fff, 0x818181831f1f1fff, 0x818181813f3f3fff,
+    0xe1e1e0e0fcfcffff, 0xe1e1e1f0f8f8ffff, 0xe1e1e1f1f1f1ffff, 0xe3e3e3e3e3e3ffff,
+    0xc3c3c3c7c7c7ffff, 0xc3c3c3878f8fffff, 0xc3c383031f1fffff, 0x818181013f3fffff,
+    0xe3e1e0e0fcffffff, 0xfff3e0f0f8ffffff, 0xfff3e1f1f1ffffff, 0x0000000000000000,
+    0xffe7c3c7c7ffffff, 0xffe783878fffffff, 0xe3c303031fffffff, 0xc18101013fffffff,
+    0xe3e0e0e0ffffffff, 0xfff0e0f0ffffffff, 0xfff1e1f1ffffffff, 0xffe3e3e3ffffffff,
+    0xffc7c3c7ffffffff, 0xff878387ffffffff, 0xe3030303ffffffff, 0xc1010101ffffffff,
+    0xe0e0e0e1ffffffff, 0xf8f0e0f3ffffffff, 0xf1f1e1f7ffffffff, 0xe3e3e3f7ffffffff,
+    0xc7c7c3f7ffffffff, 0x8f8783e7ffffffff, 0x030303c3ffffffff, 0x01010181ffffffff,
+    0xe0e0e0e1ffffffff, 0xf0f0e1e3ffffffff, 0xf1f1e3e3ffffffff, 0xe3e3e3e3ffffffff,
+    0xc7c7e3e3ffffffff, 0x8787c3e3ffffffff, 0x030383c3ffffffff, 0x01018181ffffffff,
+    0xf0f0f0f0fffffcfc, 0xf0f0f0f0fffff8f8, 0xf0f0f0f0fffff1f1, 0xf0f0f0f0ffffe3e3,
+    0xf0f0f0f0ffffc7c7, 0xf0f0f0f0ffff8f8f, 0xf0f0f0f0ffff1f1f, 0xf0f0f0f0ffff3f3f,
+    0xf0f0f0f0fffcfcfc, 0xf0f0f0f0fff8f8f8, 0xf0f0f0f0fff1f1f1, 0xf0f0f0f0ffe3e3e3,
+    0xf0f0f0f0ffc7c7c7, 0xf0f0f0f0ff8f8f8f, 0xf0f0f0f0ff1f1f1f, 0xf0f0f0f0ff3f3f3f,
+    0xf0f0f0f0fcfcfcff, 0xf0f0f0f0f8f8f8ff, 0xf0f0f0f0f1f1f1ff, 0xf0f0f0f0e3e3e3ff,
+    0xf0f0f0f0c7c7c7ff, 0xf0f0f0f08f8f8fff, 0xf0f0f0f01f1f1fff, 0xf0f0f0f03f3f3fff,
+    0xf0f0f0f0fcfcffff, 0xf0f0f0f0f8f8ffff, 0xf0f0f0f0f1f1ffff, 0xf0f0f0e0e3e3ffff,
+    0xf0f0f0c0c7c7ffff, 0xf0f0f0808f8fffff, 0xf0f0f0101f1fffff, 0xf0f0f0303f3fffff,
+    0xf0f0f0f0fcffffff, 0xf0f0f0f8f8ffffff, 0xf0f0f0f0f1ffffff, 0xf0f0e0e0e3ffffff,
+    0xf0f0c0c0c7ffffff, 0xf0f080808fffffff, 0xf0f010101fffffff, 0xf0f030303fffffff,
+    0x0000000000000000, 0xf8f8f8f8ffffffff, 0xf8f0f0f0ffffffff, 0xf0e0e0e0ffffffff,
+    0xf0c0c0c0ffffffff, 0xf0808080ffffffff, 0xf0101010ffffffff, 0xf0303030ffffffff,
+    0xf0f0f0ffffffffff, 0xf8f8f8ffffffffff, 0xf0f0f0fcffffffff, 0xe0e0e0f8ffffffff,
+    0xc0c0c0f0ffffffff, 0x808080f0ffffffff, 0x101010f0ffffffff, 0x303030f0ffffffff,
+    0xf0f0f0f8ffffffff, 0xf8f8fcfcffffffff, 0xf0f0f8f8ffffffff, 0xe0e0f0f0ffffffff,
+    0xc0c0f0f0ffffffff, 0x8080f0f0ffffffff, 0x1010f0f0ffffffff, 0x3030f0f0ffffffff,
+    0xf8f8f8fffffffcfc, 0xf8f8f8fffffff8f8, 0xf8f8f8fffffff1f1, 0xf8f8f8ffffffe3e3,
+    0xf8f8f8ffffffc7c7, 0xf8f8f8ffffff8f8f, 0xf8f8f8ffffff1f1f, 0xf8f8f8ffffff3f3f,
+    0xf8f8f8fffffcfcfc, 0xf8f8f8fffff8f8f8, 0xf8f8f8fffff1f1f1, 0xf8f8f8ffffe3e3e3,
+    0xf8f8f8ffffc7c7c7, 0xf8f8f8ffff8f8f8f, 0xf8f8f8ffff1f1f1f, 0xf8f8f8ffff3f3f3f,
+    0xf8f8f8fffcfcfcff, 0xf8f8f8fff8f8f8ff, 0xf8f8f8fff1f1f1ff, 0xf8f8f8ffe3e3e3ff,
+    0xf8f8f8ffc7c7c7ff, 0xf8f8f8ff8f8f8fff, 0xf8f8f8ff1f1f1fff, 0xf8f8f8ff3f3f3fff,
+    0xf8f8f8fcfcfcffff, 0xf8f8f8f8f8f8ffff, 0xf8f8f8f1f1f1ffff, 0xf8f8f8e3e3e3ffff,
+    0xf8f8f8c7c7c7ffff, 0xf8f8f88f8f8fffff, 0xf8f8f81f1f1fffff, 0xf8f8f83f3f3fffff,
+    0xf8f8f8fcfcffffff, 0xf8f8f8f8f8ffffff, 0xf8f8f0f1f1ffffff, 0xf8f8e0e3e3ffffff,
+    0xf8f8c0c7c7ffffff, 0xf8f8888f8fffffff, 0xf8f8181f1fffffff, 0xf8f8383f3fffffff,
+    0x0000000000000000, 0xfcf8f8f8ffffffff, 0xfcf0f0f1ffffffff, 0xf8e0e0e3ffffffff,
+    0xf8c0c0c7ffffffff, 0xf888888fffffffff, 0xf818181fffffffff, 0xf838383fffffffff,
+    0xf8f8fcffffffffff, 0xf8f8f8ffffffffff, 0xf0f0f0ffffffffff, 0xe0e0e0ffffffffff,
+    0xc0c0c0ffffffffff, 0x888888ffffffffff, 0x181818ffffffffff, 0x383838ffffffffff,
+    0xf8f8fcffffffffff, 0xf8f8feffffffffff, 0xf0f0fcffffffffff, 0xe0e0f8ffffffffff,
+    0xc0c0f8ffffffffff, 0x8888f8ffffffffff, 0x1818f8ffffffffff, 0x3838f8ffffffffff,
+    0xe0e0e0e0fffffcfc, 0xe0e0e0e0fffff8f8, 0xe0e0e0e0fffff1f1, 0xe0e0e0e0ffffe3e3,
+    0xe0e0e0e0ffffc7c7, 0xe0e0e0e0ffff8f8f, 0xe0e0e0e0ffff1f1f, 0xe0e0e0e0ffff3f3f,
+    0xe0e0e0e0fffcfcfc, 0xe0e0e0e0fff8f8f8, 0xe0e0e0e0fff1f1f1, 0xe0e0e0e0ffe3e3e3,
+    0xe0e0e0e0ffc7c7c7, 0xe0e0e0e0ff8f8f8f, 0xe0e0e0e0ff1f1f1f, 0xe0e0e0e0ff3f3f3f,
+    0xe0e0e0e0fcfcfcff, 0xe0e0e0e0f8f8f8ff, 0xe0e0e0e0f1f1f1ff, 0xe0e0e0e0e3e3e3ff,
+    0xe0e0e0e0c7c7c7ff, 0xe0e0e0e08f8f8fff, 0xe0e0e0e01f1f1fff, 0xe0e0e0e03f3f3fff,
+    0xf0f0f0f0fcfcffff, 0xf0f0f0f0f8f8ffff, 0xf0f0f0f0f1f1ffff, 0xf0e0e0e0e3e3ffff,
+    0xf0e0e0c0c7c7ffff, 0xe0e0e0808f8fffff, 0xe0e0e0001f1fffff, 0xe0e0e0203f3fffff,
+    0xf8f8f8fcfcffffff, 0xf8f8f8f8f8ffffff, 0xf8f8f0f1f1ffffff, 0xf8f8e0e0e3ffffff,
+    0xf0f0c0c0c7ffffff, 0xe0e080808fffffff, 0xe0e000001fffffff, 0xe0e020203fffffff,
+    0xfdfcfcfcffffffff, 0x0000000000000000, 0xf5f0f1f1ffffffff, 0xf8e0e0e0ffffffff,
+    0xf0c0c0c0ffffffff, 0xe0808080ffffffff, 0xe0000000ffffffff, 0xe0202020ffffffff,
+    0xfcfcfcffffffffff, 0xf8f8f8ffffffffff, 0xf0f1f1ffffffffff, 0xe0e0e0f8ffffffff,
+    0xc0c0c0f0ffffffff, 0x808080e0ffffffff, 0x000000e0ffffffff, 0x202020e0ffffffff,
+    0xfcf8f8f8ffffffff, 0xf8f8f8f8ffffffff, 0xf0f0f8f8ffffffff, 0xe0e0f0f0ffffffff,
+    0xc0c0e0e0ffffffff, 0x8080e0e0ffffffff, 0x0000e0e0ffffffff, 0x2020e0e0ffffffff,
+    0xf0f0f0fffffffcfc, 0xf0f0f0fffffff8f8, 0xf0f0f0fffffff1f1, 0xf0f0f0ffffffe3e3,
+    0xf0f0f0ffffffc7c7, 0xf0f0f0ffffff8f8f, 0xf0f0f0ffffff1f1f, 0xf0f0f0ffffff3f3f,
+    0xf0f0f0fffffcfcfc, 0xf0f0f0fffff8f8f8, 0xf0f0f0fffff1f1f1, 0xf0f0f0ffffe3e3e3,
+    0xf0f0f0ffffc7c7c7, 0xf0f0f0ffff8f8f8f, 0xf0f0f0ffff1f1f1f, 0xf0f0f0ffff3f3f3f,
+    0xf0f0f0fffcfcfcff, 0xf0f0f0fff8f8f8ff, 0xf0f0f0fff1f1f1ff, 0xf0f0f0ffe3e3e3ff,
+    0xf0f0f0ffc7c7c7ff, 0xf0f0f0ff8f8f8fff, 0xf0f0f0ff1f1f1fff, 0xf0f0f0ff3f3f3fff,
+    0xf8f8f8fcfcfcffff, 0xf8f8f8f8f8f8ffff, 0xf8f8f8f1f1f1ffff, 0xf8f8f0e3e3e3ffff,
+    0xf8f8f0c7c7c7ffff, 0xf0f0f08f8f8fffff, 0xf0f0f01f1f1fffff, 0xf0f0f03f3f3fffff,
+    0xfdf8fcfcfcffffff, 0xfdf8f8f8f8ffffff, 0xfdf8f1f1f1ffffff, 0xfdf8e1e3e3ffffff,
+    0xf8f8c0c7c7ffffff, 0xf0f0808f8fffffff, 0xf0f0101f1fffffff, 0xf0f0303f3fffffff,
+    0xfef8fcfcffffffff, 0x0000000000000000, 0xfaf0f1f1ffffffff, 0xfde0e1e3ffffffff,
+    0xf8c0c0c7ffffffff, 0xf080808fffffffff, 0xf010101fffffffff, 0xf030303fffffffff,
+    0xfcf8fcffffffffff, 0xf8f8f8ffffffffff, 0xf1f0f1ffffffffff, 0xe1e0e1ffffffffff,
+    0xc0c0c0ffffffffff, 0x808080ffffffffff, 0x101010ffffffffff, 0x303030ffffffffff,
+    0xfcf8fdffffffffff, 0xf8f8fdffffffffff, 0xf1f0fdffffffffff, 0xe0e0f9ffffffffff,
+    0xc0c0f0ffffffffff, 0x8080f0ffffffffff, 0x1010f0ffffffffff, 0x3030f0ffffffffff,
+    0xc0c0c0c0fffffcfc, 0xc0c0c0c0fffff8f8, 0xc0c0c0c0fffff1f1, 0xc0c0c0c0ffffe3e3,
+    0xc0c0c0c0ffffc7c7, 0xc0c0c0c0ffff8f8f, 0xc0c0c0c0ffff1f1f, 0xc0c0c0c0ffff3f3f,
+    0xc0c0c0c0fffcfcfc, 0xc0c0c0c0fff8f8f8, 0xc0c0c0c0fff1f1f1, 0xc0c0c0c0ffe3e3e3,
+    0xc0c0c0c0ffc7c7c7, 0xc0c0c0c0ff8f8f8f, 0xc0c0c0c0ff1f1f1f, 0xc0c0c0c0ff3f3f3f,
+    0xc0c0c0c0fcfcfcff, 0xc0c0c0c0f8f8f8ff, 0xc0c0c0c0f1f1f1ff, 0xc0c0c0c0e3e3e3ff,
+    0xc0c0c0c0c7c7c7ff, 0xc0c0c0c08f8f8fff, 0xc0c0c0c01f1f1fff, 0xc0c0c0c03f3f3fff,
+    0xe0e0e0e0fcfcffff, 0xe0e0e0e0f8f8ffff, 0xe0e0e0e0f1f1ffff, 0xe0e0e0e0e3e3ffff,
+    0xe0c0c0c0c7c7ffff, 0xe0c0c0808f8fffff, 0xc0c0c0001f1fffff, 0xc0c0c0003f3fffff,
+    0xf1f1f0f0fcffffff, 0xf1f1f0f8f8ffffff, 0xf1f1f1f1f1ffffff, 0xf1f1e1e3e3ffffff,
+    0xf1f1c1c1c7ffffff, 0xe0e080808fffffff, 0xc0c000001fffffff, 0xc0c000003fffffff,
+    0xf1f0f0f0ffffffff, 0xfaf8f8f8ffffffff, 0x0000000000000000, 0xebe3e3e3ffffffff,
+    0xf1c1c1c1ffffffff, 0xe0808080ffffffff, 0xc0000000ffffffff, 0xc0000000ffffffff,
+    0xf0f0f0f1ffffffff, 0xf8f8f8ffffffffff, 0xf1f1f1ffffffffff, 0xe3e3e3ffffffffff,
+    0xc1c1c1f1ffffffff, 0x808080e0ffffffff, 0x000000c0ffffffff, 0x000000c0ffffffff,
+    0xf0f0f0f0ffffffff, 0xf8f0f1f1ffffffff, 0xf1f1f1f1ffffffff, 0xe3e1f1f1ffffffff,
+    0xc1c1e1e1ffffffff, 0x8080c0c0ffffffff, 0x0000c0c0ffffffff, 0x0000c0c0ffffffff,
+    0xe0e0e0fffffffcfc, 0xe0e0e0fffffff8f8, 0xe0e0e0fffffff1f1, 0xe0e0e0ffffffe3e3,
+    0xe0e0e0ffffffc7c7, 0xe0e0e0ffffff8f8f, 0xe0e0e0ffffff1f1f, 0xe0e0e0ffffff3f3f,
+    0xe0e0e0fffffcfcfc, 0xe0e0e0fffff8f8f8, 0xe0e0e0fffff1f1f1, 0xe0e0e0ffffe3e3e3,
+    0xe0e0e0ffffc7c7c7, 0xe0e0e0ffff8f8f8f, 0xe0e0e0ffff1f1f1f, 0xe0e0e0ffff3f3f3f,
+    0xe0e0e0fffcfcfcff, 0xe0e0e0fff8f8f8ff, 0xe0e0e0fff1f1f1ff, 0xe0e0e0ffe3e3e3ff,
+    0xe0e0e0ffc7c7c7ff, 0xe0e0e0ff8f8f8fff, 0xe0e0e0ff1f1f1fff, 0xe0e0e0ff3f3f3fff,
+    0xf1f1f0fcfcfcffff, 0xf1f1f1f8f8f8ffff, 0xf1f1f1f1f1f1ffff, 0xf1f1f1e3e3e3ffff,
+    0xf1f1e1c7c7c7ffff, 0xf1f1e18f8f8fffff, 0xe0e0e01f1f1fffff, 0xe0e0e03f3f3fffff,
+    0xfbf1f8fcfcffffff, 0xfbf1f8f8f8ffffff, 0xfbf1f1f1f1ffffff, 0xfbf1e3e3e3ffffff,
+    0xfbf1c3c7c7ffffff, 0xf1f1818f8fffffff, 0xe0e0001f1fffffff, 0xe0e0203f3fffffff,
+    0xfbf0f8fcffffffff, 0xfdf0f8f8ffffffff, 0x0000000000000000, 0xf7e1e3e3ffffffff,
+    0xfbc1c3c7ffffffff, 0xf181818fffffffff, 0xe000001fffffffff, 0xe020203fffffffff,
+    0xf8f0f8ffffffffff, 0xf8f0f8ffffffffff, 0xf1f1f1ffffffffff, 0xe3e1e3ffffffffff,
+    0xc3c1c3ffffffffff, 0x818181ffffffffff, 0x000000ffffffffff, 0x202020ffffffffff,
+    0xf8f0f9ffffffffff, 0xf8f0fbffffffffff, 0xf1f1fbffffffffff, 0xe3e1fbffffffffff,
+    0xc3c1f3ffffffffff, 0x8181e1ffffffffff, 0x0000e0ffffffffff, 0x2020e0ffffffffff,
+    0x80808080fffffcfc, 0x80808080fffff8f8, 0x80808080fffff1f1, 0x80808080ffffe3e3,
+    0x80808080ffffc7c7, 0x80808080ffff8f8f, 0x80808080ffff1f1f, 0x80808080ffff3f3f,
+    0x80808080fffcfcfc, 0x80808080fff8f8f8, 0x80808080fff1f1f1, 0x80808080ffe3e3e3,
+    0x80808080ffc7c7c7, 0x80808080ff8f8f8f, 0x80808080ff1f1f1f, 0x80808080ff3f3f3f,
+    0x80808080fcfcfcff, 0x80808080f8f8f8ff, 0x80808080f1f1f1ff, 0x80808080e3e3e3ff,
+    0x80808080c7c7c7ff, 0x808080808f8f8fff, 0x808080801f1f1fff, 0x808080803f3f3fff,
+    0xc1c0c0c0fcfcffff, 0xc1c0c0c0f8f8ffff, 0xc1c1c1c1f1f1ffff, 0xc1c1c1c1e3e3ffff,
+    0xc1c1c1c1c7c7ffff, 0xc18181818f8fffff, 0xc18181011f1fffff, 0x808080003f3fffff,
+    0xc1c1c0c0fcffffff, 0xe3e3e0e0f8ffffff, 0xe3e3e1f1f1ffffff, 0xe3e3e3e3e3ffffff,
+    0xe3e3c3c7c7ffffff, 0xe3e383838fffffff, 0xc1c101011fffffff, 0x808000003fffffff,
+    0xc1c0c0c0ffffffff, 0xe3e0e0e0ffffffff, 0xf5f1f1f1ffffffff, 0x0000000000000000,
+    0xd7c7c7c7ffffffff, 0xe3838383ffffffff, 0xc1010101ffffffff, 0x80000000ffffffff,
+    0xc0c0c0c1ffffffff, 0xe0e0e0e3ffffffff, 0xf1f1f1ffffffffff, 0xe3e3e3ffffffffff,
+    0xc7c7c7ffffffffff, 0x838383e3ffffffff, 0x010101c1ffffffff, 0x00000080ffffffff,
+    0xc0c0c0c0ffffffff, 0xe0e0e1e1ffffffff, 0xf1e1e3e3ffffffff, 0xe3e3e3e3ffffffff,
+    0xc7c3e3e3ffffffff, 0x8383c3c3ffffffff, 0x01018181ffffffff, 0x00008080ffffffff,
+    0xc1c1c1fffffffcfc, 0xc1c1c1fffffff8f8, 0xc1c1c1fffffff1f1, 0xc1c1c1ffffffe3e3,
+    0xc1c1c1ffffffc7c7, 0xc1c1c1ffffff8f8f, 0xc1c1c1ffffff1f1f, 0xc1c1c1ffffff3f3f,
+    0xc1c1c1fffffcfcfc, 0xc1c1c1fffff8f8f8, 0xc1c1c1fffff1f1f1, 0xc1c1c1ffffe3e3e3,
+    0xc1c1c1ffffc7c7c7, 0xc1c1c1ffff8f8f8f, 0xc1c1c1ffff1f1f1f, 0xc1c1c1ffff3f3f3f,
+    0xc1c1c1fffcfcfcff, 0xc1c1c1fff8f8f8ff, 0xc1c1c1fff1f1f1ff, 0xc1c1c1ffe3e3e3ff,
+    0xc1c1c1ffc7c7c7ff, 0xc1c1c1ff8f8f8fff, 0xc1c1c1ff1f1f1fff, 0xc1c1c1ff3f3f3fff,
+    0xe3e3e1fcfcfcffff, 0xe3e3e1f8f8f8ffff, 0xe3e3e3f1f1f1ffff, 0xe3e3e3e3e3e3ffff,
+    0xe3e3e3c7c7c7ffff, 0xe3e3c38f8f8fffff, 0xe3e3c31f1f1fffff, 0xc1c1c13f3f3fffff,
+    0xe3e3e0fcfcffffff, 0xf7e3f0f8f8ffffff, 0xf7e3f1f1f1ffffff, 0xf7e3e3e3e3ffffff,
+    0xf7e3c7c7c7ffffff, 0xf7e3878f8fffffff, 0xe3e3031f1fffffff, 0xc1c1013f3fffffff,
+    0xe3e0e0fcffffffff, 0xf7e0f0f8ffffffff, 0xfbe1f1f1ffffffff, 0x0000000000000000,
+    0xefc3c7c7ffffffff, 0xf783878fffffffff, 0xe303031fffffffff, 0xc101013fffffffff,
+    0xe0e0e0ffffffffff, 0xf0e0f0ffffffffff, 0xf1e1f1ffffffffff, 0xe3e3e3ffffffffff,
+    0xc7c3c7ffffffffff, 0x878387ffffffffff, 0x030303ffffffffff, 0x010101ffffffffff,
+    0xe0e0e1ffffffffff, 0xf0e0f3ffffffffff, 0xf1e1f7ffffffffff, 0xe3e3f7ffffffffff,
+    0xc7c3f7ffffffffff, 0x8783e7ffffffffff, 0x0303c3ffffffffff, 0x0101c1ffffffffff,
+    0xf8f8f8fffffffcfc, 0xf8f8f8fffffff8f8, 0xf8f8f8fffffff1f1, 0xf8f8f8ffffffe3e3,
+    0xf8f8f8ffffffc7c7, 0xf8f8f8ffffff8f8f, 0xf8f8f8ffffff1f1f, 0xf8f8f8ffffff3f3f,
+    0xf8f8f8fffffcfcfc, 0xf8f8f8fffff8f8f8, 0xf8f8f8fffff1f1f1, 0xf8f8f8ffffe3e3e3,
+    0xf8f8f8ffffc7c7c7, 0xf8f8f8ffff8f8f8f, 0xf8f8f8ffff1f1f1f, 0xf8f8f8ffff3f3f3f,
+    0xf8f8f8fffcfcfcff, 0xf8f8f8fff8f8f8ff, 0xf8f8f8fff1f1f1ff, 0xf8f8f8ffe3e3e3ff,
+    0xf8f8f8ffc7c7c7ff, 0xf8f8f8ff8f8f8fff, 0xf8f8f8ff1f1f1fff, 0xf8f8f8ff3f3f3fff,
+    0xf8f8f8fcfcfcffff, 0xf8f8f8f8f8f8ffff, 0xf8f8f8f1f1f1ffff, 0xf8f8f8e3e3e3ffff,
+    0xf8f8f8c7c7c7ffff, 0xf8f8f88f8f8fffff, 0xf8f8f81f1f1fffff, 0xf8f8f83f3f3fffff,
+    0xf8f8f8fcfcffffff, 0xf8f8f8f8f8ffffff, 0xf8f8f0f1f1ffffff, 0xf8f8e0e3e3ffffff,
+    0xf8f8c0c7c7ffffff, 0xf8f8888f8fffffff, 0xf8f8181f1fffffff, 0xf8f8383f3fffffff,
+    0xfcfcfcfcffffffff, 0xfcf8f8f8ffffffff, 0xfcf0f0f1ffffffff, 0xf8e0e0e3ffffffff,
+    0xf8c0c0c7ffffffff, 0xf888888fffffffff, 0xf818181fffffffff, 0xf838383fffffffff,
+    0x0000000000000000, 0xf8f8f8ffffffffff, 0xf0f0f0ffffffffff, 0xe0e0e0ffffffffff,
+    0xc0c0c0ffffffffff, 0x888888ffffffffff, 0x181818ffffffffff, 0x383838ffffffffff,
+    0xf0f0f1ffffffffff, 0xf8f8ffffffffffff, 0xf0f0fcffffffffff, 0xe0e0f8ffffffffff,
+    0xc0c0f8ffffffffff, 0x8888f8ffffffffff, 0x1818f8ffffffffff, 0x3838f8ffffffffff,
+    0xfcfcfffffffffcfc, 0xfcfcfffffffff8f8, 0xfcfcfffffffff1f1, 0xfcfcffffffffe3e3,
+    0xfcfcffffffffc7c7, 0xfcfcffffffff8f8f, 0xfcfcffffffff1f1f, 0xfcfcffffffff3f3f,
+    0xfcfcfffffffcfcfc, 0xfcfcfffffff8f8f8, 0xfcfcfffffff1f1f1, 0xfcfcffffffe3e3e3,
+    0xfcfcffffffc7c7c7, 0xfcfcffffff8f8f8f, 0xfcfcffffff1f1f1f, 0xfcfcffffff3f3f3f,
+    0xfcfcfffffcfcfcff, 0xfcfcfffff8f8f8ff, 0xfcfcfffff1f1f1ff, 0xfcfcffffe3e3e3ff,
+    0xfcfcffffc7c7c7ff, 0xfcfcffff8f8f8fff, 0xfcfcffff1f1f1fff, 0xfcfcffff3f3f3fff,
+    0xfcfcfffcfcfcffff, 0xfcfcfff8f8f8ffff, 0xfcfcfff1f1f1ffff, 0xfcfcffe3e3e3ffff,
+    0xfcfcffc7c7c7ffff, 0xfcfcff8f8f8fffff, 0xfcfcff1f1f1fffff, 0xfcfcff3f3f3fffff,
+    0xfcfcfcfcfcffffff, 0xfcfcf8f8f8ffffff, 0xfcfcf1f1f1ffffff, 0xfcfce3e3e3ffffff,
+    0xfcfcc7c7c7ffffff, 0xfcfc8f8f8fffffff, 0xfcfc1f1f1fffffff, 0xfcfc3f3f3fffffff,
+    0xfcfcfcfcffffffff, 0xfcf8f8f8ffffffff, 0xfcf0f1f1ffffffff, 0xfce0e3e3ffffffff,
+    0xfcc4c7c7ffffffff, 0xfc8c8f8fffffffff, 0xfc1c1f1fffffffff, 0xfc3c3f3fffffffff,
+    0x0000000000000000, 0xf8f8f8ffffffffff, 0xf0f0f1ffffffffff, 0xe0e0e3ffffffffff,
+    0xc4c4c7ffffffffff, 0x8c8c8fffffffffff, 0x1c1c1fffffffffff, 0x3c3c3fffffffffff,
+    0xf8f8ffffffffffff, 0xf8f8ffffffffffff, 0xf0f0ffffffffffff, 0xe0e0ffffffffffff,
+    0xc4c4ffffffffffff, 0x8c8cffffffffffff, 0x1c1cffffffffffff, 0x3c3cffffffffffff,
+    0xf0f0f0fffffffcfc, 0xf0f0f0fffffff8f8, 0xf0f0f0fffffff1f1, 0xf0f0f0ffffffe3e3,
+    0xf0f0f0ffffffc7c7, 0xf0f0f0ffffff8f8f, 0xf0f0f0ffffff1f1f, 0xf0f0f0ffffff3f3f,
+    0xf0f0f0fffffcfcfc, 0xf0f0f0fffff8f8f8, 0xf0f0f0fffff1f1f1, 0xf0f0f0ffffe3e3e3,
+    0xf0f0f0ffffc7c7c7, 0xf0f0f0ffff8f8f8f, 0xf0f0f0ffff1f1f1f, 0xf0f0f0ffff3f3f3f,
+    0xf0f0f0fffcfcfcff, 0xf0f0f0fff8f8f8ff, 0xf0f0f0fff1f1f1ff, 0xf0f0f0ffe3e3e3ff,
+    0xf0f0f0ffc7c7c7ff, 0xf0f0f0ff8f8f8fff, 0xf0f0f0ff1f1f1fff, 0xf0f0f0ff3f3f3fff,
+    0xf0f0f0fcfcfcffff, 0xf0f0f0f8f8f8ffff, 0xf0f0f0f1f1f1ffff, 0xf0f0f0e3e3e3ffff,
+    0xf0f0f0c7c7c7ffff, 0xf0f0f08f8f8fffff, 0xf0f0f01f1f1fffff, 0xf0f0f03f3f3fffff,
+    0xf0f0f0fcfcffffff, 0xf0f0f0f8f8ffffff, 0xf0f0f0f1f1ffffff, 0xf0f0e0e3e3ffffff,
+    0xf0f0c0c7c7ffffff, 0xf0f0808f8fffffff, 0xf0f0101f1fffffff, 0xf0f0303f3fffffff,
+    0xfaf8fcfcffffffff, 0xfdf8f8f8ffffffff, 0xfaf0f1f1ffffffff, 0xf8e0e0e3ffffffff,
+    0xf0c0c0c7ffffffff, 0xf080808fffffffff, 0xf010101fffffffff, 0xf030303fffffffff,
+    0xfcfcfcffffffffff, 0x0000000000000000, 0xf1f1f1ffffffffff, 0xe0e0e0ffffffffff,
+    0xc0c0c0ffffffffff, 0x808080ffffffffff, 0x101010ffffffffff, 0x303030ffffffffff,
+    0xfcfcffffffffffff, 0xf8f8ffffffffffff, 0xf1f1ffffffffffff, 0xe0e0f8ffffffffff,
+    0xc0c0f0ffffffffff, 0x8080f0ffffffffff, 0x1010f0ffffffffff, 0x3030f0ffffffffff,
+    0xf8f8fffffffffcfc, 0xf8f8fffffffff8f8, 0xf8f8fffffffff1f1, 0xf8f8ffffffffe3e3,
+    0xf8f8ffffffffc7c7, 0xf8f8ffffffff8f8f, 0xf8f8ffffffff1f1f, 0xf8f8ffffffff3f3f,
+    0xf8f8fffffffcfcfc, 0xf8f8fffffff8f8f8, 0xf8f8fffffff1f1f1, 0xf8f8ffffffe3e3e3,
+    0xf8f8ffffffc7c7c7, 0xf8f8ffffff8f8f8f, 0xf8f8ffffff1f1f1f, 0xf8f8ffffff3f3f3f,
+    0xf8f8fffffcfcfcff, 0xf8f8fffff8f8f8ff, 0xf8f8fffff1f1f1ff, 0xf8f8ffffe3e3e3ff,
+    0xf8f8ffffc7c7c7ff, 0xf8f8ffff8f8f8fff, 0xf8f8ffff1f1f1fff, 0xf8f8ffff3f3f3fff,
+    0xf8f8fffcfcfcffff, 0xf8f8fff8f8f8ffff, 0xf8f8fff1f1f1ffff, 0xf8f8ffe3e3e3ffff,
+    0xf8f8ffc7c7c7ffff, 0xf8f8ff8f8f8fffff, 0xf8f8ff1f1f1fffff, 0xf8f8ff3f3f3fffff,
+    0xfaf8fcfcfcffffff, 0xfaf8f8f8f8ffffff, 0xfaf8f1f1f1ffffff, 0xfaf8e3e3e3ffffff,
+    0xf8f8c7c7c7ffffff, 0xf8f88f8f8fffffff, 0xf8f81f1f1fffffff, 0xf8f83f3f3fffffff,
+    0xf8fcfcfcffffffff, 0xfaf8f8f8ffffffff, 0xf8f1f1f1ffffffff, 0xfae1e3e3ffffffff,
+    0xf8c0c7c7ffffffff, 0xf8888f8fffffffff, 0xf8181f1fffffffff, 0xf8383f3fffffffff,
+    0xf8fcfcffffffffff, 0x0000000000000000, 0xf0f1f1ffffffffff, 0xe2e1e3ffffffffff,
+    0xc0c0c7ffffffffff, 0x88888fffffffffff, 0x18181fffffffffff, 0x38383fffffffffff,
+    0xf8fcffffffffffff, 0xf8f8ffffffffffff, 0xf0f1ffffffffffff, 0xe0e1ffffffffffff,
+    0xc0c0ffffffffffff, 0x8888ffffffffffff, 0x1818ffffffffffff, 0x3838ffffffffffff,
+    0xe0e0e0fffffffcfc, 0xe0e0e0fffffff8f8, 0xe0e0e0fffffff1f1, 0xe0e0e0ffffffe3e3,
+    0xe0e0e0ffffffc7c7, 0xe0e0e0ffffff8f8f, 0xe0e0e0ffffff1f1f, 0xe0e0e0ffffff3f3f,
+    0xe0e0e0fffffcfcfc, 0xe0e0e0fffff8f8f8, 0xe0e0e0fffff1f1f1, 0xe0e0e0ffffe3e3e3,
+    0xe0e0e0ffffc7c7c7, 0xe0e0e0ffff8f8f8f, 0xe0e0e0ffff1f1f1f, 0xe0e0e0ffff3f3f3f,
+    0xe0e0e0fffcfcfcff, 0xe0e0e0fff8f8f8ff, 0xe0e0e0fff1f1f1ff, 0xe0e0e0ffe3e3e3ff,
+    0xe0e0e0ffc7c7c7ff, 0xe0e0e0ff8f8f8fff, 0xe0e0e0ff1f1f1fff, 0xe0e0e0ff3f3f3fff,
+    0xe0e0e0fcfcfcffff, 0xe0e0e0f8f8f8ffff, 0xe0e0e0f1f1f1ffff, 0xe0e0e0e3e3e3ffff,
+    0xe0e0e0c7c7c7ffff, 0xe0e0e08f8f8fffff, 0xe0e0e01f1f1fffff, 0xe0e0e03f3f3fffff,
+    0xe0e0e0fcfcffffff, 0xe0e0e0f8f8ffffff, 0xe0e0e0f1f1ffffff, 0xe0e0e0e3e3ffffff,
+    0xe0e0c0c7c7ffffff, 0xe0e0808f8fffffff, 0xe0e0001f1fffffff, 0xe0e0203f3fffffff,
+    0xf0f0f0fcffffffff, 0xf4f0f8f8ffffffff, 0xfbf1f1f1ffffffff, 0xf5e1e3e3ffffffff,
+    0xf1c1c1c7ffffffff, 0xe080808fffffffff, 0xe000001fffffffff, 0xe020203fffffffff,
+    0xf0f0f0ffffffffff, 0xf8f8f8ffffffffff, 0x0000000000000000, 0xe3e3e3ffffffffff,
+    0xc1c1c1ffffffffff, 0x808080ffffffffff, 0x000000ffffffffff, 0x202020ffffffffff,
+    0xf0f0f1ffffffffff, 0xf8f8ffffffffffff, 0xf1f1ffffffffffff, 0xe3e3ffffffffffff,
+    0xc1c1f1ffffffffff, 0x8080e0ffffffffff, 0x0000e0ffffffffff, 0x2020e0ffffffffff,
+    0xf1f1fffffffffcfc, 0xf1f1fffffffff8f8, 0xf1f1fffffffff1f1, 0xf1f1ffffffffe3e3,
+    0xf1f1ffffffffc7c7, 0xf1f1ffffffff8f8f, 0xf1f1ffffffff1f1f, 0xf1f1ffffffff3f3f,
+    0xf1f1fffffffcfcfc, 0xf1f1fffffff8f8f8, 0xf1f1fffffff1f1f1, 0xf1f1ffffffe3e3e3,
+    0xf1f1ffffffc7c7c7, 0xf1f1ffffff8f8f8f, 0xf1f1ffffff1f1f1f, 0xf1f1ffffff3f3f3f,
+    0xf1f1fffffcfcfcff, 0xf1f1fffff8f8f8ff, 0xf1f1fffff1f1f1ff, 0xf1f1ffffe3e3e3ff,
+    0xf1f1ffffc7c7c7ff, 0xf1f1ffff8f8f8fff, 0xf1f1ffff1f1f1fff, 0xf1f1ffff3f3f3fff,
+    0xf1f1fffcfcfcffff, 0xf1f1fff8f8f8ffff, 0xf1f1fff1f1f1ffff, 0xf1f1ffe3e3e3ffff,
+    0xf1f1ffc7c7c7ffff, 0xf1f1ff8f8f8fffff, 0xf1f1ff1f1f1fffff, 0xf1f1ff3f3f3fffff,
+    0xf5f1fcfcfcffffff, 0xf5f1f8f8f8ffffff, 0xf5f1f1f1f1ffffff, 0xf5f1e3e3e3ffffff,
+    0xf5f1c7c7c7ffffff, 0xf1f18f8f8fffffff, 0xf1f11f1f1fffffff, 0xf1f13f3f3fffffff,
+    0xf5f8fcfcffffffff, 0xf1f8f8f8ffffffff, 0xf5f1f1f1ffffffff, 0xf1e3e3e3ffffffff,
+    0xf5c3c7c7ffffffff, 0xf1818f8fffffffff, 0xf1111f1fffffffff, 0xf1313f3fffffffff,
+    0xf4f8fcffffffffff, 0xf0f8f8ffffffffff, 0x0000000000000000, 0xe1e3e3ffffffffff,
+    0xc5c3c7ffffffffff, 0x81818fffffffffff, 0x11111fffffffffff, 0x31313fffffffffff,
+    0xf0f8ffffffffffff, 0xf0f8ffffffffffff, 0xf1f1ffffffffffff, 0xe1e3ffffffffffff,
+    0xc1c3ffffffffffff, 0x8181ffffffffffff, 0x1111ffffffffffff, 0x3131ffffffffffff,
+    0xc1c1c1fffffffcfc, 0xc1c1c1fffffff8f8, 0xc1c1c1fffffff1f1, 0xc1c1c1ffffffe3e3,
+    0xc1c1c1ffffffc7c7, 0xc1c1c1ffffff8f8f, 0xc1c1c1ffffff1f1f, 0xc1c1c1ffffff3f3f,
+    0xc1c1c1fffffcfcfc, 0xc1c1c1fffff8f8f8, 0xc1c1c1fffff1f1f1, 0xc1c1c1ffffe3e3e3,
+    0xc1c1c1ffffc7c7c7, 0xc1c1c1ffff8f8f8f, 0xc1c1c1ffff1f1f1f, 0xc1c1c1ffff3f3f3f,
+    0xc1c1c1fffcfcfcff, 0xc1c1c1fff8f8f8ff, 0xc1c1c1fff1f1f1ff, 0xc1c1c1ffe3e3e3ff,
+    0xc1c1c1ffc7c7c7ff, 0xc1c1c1ff8f8f8fff, 0xc1c1c1ff1f1f1fff, 0xc1c1c1ff3f3f3fff,
+    0xc1c1c1fcfcfcffff, 0xc1c1c1f8f8f8ffff, 0xc1c1c1f1f1f1ffff, 0xc1c1c1e3e3e3ffff,
+    0xc1c1c1c7c7c7ffff, 0xc1c1c18f8f8fffff, 0xc1c1c11f1f1fffff, 0xc1c1c13f3f3fffff,
+    0xc1c1c0fcfcffffff, 0xc1c1c0f8f8ffffff, 0xc1c1c1f1f1ffffff, 0xc1c1c1e3e3ffffff,
+    0xc1c1c1c7c7ffffff, 0xc1c1818f8fffffff, 0xc1c1011f1fffffff, 0xc1c1013f3fffffff,
+    0xc1c0c0fcffffffff, 0xe3e0e0f8ffffffff, 0xebe1f1f1ffffffff, 0xf7e3e3e3ffffffff,
+    0xebc3c7c7ffffffff, 0xe383838fffffffff, 0xc101011fffffffff, 0xc101013fffffffff,
+    0xc0c0c0ffffffffff, 0xe0e0e0ffffffffff, 0xf1f1f1ffffffffff, 0x0000000000000000,
+    0xc7c7c7ffffffffff, 0x838383ffffffffff, 0x010101ffffffffff, 0x010101ffffffffff,
+    0xc0c0c1ffffffffff, 0xe0e0e3ffffffffff, 0xf1f1ffffffffffff, 0xe3e3ffffffffffff,
+    0xc7c7ffffffffffff, 0x8383e3ffffffffff, 0x0101c1ffffffffff, 0x0101c1ffffffffff,
+    0xe3e3fffffffffcfc, 0xe3e3fffffffff8f8, 0xe3e3fffffffff1f1, 0xe3e3ffffffffe3e3,
+    0xe3e3ffffffffc7c7, 0xe3e3ffffffff8f8f, 0xe3e3ffffffff1f1f, 0xe3e3ffffffff3f3f,
+    0xe3e3fffffffcfcfc, 0xe3e3fffffff8f8f8, 0xe3e3fffffff1f1f1, 0xe3e3ffffffe3e3e3,
+    0xe3e3ffffffc7c7c7, 0xe3e3ffffff8f8f8f, 0xe3e3ffffff1f1f1f, 0xe3e3ffffff3f3f3f,
+    0xe3e3fffffcfcfcff, 0xe3e3fffff8f8f8ff, 0xe3e3fffff1f1f1ff, 0xe3e3ffffe3e3e3ff,
+    0xe3e3ffffc7c7c7ff, 0xe3e3ffff8f8f8fff, 0xe3e3ffff1f1f1fff, 0xe3e3ffff3f3f3fff,
+    0xe3e3fffcfcfcffff, 0xe3e3fff8f8f8ffff, 0xe3e3fff1f1f1ffff, 0xe3e3ffe3e3e3ffff,
+    0xe3e3ffc7c7c7ffff, 0xe3e3ff8f8f8fffff, 0xe3e3ff1f1f1fffff, 0xe3e3ff3f3f3fffff,
+    0xe3e3fcfcfcffffff, 0xebe3f8f8f8ffffff, 0xebe3f1f1f1ffffff, 0xebe3e3e3e3ffffff,
+    0xebe3c7c7c7ffffff, 0xebe38f8f8fffffff, 0xe3e31f1f1fffffff, 0xe3e33f3f3fffffff,
+    0xe3e0fcfcffffffff, 0xebf0f8f8ffffffff, 0xe3f1f1f1ffffffff, 0xebe3e3e3ffffffff,
+    0xe3c7c7c7ffffffff, 0xeb878f8fffffffff, 0xe3031f1fffffffff, 0xe3233f3fffffffff,
+    0xe0e0fcffffffffff, 0xe8f0f8ffffffffff, 0xe1f1f1ffffffffff, 0x0000000000000000,
+    0xc3c7c7ffffffffff, 0x8b878fffffffffff, 0x03031fffffffffff, 0x23233fffffffffff,
+    0xe0e0ffffffffffff, 0xe0f0ffffffffffff, 0xe1f1ffffffffffff, 0xe3e3ffffffffffff,
+    0xc3c7ffffffffffff, 0x8387ffffffffffff, 0x0303ffffffffffff, 0x2323ffffffffffff,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_fen;
+    use crate::{Evaluate, BLACK, WHITE};
+
+    /// Build the KPK bitbase from scratch by retrograde analysis.
+    fn generate_kpk() -> Vec<u64> {
+        const INVALID: u8 = 0;
+        const UNKNOWN: u8 = 1;
+        const DRAW: u8 = 2;
+        const WIN: u8 = 4;
+
+        let distance = |a: usize, b: usize| {
+            let rows = ((a >> 3) as i32 - (b >> 3) as i32).abs();
+            let cols = ((a & 7) as i32 - (b & 7) as i32).abs();
+            rows.max(cols)
+        };
+        let king_moves = |square: usize| (0..64).filter(move |s| distance(*s, square) == 1);
+        let pawn_attacks = |pawn: usize, square: usize| {
+            square >> 3 == (pawn >> 3) + 1 && distance(pawn, square) == 1 && square & 7 != pawn & 7
+        };
+        let decode = |index: usize| {
+            let pawn_index = index >> 13;
+            (
+                (index >> 12) & 1 == 1,
+                (index >> 6) & 63,
+                index & 63,
+                (pawn_index / 4 + 1) * 8 + pawn_index % 4,
+            )
+        };
+
+        let mut db = vec![INVALID; KPK_SIZE];
+        for (index, entry) in db.iter_mut().enumerate() {
+            let (to_move, king, weak_king, pawn) = decode(index);
+            assert_eq!(get_kpk_index(to_move, king, weak_king, pawn), index);
+
+            let promotion = pawn + 8;
+            *entry = if distance(king, weak_king) <= 1
+                || king == pawn
+                || weak_king == pawn
+                || (to_move && pawn_attacks(pawn, weak_king))
+            {
+                INVALID
+            } else if to_move
+                && pawn >> 3 == 6
+                && king != promotion
+                && (distance(weak_king, promotion) > 1 || distance(king, promotion) == 1)
+            {
+                // The pawn promotes safely
+                WIN
+            } else if !to_move
+                && (king_moves(weak_king).all(|s| distance(s, king) <= 1 || pawn_attacks(pawn, s))
+                    || (distance(weak_king, pawn) == 1 && distance(king, pawn) > 1))
+            {
+                // Stalemate, or the pawn is captured
+                DRAW
+            } else {
+                UNKNOWN
+            };
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for index in 0..KPK_SIZE {
+                if db[index] != UNKNOWN {
+                    continue;
+                }
+                let (to_move, king, weak_king, pawn) = decode(index);
+                let mut result = INVALID;
+                if to_move {
+                    for s in king_moves(king) {
+                        result |= db[get_kpk_index(false, s, weak_king, pawn)];
+                    }
+                    if pawn >> 3 < 6 {
+                        result |= db[get_kpk_index(false, king, weak_king, pawn + 8)];
+                    }
+                    if pawn >> 3 == 1 && pawn + 8 != king && pawn + 8 != weak_king {
+                        result |= db[get_kpk_index(false, king, weak_king, pawn + 16)];
+                    }
+                } else {
+                    for s in king_moves(weak_king) {
+                        result |= db[get_kpk_index(true, king, s, pawn)];
+                    }
+                }
+
+                // The player to move picks the best outcome for themselves
+                let (good, bad) = if to_move { (WIN, DRAW) } else { (DRAW, WIN) };
+                if result & good != 0 {
+                    db[index] = good;
+                    changed = true;
+                } else if result & UNKNOWN == 0 {
+                    db[index] = bad;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut bits = vec![0; KPK_SIZE / 64];
+        for (index, entry) in db.iter().enumerate() {
+            if *entry == WIN {
+                bits[index / 64] |= 1 << (index % 64);
+            }
+        }
+        bits
+    }
+
+    #[test]
+    fn test_kpk_bitbase() {
+        assert_eq!(generate_kpk(), KPK_BITBASE.to_vec());
+
+        // The king in front of its pawn with the opposition wins
+        let board = parse_fen("4k3/8/4K3/4P3/8/8/8/8 b - - 0 1").unwrap();
+        assert!(board.value_for(WHITE) > KNOWN_WIN);
+        let board = parse_fen("4k3/8/4K3/4P3/8/8/8/8 w - - 0 1").unwrap();
+        assert!(board.value_for(WHITE) > KNOWN_WIN);
+        // The lone king keeps the opposition in front of the pawn
+        let board = parse_fen("8/8/8/4k3/8/4P3/4K3/8 w - - 0 1").unwrap();
+        assert_eq!(board.value_for(WHITE), 0.0);
+        // A rook pawn can not be won against a king in the corner
+        let board = parse_fen("k7/8/8/8/8/8/P7/K7 w - - 0 1").unwrap();
+        assert_eq!(board.value_for(WHITE), 0.0);
+        // The king can not catch the pawn
+        let board = parse_fen("8/8/8/8/4P3/2K5/8/k7 b - - 0 1").unwrap();
+        assert!(board.value_for(BLACK) < -KNOWN_WIN);
+        assert_eq!(board.value_for(BLACK), board.flip_colors().value_for(WHITE));
+    }
+
+    #[test]
+    fn test_mating_endgames() {
+        // The lone king is better off in the center
+        let center = parse_fen("8/8/8/3k4/8/8/8/KQ6 w - - 0 1").unwrap();
+        let edge = parse_fen("3k4/8/8/8/8/8/8/KQ6 w - - 0 1").unwrap();
+        let corner = parse_fen("k7/8/8/8/8/8/8/KQ6 w - - 0 1").unwrap();
+        assert!(center.value_for(WHITE) > KNOWN_WIN);
+        assert!(edge.value_for(WHITE) > center.value_for(WHITE));
+        assert!(corner.value_for(WHITE) > edge.value_for(WHITE));
+        assert_eq!(corner.value_for(BLACK), -corner.value_for(WHITE));
+
+        // A rook is worth less than a queen
+        let rook = parse_fen("k7/8/8/8/8/8/8/KR6 w - - 0 1").unwrap();
+        assert!(rook.value_for(WHITE) > KNOWN_WIN);
+        assert!(rook.value_for(WHITE) < corner.value_for(WHITE));
+
+        // With a dark squared bishop, only the A1 and H8 corners are mates
+        let right = parse_fen("8/8/8/8/8/8/8/k1K1BN2 w - - 0 1").unwrap();
+        let wrong = parse_fen("k7/8/8/8/8/8/8/2K1BN2 w - - 0 1").unwrap();
+        assert!(right.value_for(WHITE) > wrong.value_for(WHITE));
+        assert!(wrong.value_for(WHITE) > KNOWN_WIN);
+    }
+
+    #[test]
+    fn test_drawish_endgames() {
+        // Neither player can checkmate
+        let board = parse_fen("8/8/3k4/8/8/2NN4/8/K7 w - - 0 1").unwrap();
+        assert!(!has_mating_material(PieceLists::new(&board).get(WHITE)));
+        assert_eq!(board.value_for(WHITE), 0.0);
+        let board = parse_fen("8/8/3k4/8/8/2N5/8/K7 w - - 0 1").unwrap();
+        assert_eq!(board.value_for(WHITE), 0.0);
+
+        // A knight can not win against a pawn
+        let board = parse_fen("8/8/3k4/3p4/8/2N5/8/K7 w - - 0 1").unwrap();
+        assert_eq!(get_draw_scale(&PieceLists::new(&board), WHITE), 0.0);
+        assert!(board.value_for(BLACK) >= 0.0);
+
+        // The bishop does not cover the promotion square
+        let board = parse_fen("7k/8/8/7P/8/8/8/K4B2 w - - 0 1").unwrap();
+        assert!(is_wrong_rook_pawn(&PieceLists::new(&board), WHITE));
+        assert_eq!(board.value_for(WHITE), 0.0);
+        let board = parse_fen("7k/8/8/7P/8/8/8/K3B3 w - - 0 1").unwrap();
+        assert!(!is_wrong_rook_pawn(&PieceLists::new(&board), WHITE));
+        assert!(board.value_for(WHITE) > 0.0);
+
+        // Bishops on opposite colors
+        let board = parse_fen("8/5k2/4b3/3PP3/8/2B5/8/K7 w - - 0 1").unwrap();
+        assert_eq!(get_draw_scale(&PieceLists::new(&board), WHITE), 0.5);
+        let board = parse_fen("8/5k2/3b4/3PP3/8/2B5/8/K7 w - - 0 1").unwrap();
+        assert_eq!(get_draw_scale(&PieceLists::new(&board), WHITE), 1.0);
+
+        // Counting the pieces is enough to rule out most boards
+        assert!(could_be_known_ending(&board));
+        assert!(!could_be_known_ending(&Board::default()));
+        let board = parse_fen("4k3/3rp3/8/8/8/8/3RP3/4K3 w - - 0 1").unwrap();
+        assert!(!could_be_known_ending(&board));
+    }
+
+    #[test]
+    fn test_dead_positions() {
+        let board = parse_fen("8/8/3k4/8/8/8/8/K7 w - - 0 1").unwrap();
+        assert!(board.is_dead_position());
+        let board = parse_fen("8/8/3k4/8/8/2N5/8/K7 w - - 0 1").unwrap();
+        assert!(board.is_dead_position());
+
+        // Checkmate is still possible if the other player helps
+        let board = parse_fen("8/8/3k4/8/8/2NN4/8/K7 w - - 0 1").unwrap();
+        assert!(can_checkmate(&board, WHITE));
+        assert!(!board.is_dead_position());
+        let board = parse_fen("8/8/3k4/3p4/8/2N5/8/K7 w - - 0 1").unwrap();
+        assert!(can_checkmate(&board, WHITE));
+        let board = parse_fen("8/8/3k4/3q4/8/2N5/8/K7 w - - 0 1").unwrap();
+        assert!(!can_checkmate(&board, WHITE));
+        assert!(can_checkmate(&board, BLACK));
+
+        // Bishops that are all on squares of the same color
+        let board = parse_fen("5b2/8/3k4/8/8/8/8/K1B5 w - - 0 1").unwrap();
+        assert!(board.is_dead_position());
+        let board = parse_fen("5b2/8/3k4/8/8/8/3B4/K1B1B3 w - - 0 1").unwrap();
+        assert!(board.is_dead_position());
+        let board = parse_fen("2b5/8/3k4/8/8/8/8/K1B5 w - - 0 1").unwrap();
+        assert!(!board.is_dead_position());
+        let board = parse_fen("8/8/3k4/8/8/8/8/K1BB4 w - - 0 1").unwrap();
+        assert!(can_checkmate(&board, WHITE));
+    }
+}
@@ -0,0 +1,1431 @@
+use super::endgame::{could_be_known_ending, get_draw_scale, get_endgame_value, PieceLists};
+use super::piece::{DIAGONAL_DIRECTIONS, ORTHOGONAL_DIRECTIONS};
+use super::{Board, Color, Evaluate, Move, PawnEntry, PawnHashTable, Piece, Position, Variant};
+use alloc::{string::String, vec::Vec};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A pair of values for the middlegame and the endgame.
+///
+/// Every evaluation term is scored separately for both phases of the
+/// game, and the two are blended together according to how much
+/// material is left on the board.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Score {
+    middlegame: f64,
+    endgame: f64,
+}
+
+impl Score {
+    /// Create a score from its middlegame and endgame values.
+    #[inline]
+    pub const fn new(middlegame: f64, endgame: f64) -> Self {
+        Self {
+            middlegame,
+            endgame,
+        }
+    }
+
+    /// Get the value of the score in the middlegame.
+    #[inline]
+    pub fn get_middlegame(&self) -> f64 {
+        self.middlegame
+    }
+
+    /// Get the value of the score in the endgame.
+    #[inline]
+    pub fn get_endgame(&self) -> f64 {
+        self.endgame
+    }
+
+    /// Blend the middlegame and endgame values for a given game phase,
+    /// where 1.0 is the opening and 0.0 is a bare endgame.
+    #[inline]
+    pub fn taper(&self, phase: f64) -> f64 {
+        self.middlegame * phase + self.endgame * (1.0 - phase)
+    }
+
+    /// Get the middlegame and endgame values to change them in place.
+    #[inline]
+    pub(crate) fn get_values_mut(&mut self) -> [&mut f64; 2] {
+        [&mut self.middlegame, &mut self.endgame]
+    }
+}
+
+impl Add for Score {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.middlegame + other.middlegame,
+            self.endgame + other.endgame,
+        )
+    }
+}
+
+impl AddAssign for Score {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Score {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(
+            self.middlegame - other.middlegame,
+            self.endgame - other.endgame,
+        )
+    }
+}
+
+impl SubAssign for Score {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Score {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.middlegame, -self.endgame)
+    }
+}
+
+impl Mul<f64> for Score {
+    type Output = Self;
+    fn mul(self, factor: f64) -> Self {
+        Self::new(self.middlegame * factor, self.endgame * factor)
+    }
+}
+
+/// A bonus or penalty for every square on the board for one type of piece.
+///
+/// Tables are laid out as the board is seen from White's side, so the first
+/// row is the eighth rank. Black's pieces use the same table mirrored vertically.
+pub type PieceSquareTable = [[f64; 8]; 8];
+
+/// The parameters that the evaluation function is built from.
+///
+/// All of the values are in the same units as `Board::value_for`,
+/// where a pawn in the middlegame is worth 10.
+///
+/// Parameters can be changed at runtime, and saved to and loaded from
+/// text with `to_string` and `parse_eval_params`. To search with them,
+/// wrap a board in an `Evaluator`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EvalParams {
+    pub(crate) material: [Score; 6],
+    pub(crate) middlegame_tables: [PieceSquareTable; 6],
+    pub(crate) endgame_tables: [PieceSquareTable; 6],
+    /// The bonus for a passed pawn on each rank, counted from its own side
+    pub(crate) passed_pawn: [Score; 8],
+    /// The bonus for a passed pawn on each rank with a piece standing in front of it
+    pub(crate) blocked_passed_pawn: [Score; 8],
+    /// The bonus for a pawn on each rank that can become a passed pawn
+    pub(crate) candidate_passer: [Score; 8],
+    pub(crate) doubled_pawn: Score,
+    pub(crate) isolated_pawn: Score,
+    pub(crate) backward_pawn: Score,
+    /// The bonus for a pawn defended by another pawn
+    pub(crate) pawn_chain: Score,
+    /// The penalty for each type of piece attacking the squares around the king
+    pub(crate) king_attack: [Score; 6],
+    /// How much of the king attack penalty counts for each number of attackers
+    pub(crate) king_attackers_scale: [f64; 8],
+    /// The bonus for each pawn sheltering a king on its own side of the board
+    pub(crate) pawn_shield: Score,
+    /// The penalty for a file next to the king with only enemy pawns on it
+    pub(crate) semi_open_file_near_king: Score,
+    /// The penalty for a file next to the king with no pawns on it
+    pub(crate) open_file_near_king: Score,
+    /// The bonus for each safe square that each type of piece can move to
+    pub(crate) mobility: [Score; 6],
+    /// The weight each term of the evaluation is multiplied by, in the
+    /// order of `EvalTerm::ALL`: material, piece-square tables, pawn
+    /// structure, king safety and mobility
+    pub(crate) term_weights: [f64; 5],
+}
+
+impl Default for EvalParams {
+    fn default() -> Self {
+        DEFAULT_EVAL_PARAMS
+    }
+}
+
+/// Write the parameters out as text, one named parameter per line,
+/// such as `material queen: 90 94`. Piece-square tables are written
+/// as a block of eight rows under their name. The text can be read
+/// back with `parse_eval_params`.
+impl core::fmt::Display for EvalParams {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let mut params = *self;
+        for (name, values) in params.get_entries_mut() {
+            write!(f, "{}:", name)?;
+            if values.len() == 64 {
+                for row in values.chunks(8) {
+                    writeln!(f)?;
+                    for value in row {
+                        write!(f, " {:>6}", value)?;
+                    }
+                }
+            } else {
+                for value in values {
+                    write!(f, " {}", value)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl EvalParams {
+    /// Get the material value of a type of piece.
+    #[inline]
+    pub fn get_material(&self, piece: Piece) -> Score {
+        self.material[get_piece_index(piece)]
+    }
+
+    /// Get the bonus or penalty for a piece standing on its square.
+    #[inline]
+    pub fn get_piece_square(&self, piece: Piece) -> Score {
+        let pos = piece.get_pos();
+        if pos.is_off_board() {
+            return Score::default();
+        }
+
+        // Black's pieces are looked up in the mirror image of the table
+        let row = match piece.get_color() {
+            Color::White => 7 - pos.get_row(),
+            Color::Black => pos.get_row(),
+        } as usize;
+        let col = pos.get_col() as usize;
+        let index = get_piece_index(piece);
+        Score::new(
+            self.middlegame_tables[index][row][col],
+            self.endgame_tables[index][row][col],
+        )
+    }
+
+    /// Get the total value of a piece on its square.
+    #[inline]
+    pub fn get_piece_value(&self, piece: Piece) -> Score {
+        self.get_material(piece) + self.get_piece_square(piece)
+    }
+
+    /// Get a copy of these parameters with a different material value for a type of piece.
+    #[inline]
+    pub fn set_material(&self, piece: Piece, value: Score) -> Self {
+        let mut result = *self;
+        result.material[get_piece_index(piece)] = value;
+        result
+    }
+
+    /// Get the middlegame and endgame piece-square tables for a type of piece.
+    #[inline]
+    pub fn get_piece_square_tables(&self, piece: Piece) -> (PieceSquareTable, PieceSquareTable) {
+        let index = get_piece_index(piece);
+        (self.middlegame_tables[index], self.endgame_tables[index])
+    }
+
+    /// Get a copy of these parameters with different
+    /// piece-square tables for a type of piece.
+    #[inline]
+    pub fn set_piece_square_tables(
+        &self,
+        piece: Piece,
+        middlegame: PieceSquareTable,
+        endgame: PieceSquareTable,
+    ) -> Self {
+        let mut result = *self;
+        let index = get_piece_index(piece);
+        result.middlegame_tables[index] = middlegame;
+        result.endgame_tables[index] = endgame;
+        result
+    }
+
+    /// Get the weight that a term is multiplied by in the evaluation.
+    #[inline]
+    pub fn get_term_weight(&self, term: EvalTerm) -> f64 {
+        self.term_weights[term as usize]
+    }
+
+    /// Get a copy of these parameters with a different weight for a term.
+    #[inline]
+    pub fn set_term_weight(&self, term: EvalTerm, weight: f64) -> Self {
+        let mut result = *self;
+        result.term_weights[term as usize] = weight;
+        result
+    }
+
+    /// Evaluate a board from the perspective of a given player.
+    ///
+    /// This includes the pieces in each player's pocket in Crazyhouse,
+    /// but does not reverse the score for variants like Antichess.
+    /// In regular chess, endings that are known to be won or drawn,
+    /// like king and pawn against king, are scored by hand instead.
+    pub fn evaluate(&self, board: &Board, color: Color) -> f64 {
+        self.evaluate_with_pawns(board, color, &PawnEntry::new(board, self))
+    }
+
+    /// Evaluate a board from the perspective of a given
+    /// player, with its pawn structure already analysed.
+    pub(crate) fn evaluate_with_pawns(
+        &self,
+        board: &Board,
+        color: Color,
+        pawns: &PawnEntry,
+    ) -> f64 {
+        if board.get_variant() != Variant::Standard || !could_be_known_ending(board) {
+            return self.trace_with_pawns(board, pawns).get_value(color);
+        }
+        let lists = PieceLists::new(board);
+        if let Some(value) = get_endgame_value(board, &lists, color) {
+            return value;
+        }
+
+        let value = self.trace_with_pawns(board, pawns).get_value(color);
+        let ahead = if value > 0.0 { color } else { !color };
+        value * get_draw_scale(&lists, ahead)
+    }
+
+    /// Break the evaluation of a board down into its terms.
+    pub fn trace(&self, board: &Board) -> EvalTrace {
+        self.trace_with_pawns(board, &PawnEntry::new(board, self))
+    }
+
+    /// Get every parameter along with the name it is written under.
+    pub(crate) fn get_entries_mut(&mut self) -> Vec<(String, Vec<&mut f64>)> {
+        let Self {
+            material,
+            middlegame_tables,
+            endgame_tables,
+            passed_pawn,
+            blocked_passed_pawn,
+            candidate_passer,
+            doubled_pawn,
+            isolated_pawn,
+            backward_pawn,
+            pawn_chain,
+            king_attack,
+            king_attackers_scale,
+            pawn_shield,
+            semi_open_file_near_king,
+            open_file_near_king,
+            mobility,
+            term_weights,
+        } = self;
+
+        let mut entries = vec![];
+        for (name, score) in PIECE_NAMES.iter().zip(material.iter_mut()) {
+            entries.push((
+                format!("material {}", name),
+                Vec::from(score.get_values_mut()),
+            ));
+        }
+        for (phase, tables) in [
+            ("middlegame", middlegame_tables),
+            ("endgame", endgame_tables),
+        ] {
+            for (name, table) in PIECE_NAMES.iter().zip(tables.iter_mut()) {
+                let values = table.iter_mut().flatten().collect();
+                entries.push((format!("{} table {}", phase, name), values));
+            }
+        }
+        for (name, scores) in [
+            ("passed pawn", passed_pawn),
+            ("blocked passed pawn", blocked_passed_pawn),
+            ("candidate passer", candidate_passer),
+        ] {
+            for (rank, score) in scores.iter_mut().enumerate() {
+                entries.push((
+                    format!("{} rank {}", name, rank + 1),
+                    Vec::from(score.get_values_mut()),
+                ));
+            }
+        }
+        for (name, score) in [
+            ("doubled pawn", doubled_pawn),
+            ("isolated pawn", isolated_pawn),
+            ("backward pawn", backward_pawn),
+            ("pawn chain", pawn_chain),
+        ] {
+            entries.push((String::from(name), Vec::from(score.get_values_mut())));
+        }
+        for (name, score) in PIECE_NAMES.iter().zip(king_attack.iter_mut()) {
+            entries.push((
+                format!("king attack {}", name),
+                Vec::from(score.get_values_mut()),
+            ));
+        }
+        entries.push((
+            String::from("king attackers scale"),
+            king_attackers_scale.iter_mut().collect(),
+        ));
+        for (name, score) in [
+            ("pawn shield", pawn_shield),
+            ("semi-open file near king", semi_open_file_near_king),
+            ("open file near king", open_file_near_king),
+        ] {
+            entries.push((String::from(name), Vec::from(score.get_values_mut())));
+        }
+        for (name, score) in PIECE_NAMES.iter().zip(mobility.iter_mut()) {
+            entries.push((
+                format!("mobility {}", name),
+                Vec::from(score.get_values_mut()),
+            ));
+        }
+        for (term, weight) in EvalTerm::ALL.iter().zip(term_weights.iter_mut()) {
+            entries.push((format!("weight {}", term), vec![weight]));
+        }
+        entries
+    }
+
+    /// Get every parameter that can be tuned.
+    ///
+    /// This leaves out the term weights, since scaling every
+    /// parameter of a term already does the same thing.
+    pub(crate) fn get_tunable_values_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        let Self {
+            material,
+            middlegame_tables,
+            endgame_tables,
+            passed_pawn,
+            blocked_passed_pawn,
+            candidate_passer,
+            doubled_pawn,
+            isolated_pawn,
+            backward_pawn,
+            pawn_chain,
+            king_attack,
+            king_attackers_scale,
+            pawn_shield,
+            semi_open_file_near_king,
+            open_file_near_king,
+            mobility,
+            term_weights: _,
+        } = self;
+
+        // These are in the same order as `get_entries_mut`
+        material
+            .iter_mut()
+            .flat_map(Score::get_values_mut)
+            .chain(middlegame_tables.iter_mut().flatten().flatten())
+            .chain(endgame_tables.iter_mut().flatten().flatten())
+            .chain(passed_pawn.iter_mut().flat_map(Score::get_values_mut))
+            .chain(
+                blocked_passed_pawn
+                    .iter_mut()
+                    .flat_map(Score::get_values_mut),
+            )
+            .chain(candidate_passer.iter_mut().flat_map(Score::get_values_mut))
+            .chain(doubled_pawn.get_values_mut())
+            .chain(isolated_pawn.get_values_mut())
+            .chain(backward_pawn.get_values_mut())
+            .chain(pawn_chain.get_values_mut())
+            .chain(king_attack.iter_mut().flat_map(Score::get_values_mut))
+            .chain(king_attackers_scale.iter_mut())
+            .chain(pawn_shield.get_values_mut())
+            .chain(semi_open_file_near_king.get_values_mut())
+            .chain(open_file_near_king.get_values_mut())
+            .chain(mobility.iter_mut().flat_map(Score::get_values_mut))
+    }
+
+    /// Get one of the parameters that can be tuned by its
+    /// index, without collecting every other one.
+    #[inline]
+    pub(crate) fn get_tunable_value_mut(&mut self, index: usize) -> Option<&mut f64> {
+        self.get_tunable_values_mut().nth(index)
+    }
+
+    fn trace_with_pawns(&self, board: &Board, pawns: &PawnEntry) -> EvalTrace {
+        let mut trace = EvalTrace {
+            scores: [[Score::default(); 2]; 5],
+            weights: self.term_weights,
+            phase: board.get_game_phase(),
+        };
+        for term in &EvalTerm::ALL {
+            if self.get_term_weight(*term) != 0.0 {
+                for color in &[Color::White, Color::Black] {
+                    trace.scores[*term as usize][*color as usize] =
+                        self.get_term_score(board, pawns, *term, *color);
+                }
+            }
+        }
+        trace
+    }
+
+    /// Get the unweighted score of one term of the evaluation for one player.
+    ///
+    /// This only counts in the player's favor; the evaluation
+    /// subtracts the opponent's score for the same term.
+    pub(crate) fn get_term_score(
+        &self,
+        board: &Board,
+        pawns: &PawnEntry,
+        term: EvalTerm,
+        color: Color,
+    ) -> Score {
+        match term {
+            EvalTerm::Material => self.get_material_score(board, color),
+            EvalTerm::PieceSquare => self.get_piece_square_score(board, color),
+            EvalTerm::Pawns => self.get_pawn_score(board, pawns, color),
+            EvalTerm::KingSafety => self.get_king_safety_score(board, color),
+            EvalTerm::Mobility => self.get_mobility_score(board, color),
+        }
+    }
+
+    /// Get all of a player's pieces on the board.
+    fn get_pieces(board: &Board, color: Color) -> impl Iterator<Item = Piece> + '_ {
+        (0..64)
+            .filter_map(move |square| board.get_piece(Position::new(square / 8, square % 8)))
+            .filter(move |piece| piece.get_color() == color)
+    }
+
+    /// Get the material of a player's pieces, including those in their pocket.
+    fn get_material_score(&self, board: &Board, color: Color) -> Score {
+        let mut score = Score::default();
+        for piece in Self::get_pieces(board, color) {
+            score += self.get_material(piece);
+        }
+
+        let pocket = board.get_pocket(color);
+        for piece in pocket.get_pieces(color) {
+            score += self.get_material(piece) * pocket.count(piece) as f64;
+        }
+        score
+    }
+
+    /// Get the bonuses and penalties for the squares a player's pieces stand on.
+    fn get_piece_square_score(&self, board: &Board, color: Color) -> Score {
+        let mut score = Score::default();
+        for piece in Self::get_pieces(board, color) {
+            score += self.get_piece_square(piece);
+        }
+        score
+    }
+
+    /// Get the score of a player's pawn structure.
+    ///
+    /// Passed pawns are worth less when a piece is standing in their way.
+    fn get_pawn_score(&self, board: &Board, pawns: &PawnEntry, color: Color) -> Score {
+        let mut score = pawns.get_score(color);
+        for pos in pawns.get_passed_pawns(color) {
+            let rank = match color {
+                Color::White => pos.get_row(),
+                Color::Black => 7 - pos.get_row(),
+            } as usize;
+            score += if board.has_piece(pos.pawn_up(color)) {
+                self.blocked_passed_pawn[rank]
+            } else {
+                self.passed_pawn[rank]
+            };
+        }
+        score
+    }
+
+    /// Get the safety of a player's king.
+    ///
+    /// Enemy pieces attacking the squares around the king are penalized more
+    /// the more of them join the attack, and pawns in front of a king on its
+    /// own side of the board shelter it, while open files next to it do not.
+    fn get_king_safety_score(&self, board: &Board, color: Color) -> Score {
+        let king_pos = match board.get_king_pos(color) {
+            Some(pos) => pos,
+            None => return Score::default(),
+        };
+
+        // The king's square and its neighbours, leaving out the ones
+        // the enemy cannot attack because their own pieces stand there
+        let mut zone = 0;
+        for row in -1..=1 {
+            for col in -1..=1 {
+                let pos = Position::new(king_pos.get_row() + row, king_pos.get_col() + col);
+                if pos.is_on_board() && !board.has_ally_piece(pos, !color) {
+                    zone |= get_square_bit(pos);
+                }
+            }
+        }
+
+        let mut attackers = 0;
+        let mut attack = Score::default();
+        for piece in Self::get_pieces(board, !color) {
+            if !piece.is_pawn() && !piece.is_king() && get_attacks(piece, board) & zone != 0 {
+                attackers += 1;
+                attack += self.king_attack[get_piece_index(piece)];
+            }
+        }
+        let mut score = attack * self.king_attackers_scale[attackers.min(7)];
+
+        let has_pawn =
+            |color: Color, row: i32, col: i32| match board.get_piece(Position::new(row, col)) {
+                Some(piece) => piece.is_pawn() && piece.get_color() == color,
+                None => false,
+            };
+        let forward = match color {
+            Color::White => 1,
+            Color::Black => -1,
+        };
+        let on_own_side = match color {
+            Color::White => king_pos.get_row() <= 1,
+            Color::Black => king_pos.get_row() >= 6,
+        };
+        for col in (king_pos.get_col() - 1)..=(king_pos.get_col() + 1) {
+            if !(0..8).contains(&col) {
+                continue;
+            }
+
+            if on_own_side {
+                for distance in 1..=2 {
+                    if has_pawn(color, king_pos.get_row() + forward * distance, col) {
+                        score += self.pawn_shield;
+                    }
+                }
+            }
+
+            if !(0..8).any(|row| has_pawn(color, row, col)) {
+                score += if (0..8).any(|row| has_pawn(!color, row, col)) {
+                    self.semi_open_file_near_king
+                } else {
+                    self.open_file_near_king
+                };
+            }
+        }
+
+        score
+    }
+
+    /// Get the mobility of a player's pieces, counting the squares
+    /// they can move to that are not attacked by an enemy pawn.
+    fn get_mobility_score(&self, board: &Board, color: Color) -> Score {
+        let attacked_by_pawn = |pos: Position| {
+            let ahead = pos.pawn_up(color);
+            [ahead.next_left(), ahead.next_right()]
+                .iter()
+                .any(|pos| match board.get_piece(*pos) {
+                    Some(piece) => piece.is_pawn() && piece.get_color() != color,
+                    None => false,
+                })
+        };
+
+        let mut safe = 0;
+        for square in 0..64 {
+            let pos = Position::new(square / 8, square % 8);
+            if !board.has_ally_piece(pos, color) && !attacked_by_pawn(pos) {
+                safe |= get_square_bit(pos);
+            }
+        }
+
+        let mut score = Score::default();
+        for piece in Self::get_pieces(board, color) {
+            if piece.is_pawn() || piece.is_king() {
+                continue;
+            }
+
+            let squares = (get_attacks(piece, board) & safe).count_ones();
+            score += self.mobility[get_piece_index(piece)] * squares as f64;
+        }
+        score
+    }
+}
+
+/// A named part of the evaluation function.
+///
+/// Each term is scored separately for both players, and
+/// can be given its own weight in the `EvalParams`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EvalTerm {
+    Material,
+    PieceSquare,
+    Pawns,
+    KingSafety,
+    Mobility,
+}
+
+impl EvalTerm {
+    /// Every term of the evaluation, in order.
+    pub const ALL: [Self; 5] = [
+        Self::Material,
+        Self::PieceSquare,
+        Self::Pawns,
+        Self::KingSafety,
+        Self::Mobility,
+    ];
+
+    /// Get the name of the term, such as `"king safety"`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Material => "material",
+            Self::PieceSquare => "piece-square",
+            Self::Pawns => "pawns",
+            Self::KingSafety => "king safety",
+            Self::Mobility => "mobility",
+        }
+    }
+}
+
+impl core::fmt::Display for EvalTerm {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// Parse evaluation parameters from the text they are displayed as.
+///
+/// Each parameter is written as its name, a colon, and its values,
+/// which may continue onto the following lines. Parameters that are
+/// left out keep their default values, so a file only needs to list
+/// the ones it changes. Anything after a `#` is a comment.
+pub fn parse_eval_params(text: &str) -> Result<EvalParams, String> {
+    let mut entries: Vec<(usize, &str, Vec<f64>)> = vec![];
+    for (number, line) in text.lines().enumerate() {
+        let line = match line.find('#') {
+            Some(comment) => &line[..comment],
+            None => line,
+        }
+        .trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let values = match line.find(':') {
+            Some(colon) => {
+                entries.push((number + 1, line[..colon].trim(), vec![]));
+                &line[colon + 1..]
+            }
+            None => line,
+        };
+        let (_, _, entry) = entries
+            .last_mut()
+            .ok_or_else(|| format!("line {}: expected a parameter name", number + 1))?;
+        for value in values.split_ascii_whitespace() {
+            entry.push(
+                value
+                    .parse()
+                    .map_err(|_| format!("line {}: `{}` is not a number", number + 1, value))?,
+            );
+        }
+    }
+
+    let mut params = DEFAULT_EVAL_PARAMS;
+    let mut targets = params.get_entries_mut();
+    for (number, name, values) in entries {
+        let (_, target) = targets
+            .iter_mut()
+            .find(|(target, _)| target == name)
+            .ok_or_else(|| format!("line {}: unknown parameter `{}`", number, name))?;
+        if target.len() != values.len() {
+            return Err(format!(
+                "line {}: `{}` has {} values, but {} were given",
+                number,
+                name,
+                target.len(),
+                values.len()
+            ));
+        }
+        for (target, value) in target.iter_mut().zip(values) {
+            **target = value;
+        }
+    }
+    drop(targets);
+    Ok(params)
+}
+
+/// Break the evaluation of a board with the default parameters down into its terms.
+///
+/// This is the same evaluation as `Board::value_for`, except
+/// that it is never reversed for variants like Antichess.
+pub fn eval_trace(board: &Board) -> EvalTrace {
+    DEFAULT_EVAL_PARAMS.trace(board)
+}
+
+/// A breakdown of the evaluation of a board into
+/// the score of every term for each player.
+///
+/// When displayed, this prints a table of every term
+/// for both players in the middlegame and the endgame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EvalTrace {
+    scores: [[Score; 2]; 5],
+    weights: [f64; 5],
+    phase: f64,
+}
+
+impl EvalTrace {
+    /// Get the score of a term for a player, multiplied by the term's weight.
+    #[inline]
+    pub fn get_score(&self, term: EvalTerm, color: Color) -> Score {
+        self.scores[term as usize][color as usize] * self.weights[term as usize]
+    }
+
+    /// Get the score of a term for a player, minus the opponent's score.
+    #[inline]
+    pub fn get_difference(&self, term: EvalTerm, color: Color) -> Score {
+        self.get_score(term, color) - self.get_score(term, !color)
+    }
+
+    /// Get the sum of the scores of every term for a player.
+    pub fn get_total(&self, color: Color) -> Score {
+        let mut total = Score::default();
+        for term in &EvalTerm::ALL {
+            total += self.get_score(*term, color);
+        }
+        total
+    }
+
+    /// Get the game phase of the board, where 1.0 is the opening and 0.0 is a bare endgame.
+    #[inline]
+    pub fn get_phase(&self) -> f64 {
+        self.phase
+    }
+
+    /// Get the final evaluation of the board from the perspective of a player.
+    #[inline]
+    pub fn get_value(&self, color: Color) -> f64 {
+        (self.get_total(color) - self.get_total(!color)).taper(self.phase)
+    }
+}
+
+impl core::fmt::Display for EvalTrace {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let row = |f: &mut core::fmt::Formatter, name: &str, white: Score, black: Score| {
+            let total = white - black;
+            writeln!(
+                f,
+                "{:<13}|{:>8.2}{:>8.2} |{:>8.2}{:>8.2} |{:>8.2}{:>8.2}",
+                name,
+                white.get_middlegame(),
+                white.get_endgame(),
+                black.get_middlegame(),
+                black.get_endgame(),
+                total.get_middlegame(),
+                total.get_endgame(),
+            )
+        };
+
+        writeln!(
+            f,
+            "{:<13}|{:>16} |{:>16} |{:>16}",
+            "Term", "White", "Black", "Total"
+        )?;
+        writeln!(
+            f,
+            "{:<13}|{:>8}{:>8} |{:>8}{:>8} |{:>8}{:>8}",
+            "", "MG", "EG", "MG", "EG", "MG", "EG"
+        )?;
+        writeln!(f, "{:-<13}+{:-<17}+{:-<17}+{:-<16}", "", "", "", "")?;
+        for term in &EvalTerm::ALL {
+            row(
+                f,
+                term.get_name(),
+                self.get_score(*term, Color::White),
+                self.get_score(*term, Color::Black),
+            )?;
+        }
+        writeln!(f, "{:-<13}+{:-<17}+{:-<17}+{:-<16}", "", "", "", "")?;
+        row(
+            f,
+            "total",
+            self.get_total(Color::White),
+            self.get_total(Color::Black),
+        )?;
+        writeln!(f)?;
+        writeln!(f, "Game phase: {:.2} (1.00 is the opening)", self.phase)?;
+        write!(
+            f,
+            "Evaluation: {:.2} from White's perspective",
+            self.get_value(Color::White)
+        )
+    }
+}
+
+/// A board that is evaluated with a given set of parameters, caching
+/// the evaluation of its pawn structure in a pawn hash table.
+///
+/// This can be searched just like a board, and shares the
+/// same table and parameters with all of the boards after it.
+#[derive(Clone, Copy)]
+pub struct Evaluator<'a> {
+    board: Board,
+    params: &'a EvalParams,
+    pawns: &'a PawnHashTable,
+}
+
+impl<'a> Evaluator<'a> {
+    /// Create an evaluator for a board.
+    pub fn new(board: Board, params: &'a EvalParams, pawns: &'a PawnHashTable) -> Self {
+        Self {
+            board,
+            params,
+            pawns,
+        }
+    }
+
+    /// Get the board being evaluated.
+    #[inline]
+    pub fn get_board(&self) -> Board {
+        self.board
+    }
+
+    /// Get the parameters used to evaluate the board.
+    #[inline]
+    pub fn get_params(&self) -> &'a EvalParams {
+        self.params
+    }
+}
+
+impl Evaluate for Evaluator<'_> {
+    fn value_for(&self, color: Color) -> f64 {
+        let pawns = self.pawns.probe(&self.board, self.params);
+        let value = self.params.evaluate_with_pawns(&self.board, color, &pawns);
+
+        if self.board.get_variant().has_forced_captures() {
+            // Losing material is the goal in Antichess
+            -value
+        } else {
+            value
+        }
+    }
+
+    fn get_terminal_value_for(&self, color: Color) -> f64 {
+        self.board.get_terminal_value_for(color)
+    }
+
+    #[inline]
+    fn get_current_player_color(&self) -> Color {
+        self.board.get_turn_color()
+    }
+
+    #[inline]
+    fn get_eval_hash_key(&self) -> Option<u64> {
+        Some(self.board.get_hash_key())
+    }
+
+    #[inline]
+    fn get_legal_moves(&self) -> Vec<Move> {
+        self.board.get_legal_moves()
+    }
+
+    #[inline]
+    fn apply_eval_move(&self, m: Move) -> Self {
+        Self {
+            board: self.board.apply_eval_move(m),
+            ..*self
+        }
+    }
+}
+
+/// Get the index of a type of piece in the parameter tables.
+#[inline]
+fn get_piece_index(piece: Piece) -> usize {
+    match piece {
+        Piece::King(_, _) => 0,
+        Piece::Queen(_, _) => 1,
+        Piece::Rook(_, _) => 2,
+        Piece::Bishop(_, _) => 3,
+        Piece::Knight(_, _) => 4,
+        Piece::Pawn(_, _) => 5,
+    }
+}
+
+/// The steps a knight jumps by, as row and column steps.
+const KNIGHT_STEPS: [(i32, i32); 8] = [
+    (1, 2),
+    (2, 1),
+    (-1, 2),
+    (-2, 1),
+    (1, -2),
+    (2, -1),
+    (-1, -2),
+    (-2, -1),
+];
+
+/// Get the bit of a square in a set of squares.
+#[inline]
+fn get_square_bit(pos: Position) -> u64 {
+    1 << (pos.get_row() * 8 + pos.get_col())
+}
+
+/// Get the set of squares a knight, bishop, rook or queen attacks.
+///
+/// Each ray of a sliding piece stops at the first piece in its way, which
+/// is included whatever its color. Pawns and kings attack nothing here.
+fn get_attacks(piece: Piece, board: &Board) -> u64 {
+    let (steps, slides): ([&[(i32, i32)]; 2], bool) = match piece {
+        Piece::Queen(_, _) => ([&ORTHOGONAL_DIRECTIONS, &DIAGONAL_DIRECTIONS], true),
+        Piece::Rook(_, _) => ([&ORTHOGONAL_DIRECTIONS, &[]], true),
+        Piece::Bishop(_, _) => ([&DIAGONAL_DIRECTIONS, &[]], true),
+        Piece::Knight(_, _) => ([&KNIGHT_STEPS, &[]], false),
+        Piece::King(_, _) | Piece::Pawn(_, _) => return 0,
+    };
+
+    let pos = piece.get_pos();
+    let mut attacks = 0;
+    for (row_step, col_step) in steps.iter().flat_map(|steps| steps.iter()) {
+        let mut to = Position::new(pos.get_row() + row_step, pos.get_col() + col_step);
+        while to.is_on_board() {
+            attacks |= get_square_bit(to);
+            if !slides || board.has_piece(to) {
+                break;
+            }
+            to = Position::new(to.get_row() + row_step, to.get_col() + col_step);
+        }
+    }
+    attacks
+}
+
+/// The names of the types of pieces in the order of the parameter tables.
+const PIECE_NAMES: [&str; 6] = ["king", "queen", "rook", "bishop", "knight", "pawn"];
+
+/// The evaluation parameters used by `Board::value_for`.
+pub const DEFAULT_EVAL_PARAMS: EvalParams = EvalParams {
+    material: [
+        Score::new(0.0, 0.0),
+        Score::new(90.0, 94.0),
+        Score::new(50.0, 52.0),
+        Score::new(30.0, 30.0),
+        Score::new(30.0, 28.0),
+        Score::new(10.0, 12.0),
+    ],
+    middlegame_tables: [
+        MIDDLEGAME_KING_TABLE,
+        MIDDLEGAME_QUEEN_TABLE,
+        MIDDLEGAME_ROOK_TABLE,
+        MIDDLEGAME_BISHOP_TABLE,
+        MIDDLEGAME_KNIGHT_TABLE,
+        MIDDLEGAME_PAWN_TABLE,
+    ],
+    endgame_tables: [
+        ENDGAME_KING_TABLE,
+        ENDGAME_QUEEN_TABLE,
+        ENDGAME_ROOK_TABLE,
+        ENDGAME_BISHOP_TABLE,
+        ENDGAME_KNIGHT_TABLE,
+        ENDGAME_PAWN_TABLE,
+    ],
+    passed_pawn: [
+        Score::new(0.0, 0.0),
+        Score::new(0.5, 1.0),
+        Score::new(1.0, 1.5),
+        Score::new(1.5, 3.0),
+        Score::new(3.0, 5.0),
+        Score::new(5.0, 9.0),
+        Score::new(8.0, 14.0),
+        Score::new(0.0, 0.0),
+    ],
+    blocked_passed_pawn: [
+        Score::new(0.0, 0.0),
+        Score::new(0.3, 0.5),
+        Score::new(0.5, 0.8),
+        Score::new(0.8, 1.5),
+        Score::new(1.5, 2.5),
+        Score::new(2.5, 4.5),
+        Score::new(4.0, 7.0),
+        Score::new(0.0, 0.0),
+    ],
+    candidate_passer: [
+        Score::new(0.0, 0.0),
+        Score::new(0.2, 0.4),
+        Score::new(0.3, 0.6),
+        Score::new(0.5, 1.0),
+        Score::new(1.0, 2.0),
+        Score::new(1.5, 3.0),
+        Score::new(0.0, 0.0),
+        Score::new(0.0, 0.0),
+    ],
+    doubled_pawn: Score::new(-1.0, -2.0),
+    isolated_pawn: Score::new(-1.0, -1.5),
+    backward_pawn: Score::new(-0.8, -1.0),
+    pawn_chain: Score::new(0.5, 0.3),
+    king_attack: [
+        Score::new(0.0, 0.0),
+        Score::new(-3.0, -0.5),
+        Score::new(-2.0, -0.25),
+        Score::new(-1.25, -0.25),
+        Score::new(-1.25, -0.25),
+        Score::new(0.0, 0.0),
+    ],
+    king_attackers_scale: [0.0, 0.0, 0.5, 0.75, 0.875, 0.9375, 0.96875, 1.0],
+    pawn_shield: Score::new(0.75, 0.0),
+    semi_open_file_near_king: Score::new(-0.75, 0.0),
+    open_file_near_king: Score::new(-1.5, 0.0),
+    mobility: [
+        Score::new(0.0, 0.0),
+        Score::new(0.125, 0.25),
+        Score::new(0.25, 0.5),
+        Score::new(0.375, 0.5),
+        Score::new(0.5, 0.5),
+        Score::new(0.0, 0.0),
+    ],
+    term_weights: [1.0; 5],
+};
+
+const MIDDLEGAME_KING_TABLE: PieceSquareTable = [
+    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
+    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
+    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
+    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
+    [-2.0, -3.0, -3.0, -4.0, -4.0, -3.0, -3.0, -2.0],
+    [-1.0, -2.0, -2.0, -2.0, -2.0, -2.0, -2.0, -1.0],
+    [2.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 2.0],
+    [2.0, 3.0, 1.0, 0.0, 0.0, 1.0, 3.0, 2.0],
+];
+
+const ENDGAME_KING_TABLE: PieceSquareTable = [
+    [-5.0, -4.0, -3.0, -2.0, -2.0, -3.0, -4.0, -5.0],
+    [-3.0, -2.0, -1.0, 0.0, 0.0, -1.0, -2.0, -3.0],
+    [-3.0, -1.0, 2.0, 3.0, 3.0, 2.0, -1.0, -3.0],
+    [-3.0, -1.0, 3.0, 4.0, 4.0, 3.0, -1.0, -3.0],
+    [-3.0, -1.0, 3.0, 4.0, 4.0, 3.0, -1.0, -3.0],
+    [-3.0, -1.0, 2.0, 3.0, 3.0, 2.0, -1.0, -3.0],
+    [-3.0, -3.0, 0.0, 0.0, 0.0, 0.0, -3.0, -3.0],
+    [-5.0, -3.0, -3.0, -3.0, -3.0, -3.0, -3.0, -5.0],
+];
+
+const MIDDLEGAME_QUEEN_TABLE: PieceSquareTable = [
+    [-2.0, -1.0, -1.0, -0.5, -0.5, -1.0, -1.0, -2.0],
+    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
+    [-0.5, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -0.5],
+    [0.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -0.5],
+    [-1.0, 0.5, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-1.0, -0.0, -1.0, -0.5, -0.5, -0.5, -1.0, -2.0],
+];
+
+const ENDGAME_QUEEN_TABLE: PieceSquareTable = [
+    [-2.0, -1.0, -1.0, -0.5, -0.5, -1.0, -1.0, -2.0],
+    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
+    [-0.5, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -0.5],
+    [-0.5, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -0.5],
+    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
+    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-2.0, -1.0, -1.0, -0.5, -0.5, -1.0, -1.0, -2.0],
+];
+
+const MIDDLEGAME_ROOK_TABLE: PieceSquareTable = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5],
+    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
+    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
+    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
+    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
+    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
+    [0.0, 0.0, 0.0, 0.5, 0.5, 0.0, 0.0, 0.0],
+];
+
+const ENDGAME_ROOK_TABLE: PieceSquareTable = [
+    [0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5],
+    [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+];
+
+const MIDDLEGAME_BISHOP_TABLE: PieceSquareTable = [
+    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
+    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
+    [-1.0, 0.5, 0.5, 1.0, 1.0, 0.5, 0.5, -1.0],
+    [-1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, -1.0],
+    [-1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
+    [-1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.5, -1.0],
+    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
+];
+
+const ENDGAME_BISHOP_TABLE: PieceSquareTable = [
+    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
+    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
+    [-1.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
+    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
+];
+
+const MIDDLEGAME_KNIGHT_TABLE: PieceSquareTable = [
+    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
+    [-4.0, -2.0, 0.0, 0.0, 0.0, 0.0, -2.0, -4.0],
+    [-3.0, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -3.0],
+    [-3.0, 0.5, 1.5, 2.0, 2.0, 1.5, 0.5, -3.0],
+    [-3.0, 0.0, 1.5, 2.0, 2.0, 1.5, 0.0, -3.0],
+    [-3.0, 0.5, 1.0, 1.5, 1.5, 1.0, 0.5, -3.0],
+    [-4.0, -2.0, 0.0, 0.5, 0.5, 0.0, -2.0, -4.0],
+    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
+];
+
+const ENDGAME_KNIGHT_TABLE: PieceSquareTable = [
+    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
+    [-4.0, -2.0, 0.0, 0.0, 0.0, 0.0, -2.0, -4.0],
+    [-3.0, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -3.0],
+    [-3.0, 0.0, 1.5, 2.0, 2.0, 1.5, 0.0, -3.0],
+    [-3.0, 0.0, 1.5, 2.0, 2.0, 1.5, 0.0, -3.0],
+    [-3.0, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -3.0],
+    [-4.0, -2.0, 0.0, 0.0, 0.0, 0.0, -2.0, -4.0],
+    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
+];
+
+const MIDDLEGAME_PAWN_TABLE: PieceSquareTable = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0],
+    [1.0, 1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 1.0],
+    [0.5, 0.5, 1.0, 2.5, 2.5, 1.0, 0.5, 0.5],
+    [0.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0],
+    [0.5, -0.5, -1.0, 0.0, 0.0, -1.0, -0.5, 0.5],
+    [0.5, 1.5, -1.0, -2.0, -2.0, 1.0, 1.5, 0.5],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+];
+
+const ENDGAME_PAWN_TABLE: PieceSquareTable = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [8.0, 8.0, 8.0, 8.0, 8.0, 8.0, 8.0, 8.0],
+    [5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0],
+    [3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0],
+    [1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5],
+    [0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::*;
+    use crate::util::parse_fen;
+    use crate::{Evaluate, BLACK, WHITE};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_game_phase() {
+        assert_eq!(Board::default().get_game_phase(), 1.0);
+        let board = parse_fen("4k3/pppppppp/8/8/8/8/PPPPPPPP/4K3 w - - 0 1").unwrap();
+        assert_eq!(board.get_game_phase(), 0.0);
+        let board = parse_fen("3qk3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert_eq!(board.get_game_phase(), 8.0 / 24.0);
+    }
+
+    #[test]
+    fn test_tapered_king() {
+        // In the middlegame the king belongs behind its pawns...
+        let params = EvalParams::default();
+        let castled = params.get_piece_square(Piece::King(WHITE, G1));
+        let central = params.get_piece_square(Piece::King(WHITE, E4));
+        assert!(castled.get_middlegame() > central.get_middlegame());
+        // ...but in the endgame it belongs in the center.
+        assert!(castled.get_endgame() < central.get_endgame());
+
+        // Black's tables are mirrored
+        assert_eq!(castled, params.get_piece_square(Piece::King(BLACK, G8)));
+
+        let centralized = parse_fen("7k/8/8/4p3/4K3/8/P7/8 w - - 0 1").unwrap();
+        let cornered = parse_fen("7k/8/8/4p3/8/8/P7/7K w - - 0 1").unwrap();
+        assert!(centralized.value_for(WHITE) > cornered.value_for(WHITE));
+
+        assert_eq!(Board::default().value_for(WHITE), 0.0);
+    }
+
+    #[test]
+    fn test_king_safety_and_mobility() {
+        let params = DEFAULT_EVAL_PARAMS;
+        let term = |fen: &str, term: EvalTerm| {
+            let board = parse_fen(fen).unwrap();
+            params.get_term_score(&board, &PawnEntry::new(&board, &params), term, WHITE)
+        };
+
+        // A rook in the middle of an empty board can move to 14 squares
+        assert_eq!(
+            term("4k3/8/8/8/3R4/8/8/4K3 w - - 0 1", EvalTerm::Mobility),
+            params.mobility[2] * 14.0
+        );
+        // ...but one of them is attacked by a pawn
+        assert_eq!(
+            term("4k3/8/4p3/8/3R4/8/8/4K3 w - - 0 1", EvalTerm::Mobility),
+            params.mobility[2] * 13.0
+        );
+
+        // A castled king with all three pawns in front of it
+        assert_eq!(
+            term("6k1/8/8/8/8/8/5PPP/6K1 w - - 0 1", EvalTerm::KingSafety),
+            params.pawn_shield * 3.0
+        );
+        assert_eq!(
+            term("6k1/8/8/8/8/8/5P1P/6K1 w - - 0 1", EvalTerm::KingSafety),
+            params.pawn_shield * 2.0 + params.open_file_near_king
+        );
+        // One attacker is not dangerous on its own, but two are
+        assert_eq!(
+            term("6k1/8/8/8/8/8/5PPP/r5K1 w - - 0 1", EvalTerm::KingSafety),
+            params.pawn_shield * 3.0
+        );
+        assert_eq!(
+            term("6k1/8/8/8/7q/8/5PPP/r5K1 w - - 0 1", EvalTerm::KingSafety),
+            params.pawn_shield * 3.0
+                + (params.king_attack[1] + params.king_attack[2]) * params.king_attackers_scale[2]
+        );
+
+        // Terms can be weighted separately
+        let board = parse_fen("6k1/8/8/8/7q/8/5PPP/r5K1 w - - 0 1").unwrap();
+        let without_king_safety = params.set_term_weight(EvalTerm::KingSafety, 0.0);
+        assert_eq!(
+            without_king_safety.get_term_weight(EvalTerm::KingSafety),
+            0.0
+        );
+        assert_ne!(
+            without_king_safety.evaluate(&board, WHITE),
+            params.evaluate(&board, WHITE)
+        );
+    }
+
+    #[test]
+    fn test_eval_trace() {
+        // Both sides score the same for every term in the starting position
+        let trace = eval_trace(&Board::default());
+        for term in &EvalTerm::ALL {
+            assert_eq!(trace.get_score(*term, WHITE), trace.get_score(*term, BLACK));
+            assert_eq!(trace.get_difference(*term, WHITE), Score::default());
+        }
+        assert_eq!(trace.get_phase(), 1.0);
+
+        // The terms add up to the evaluation
+        let board =
+            parse_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+        let trace = eval_trace(&board);
+        assert_eq!(trace.get_value(WHITE), board.value_for(WHITE));
+        assert_eq!(trace.get_value(BLACK), board.value_for(BLACK));
+        assert_eq!(
+            trace.get_difference(EvalTerm::Material, WHITE),
+            Score::default()
+        );
+
+        // Terms without any weight do not count
+        let params = DEFAULT_EVAL_PARAMS.set_term_weight(EvalTerm::Material, 0.0);
+        let trace = params.trace(&board);
+        assert_eq!(trace.get_score(EvalTerm::Material, WHITE), Score::default());
+        assert_eq!(trace.get_value(WHITE), params.evaluate(&board, WHITE));
+    }
+
+    #[test]
+    fn test_parse_eval_params() {
+        // Parameters survive being written out and read back
+        let params = DEFAULT_EVAL_PARAMS
+            .set_material(Piece::Knight(WHITE, A1), Score::new(32.5, 29.0))
+            .set_term_weight(EvalTerm::Mobility, 0.5);
+        assert_eq!(parse_eval_params(&params.to_string()), Ok(params));
+
+        // Anything left out keeps its default value
+        let mut table = [[0.0; 8]; 8];
+        table[0] = [1.0; 8];
+        let params = parse_eval_params(
+            "# a queen-loving personality
+            material queen: 100 100
+            middlegame table queen:
+                1 1 1 1 1 1 1 1  # on the eighth rank
+                0 0 0 0 0 0 0 0
+                0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+                0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+                0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+            weight king safety: 2",
+        )
+        .unwrap();
+        let queen = Piece::Queen(BLACK, D8);
+        assert_eq!(params.get_material(queen), Score::new(100.0, 100.0));
+        assert_eq!(
+            params.get_piece_square_tables(queen),
+            (table, DEFAULT_EVAL_PARAMS.get_piece_square_tables(queen).1)
+        );
+        assert_eq!(params.get_term_weight(EvalTerm::KingSafety), 2.0);
+        assert_eq!(params.get_term_weight(EvalTerm::Material), 1.0);
+        assert_eq!(params.mobility, DEFAULT_EVAL_PARAMS.mobility);
+
+        assert!(parse_eval_params("material queen: 100").is_err());
+        assert!(parse_eval_params("material amazon: 120 120").is_err());
+        assert!(parse_eval_params("material queen: 100 lots").is_err());
+        assert!(parse_eval_params("100 100").is_err());
+    }
+
+    #[test]
+    fn test_tunable_values() {
+        // Everything but the term weights can be tuned, in the order it is written in
+        let mut params = DEFAULT_EVAL_PARAMS;
+        let written: Vec<*const f64> = params
+            .get_entries_mut()
+            .into_iter()
+            .filter(|(name, _)| !name.starts_with("weight "))
+            .flat_map(|(_, values)| values)
+            .map(|value| value as *const f64)
+            .collect();
+        let tunable: Vec<*const f64> = params
+            .get_tunable_values_mut()
+            .map(|value| value as *const f64)
+            .collect();
+        assert_eq!(tunable, written);
+
+        *params.get_tunable_value_mut(0).unwrap() += 1.0;
+        assert_eq!(
+            params.get_material(Piece::King(WHITE, E1)),
+            DEFAULT_EVAL_PARAMS.get_material(Piece::King(WHITE, E1)) + Score::new(1.0, 0.0)
+        );
+        assert!(params.get_tunable_value_mut(written.len()).is_none());
+    }
+
+    #[test]
+    fn test_eval_symmetry() {
+        // The evaluation must not favor either color, so every position
+        // is worth the same to one player as its color-flipped mirror
+        // image is to the other
+        for fen in &[
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            "r1bq1rk1/pp2bppp/2n1pn2/3p4/2PP4/2N1PN2/PP3PPP/R2QKB1R w KQ - 0 8",
+            "6k1/5ppp/8/3P4/2p5/8/PP3PPP/6K1 w - - 0 1",
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R[Pn] w KQkq - 0 1",
+        ] {
+            let board = parse_fen(fen).unwrap();
+            let mirror = board.flip_colors();
+            assert_eq!(board.value_for(WHITE), -mirror.value_for(WHITE), "{}", fen);
+            assert_eq!(board.value_for(WHITE), mirror.value_for(BLACK), "{}", fen);
+
+            let (trace, mirror_trace) = (eval_trace(&board), eval_trace(&mirror));
+            for term in &EvalTerm::ALL {
+                for color in &[WHITE, BLACK] {
+                    assert_eq!(
+                        trace.get_score(*term, *color),
+                        mirror_trace.get_score(*term, !*color),
+                        "{} for {} in {}",
+                        term,
+                        color,
+                        fen
+                    );
+                }
+            }
+        }
+
+        // Every table is the same for Black as it is for White on the mirrored square
+        let params = DEFAULT_EVAL_PARAMS;
+        for square in 0..64 {
+            let pos = Position::new(square / 8, square % 8);
+            let mirror = Position::new(7 - pos.get_row(), pos.get_col());
+            for piece in &[
+                Piece::King(WHITE, pos),
+                Piece::Queen(WHITE, pos),
+                Piece::Rook(WHITE, pos),
+                Piece::Bishop(WHITE, pos),
+                Piece::Knight(WHITE, pos),
+                Piece::Pawn(WHITE, pos),
+            ] {
+                assert_eq!(
+                    params.get_piece_value(*piece),
+                    params.get_piece_value(piece.with_color(BLACK).move_to(mirror))
+                );
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use alloc::string::String;
+use core::time::Duration;
 
 use crate::board::Board;
+use crate::clock::{Clock, TimeControl};
 use crate::util::{format_fen, parse_fen, parse_san_move};
-use crate::{Color, GameResult};
+use crate::{Color, DrawReason, GameResult, WinReason};
 
 pub enum GameAction {
     // accept draw if previous action was OfferDraw
@@ -34,25 +36,25 @@ pub enum GameError {
     InvalidPosition,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GameOver {
-    WhiteCheckmates,
-    WhiteResigns,
-    BlackCheckmates,
-    BlackResigns,
-    Stalemate,
-    DrawAccepted,
+    // the color of the winner, and how they won
+    Victory(Color, WinReason),
+    // why the game was drawn
+    Draw(DrawReason),
 }
 
 // wrapper around chess_engine::Board
 //
 // abstractions for two player games, like offering/accepting a draw.
 // status is Some when the game is over.
+// clock is Some when the game is played with a time control.
 #[derive(Default)]
 pub struct Game {
     pub board: Board,
     pub draw_offered: Option<Color>,
     pub status: Option<GameOver>,
+    pub clock: Option<Clock>,
 }
 
 impl Game {
@@ -71,6 +73,7 @@ impl Game {
             board,
             draw_offered,
             status,
+            clock: None,
         })
     }
 
@@ -83,7 +86,83 @@ impl Game {
         self.board.get_turn_color()
     }
 
+    // play the rest of the game under a time control
+    pub fn set_time_control(&mut self, time_control: TimeControl) {
+        self.clock = Some(Clock::new(time_control));
+    }
+
+    // time left for a player, if the game has a clock
+    //
+    // now is the reading of the caller's monotonic clock, as it is
+    // for all of the timed methods.
+    pub fn get_remaining_time(&self, color: Color, now: Duration) -> Option<Duration> {
+        self.clock
+            .as_ref()
+            .map(|clock| clock.get_remaining(color, now))
+    }
+
+    // start the clock of the current player, if it isn't running yet
+    pub fn start_clock(&mut self, now: Duration) {
+        let color = self.get_turn_color();
+        if let Some(clock) = &mut self.clock {
+            if !clock.is_running() {
+                clock.start(color, now);
+            }
+        }
+    }
+
+    // end the game if the current player has run out of time
+    //
+    // the current player loses, unless their opponent could not
+    // checkmate them by any series of legal moves.
+    pub fn check_time(&mut self, now: Duration) -> &Option<GameOver> {
+        let color = self.get_turn_color();
+        if let (None, Some(clock)) = (&self.status, &mut self.clock) {
+            if clock.is_flagged(color, now) {
+                clock.stop(now);
+                self.status = Some(if self.board.has_sufficient_material(!color) {
+                    GameOver::Victory(!color, WinReason::Timeout)
+                } else {
+                    GameOver::Draw(DrawReason::TimeoutVsInsufficientMaterial)
+                });
+            }
+        }
+        &self.status
+    }
+
+    // make a move for current turn, and press the clock
+    //
+    // the clock is started if it isn't running yet, and the game
+    // ends instead if the current player has already run out of time.
+    pub fn make_timed_move(
+        &mut self,
+        action: &GameAction,
+        now: Duration,
+    ) -> Result<&Option<GameOver>, GameError> {
+        if self.status.is_some() {
+            return Err(GameError::GameAlreadyOver {});
+        }
+        self.start_clock(now);
+        if self.check_time(now).is_some() {
+            return Ok(&self.status);
+        }
+
+        self.make_move(action)?;
+        if let Some(clock) = &mut self.clock {
+            match (&self.status, action) {
+                (None, GameAction::MakeMove(_)) | (None, GameAction::OfferDraw(_)) => {
+                    clock.press(now)
+                }
+                (None, GameAction::AcceptDraw) | (None, GameAction::Resign) => {}
+                (Some(_), _) => clock.stop(now),
+            }
+        }
+        Ok(&self.status)
+    }
+
     // make a move for current turn
+    //
+    // this does not touch the clock, use make_timed_move for timed games.
     pub fn make_move(&mut self, action: &GameAction) -> Result<&Option<GameOver>, GameError> {
         if self.status.is_some() {
             return Err(GameError::GameAlreadyOver {});
@@ -100,7 +179,7 @@ impl Game {
     fn accept_draw(&mut self) -> Result<&Option<GameOver>, GameError> {
         if let Some(color) = self.draw_offered {
             if color != self.get_turn_color() {
-                self.status = Some(GameOver::DrawAccepted);
+                self.status = Some(GameOver::Draw(DrawReason::Agreement));
                 return Ok(&self.status);
             }
         }
@@ -132,21 +211,18 @@ impl Game {
             GameResult::IllegalMove(_) => {
                 return Err(GameError::InvalidMove {});
             }
-            GameResult::Stalemate => Some(GameOver::Stalemate),
-            GameResult::Victory(color) => match color {
-                Color::Black => Some(GameOver::BlackCheckmates),
-                Color::White => Some(GameOver::WhiteCheckmates),
-            },
+            GameResult::Draw(reason) => Some(GameOver::Draw(reason)),
+            GameResult::Victory(color, reason) => Some(GameOver::Victory(color, reason)),
         };
         Ok(&self.status)
     }
 
     // resign
     fn resign(&mut self) -> Result<&Option<GameOver>, GameError> {
-        self.status = match self.get_turn_color() {
-            Color::Black => Some(GameOver::BlackResigns),
-            Color::White => Some(GameOver::WhiteResigns),
-        };
+        self.status = Some(GameOver::Victory(
+            !self.get_turn_color(),
+            WinReason::Resignation,
+        ));
         Ok(&self.status)
     }
 }
@@ -176,7 +252,10 @@ mod tests {
             game.make_move(&GameAction::from(game_move))
                 .expect(game_move);
         }
-        assert_eq!(game.status, Some(GameOver::BlackCheckmates));
+        assert_eq!(
+            game.status,
+            Some(GameOver::Victory(Color::Black, WinReason::Checkmate))
+        );
     }
 
     #[test]
@@ -197,4 +276,60 @@ mod tests {
             Some(Piece::Rook(Color::White, Position::pgn("c8").unwrap()))
         )
     }
+
+    #[test]
+    fn test_dead_position() {
+        let mut game = Game::from_fen("8/8/3k4/8/8/8/1r6/KB6 w - - 0 1", None, None).unwrap();
+        game.make_move(&GameAction::from("Kxb2")).unwrap();
+        assert_eq!(game.status, Some(GameOver::Draw(DrawReason::DeadPosition)));
+    }
+
+    #[test]
+    fn test_resign_and_draw() {
+        let mut game = Game::default();
+        game.make_move(&GameAction::OfferDraw(String::from("e4")))
+            .unwrap();
+        game.make_move(&GameAction::AcceptDraw).unwrap();
+        assert_eq!(game.status, Some(GameOver::Draw(DrawReason::Agreement)));
+
+        let mut game = Game::default();
+        game.make_move(&GameAction::Resign).unwrap();
+        assert_eq!(
+            game.status,
+            Some(GameOver::Victory(Color::Black, WinReason::Resignation))
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut game = Game::default();
+        game.set_time_control(TimeControl::increment(
+            Duration::from_secs(60),
+            Duration::from_secs(1),
+        ));
+        game.make_timed_move(&GameAction::from("e4"), Duration::from_secs(0))
+            .unwrap();
+        game.make_timed_move(&GameAction::from("e5"), Duration::from_secs(30))
+            .unwrap();
+        assert_eq!(
+            game.get_remaining_time(Color::Black, Duration::from_secs(30)),
+            Some(Duration::from_secs(31))
+        );
+        assert_eq!(game.check_time(Duration::from_secs(90)), &None);
+        assert_eq!(
+            game.check_time(Duration::from_secs(91)),
+            &Some(GameOver::Victory(Color::Black, WinReason::Timeout))
+        );
+
+        // a lone king can not checkmate
+        let mut game = Game::from_fen("8/8/3k4/8/8/8/3Q4/K7 w - - 0 1", None, None).unwrap();
+        game.set_time_control(TimeControl::sudden_death(Duration::from_secs(1)));
+        game.start_clock(Duration::from_secs(0));
+        assert_eq!(
+            game.make_timed_move(&GameAction::from("Qd3"), Duration::from_secs(2)),
+            Ok(&Some(GameOver::Draw(
+                DrawReason::TimeoutVsInsufficientMaterial
+            )))
+        );
+    }
 }
@@ -1,6 +1,8 @@
 #![no_std]
 #[macro_use]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 use alloc::{
     string::{String, ToString},
     vec::Vec,
@@ -11,47 +13,82 @@ use core::convert::TryFrom;
 mod board;
 pub use board::{Board, BoardBuilder};
 
+mod book;
+pub use book::{get_polyglot_key, BookEntry, OpeningBook};
+
+mod clock;
+pub use clock::{Bonus, Clock, TimeControl, TimePeriod};
+
+mod endgame;
+
+mod eval;
+pub use eval::*;
+
 mod game;
 pub use game::{Game, GameAction, GameError, GameOver};
 
+mod search;
+pub use search::{Ponder, PrincipalVariation, SearchMonitor, SearchProgress};
+
+#[cfg(feature = "threads")]
+mod smp;
+#[cfg(feature = "threads")]
+pub use smp::TranspositionTable;
+
 mod square;
 pub use square::{Square, EMPTY_SQUARE};
 
+mod pawns;
+pub use pawns::{get_pawn_key, PawnEntry, PawnHashTable};
+
+#[cfg(feature = "nnue")]
+mod nnue;
+#[cfg(feature = "nnue")]
+pub use nnue::{Network, NnueEvaluator};
+
 mod piece;
 pub use piece::Piece;
 
+mod pin;
+pub use pin::Pin;
+
+mod pocket;
+pub use pocket::Pocket;
+
 mod position;
 pub use position::*;
 
+#[cfg(feature = "std")]
+mod syzygy;
+#[cfg(feature = "std")]
+pub use syzygy::{Tablebase, TablebaseEvaluator, Wdl};
+
+mod time;
+pub use time::{TimeLimits, TimeManager, TimedMonitor};
+
+mod tune;
+pub use tune::{parse_tuning_positions, Tuner, TuningPosition};
+
 mod util;
 pub use util::*;
 
+mod variant;
+pub use variant::*;
+
 pub const WHITE: Color = Color::White;
 pub const BLACK: Color = Color::Black;
 
 /// The result of a move being played on the board.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum GameResult {
     /// The game is not finished, and the game is still in play.
     Continuing(Board),
-    /// One player, the victor, checkmated the other.
-    /// This stores the color of the winner.
-    Victory(Color),
-    /// The game is drawn. This can be a result of the current player
-    /// having no legal moves and not being in check, or because
-    /// both players have insufficient material on the board.
-    ///
-    /// Insufficient material consists of:
-    /// 1. The player only has a king
-    /// 2. The player only has a king and a knight
-    /// 3. The player only has a king and two knights
-    /// 4. The player only has a king and a bishop
-    /// 5. The player only has a king and two bishops
-    ///
-    /// In a regular game of chess, threefold repetition also triggers
-    /// a stalemate, but this engine does not have builtin support for
-    /// threefold repetition detection yet.
-    Stalemate,
+    /// One player, the victor, won the game.
+    /// This stores the color of the winner, and how they won.
+    Victory(Color, WinReason),
+    /// The game is drawn, for the stored reason.
+    Draw(DrawReason),
     /// An illegal move was made. This can include many things,
     /// such as moving a piece through another piece, attempting
     /// to capture an allied piece, moving non-orthogonally or
@@ -62,6 +99,60 @@ pub enum GameResult {
     IllegalMove(Move),
 }
 
+/// How a game was won.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WinReason {
+    /// The loser was checkmated.
+    Checkmate,
+    /// The loser resigned.
+    Resignation,
+    /// The loser ran out of time, and the winner could still checkmate.
+    Timeout,
+    /// The loser forfeited the game, for example by not showing up
+    /// or by breaking the rules of a tournament.
+    Forfeit,
+    /// The winner won by a rule of the board's variant, such as
+    /// reaching the center of the board in King of the Hill.
+    Variant,
+}
+
+/// Why a game was drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DrawReason {
+    /// The current player has no legal moves and is not in check.
+    Stalemate,
+    /// Neither player has enough material left to ever checkmate the other.
+    ///
+    /// In regular chess, this follows the FIDE rules for dead positions:
+    /// 1. Both players only have kings
+    /// 2. One player has a king and a knight or bishop, and the other only a king
+    /// 3. Both players only have kings and bishops, all on squares of the same color
+    ///
+    /// A king and two knights is not a dead position, because the other
+    /// player can still blunder into checkmate.
+    DeadPosition,
+    /// A player claimed a draw because the same position
+    /// occurred three times.
+    ThreefoldRepetition,
+    /// The same position occurred five times, which draws the
+    /// game without a claim.
+    FivefoldRepetition,
+    /// A player claimed a draw because fifty moves were played by
+    /// each player without a capture or a pawn move.
+    FiftyMoveRule,
+    /// Seventy-five moves were played by each player without a
+    /// capture or a pawn move, which draws the game without a claim.
+    SeventyFiveMoveRule,
+    /// The players agreed to a draw.
+    Agreement,
+    /// A player ran out of time, but their opponent could
+    /// not have checkmated them by any series of legal moves.
+    TimeoutVsInsufficientMaterial,
+    /// The game was drawn by a rule of the board's variant, such as
+    /// both kings reaching the eighth rank in Racing Kings.
+    Variant,
+}
+
 /// The color of a piece.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Color {
@@ -97,7 +188,7 @@ impl core::ops::Not for Color {
 /// A move that can be applied to a board.
 /// When applied to a board, the board assumes that the move is
 /// being applied for the current turn's player.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug)]
 pub enum Move {
     /// If the current player is white, move the king to the C1 square, and the kingside rook to
     /// the D1 square. If the current player is black, however, move the king to the C8 square,
@@ -128,6 +219,12 @@ pub enum Move {
     /// en-passant square is forgotten and can no longer be used.
     Piece(Position, Position),
     Promotion(Position, Position, Piece),
+    /// Drop a piece from the current player's pocket onto an empty square.
+    /// This is only legal in Crazyhouse.
+    ///
+    /// The color and position of the piece itself are ignored;
+    /// only its type matters, including when moves are compared.
+    Drop(Piece, Position),
     /// When played by another player, it awards victory to the other.
     Resign,
 }
@@ -148,6 +245,7 @@ pub enum Move {
 /// - `"e2e4"`
 /// - `"e2 e4"`
 /// - `"e2 to e4"`
+/// - `"N@e4"` (drop a knight from the pocket in Crazyhouse)
 ///
 /// Parsing a move such as `"knight to e4"` or `"Qxe4"` will NOT work.
 impl TryFrom<String> for Move {
@@ -165,7 +263,18 @@ impl TryFrom<String> for Move {
             other => {
                 let words = other.split_whitespace().collect::<Vec<&str>>();
 
-                if words.len() == 1 && words[0].len() == 4 {
+                if words.len() == 1 && words[0].len() == 4 && words[0].chars().nth(1) == Some('@') {
+                    let offboard = Position::new(-1, -1);
+                    let piece = match words[0].chars().next() {
+                        Some('P') | Some('p') => Piece::Pawn(WHITE, offboard),
+                        Some('N') | Some('n') => Piece::Knight(WHITE, offboard),
+                        Some('B') | Some('b') => Piece::Bishop(WHITE, offboard),
+                        Some('R') | Some('r') => Piece::Rook(WHITE, offboard),
+                        Some('Q') | Some('q') => Piece::Queen(WHITE, offboard),
+                        _ => return Err(String::from("invalid drop")),
+                    };
+                    Self::Drop(piece, Position::pgn(&words[0][2..4])?)
+                } else if words.len() == 1 && words[0].len() == 4 {
                     Self::Piece(
                         Position::pgn(&words[0][..2])?,
                         Position::pgn(&words[0][2..4])?,
@@ -176,7 +285,9 @@ impl TryFrom<String> for Move {
                     Self::Piece(Position::pgn(words[0])?, Position::pgn(words[2])?)
                 } else if words.len() == 4 && words[1] == "to" {
                     let piece = Piece::try_from(words[3])?;
-                    if piece.is_king() || piece.is_pawn() {
+                    // kings are only valid promotions in some variants,
+                    // which is checked when the move is played
+                    if piece.is_pawn() {
                         return Err(String::from("invalid promotion"));
                     }
                     Self::Promotion(Position::pgn(words[0])?, Position::pgn(words[2])?, piece)
@@ -205,11 +316,48 @@ impl Move {
     /// - `"e2e4"`
     /// - `"e2 e4"`
     /// - `"e2 to e4"`
+    /// - `"N@e4"` (drop a knight from the pocket in Crazyhouse)
     ///
     /// Parsing a move such as `"knight to e4"` or `"Qxe4"` will NOT work.
     pub fn parse(repr: String) -> Result<Self, String> {
         Self::try_from(repr)
     }
+
+    /// Get the parts of this move that matter when comparing moves,
+    /// in the order the variants are declared.
+    fn get_key(&self) -> (u8, Option<Position>, Option<Position>, Option<Piece>) {
+        match *self {
+            Self::QueenSideCastle => (0, None, None, None),
+            Self::KingSideCastle => (1, None, None, None),
+            Self::Piece(from, to) => (2, Some(from), Some(to), None),
+            Self::Promotion(from, to, piece) => (3, Some(from), Some(to), Some(piece)),
+            Self::Drop(piece, to) => {
+                let piece = piece.with_color(WHITE).move_to(Position::new(-1, -1));
+                (4, None, Some(to), Some(piece))
+            }
+            Self::Resign => (5, None, None, None),
+        }
+    }
+}
+
+impl PartialEq for Move {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_key() == other.get_key()
+    }
+}
+
+impl Eq for Move {}
+
+impl PartialOrd for Move {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Move {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.get_key().cmp(&other.get_key())
+    }
 }
 
 impl core::fmt::Display for Move {
@@ -220,6 +368,19 @@ impl core::fmt::Display for Move {
             Move::Promotion(from, to, piece) => {
                 write!(f, "{} to {} {}", from, to, piece.get_name())
             }
+            Move::Drop(piece, to) => write!(
+                f,
+                "{}@{}",
+                match piece {
+                    Piece::King(_, _) => 'K',
+                    Piece::Queen(_, _) => 'Q',
+                    Piece::Rook(_, _) => 'R',
+                    Piece::Bishop(_, _) => 'B',
+                    Piece::Knight(_, _) => 'N',
+                    Piece::Pawn(_, _) => 'P',
+                },
+                to
+            ),
             Move::KingSideCastle => write!(f, "O-O"),
             Move::QueenSideCastle => write!(f, "O-O-O"),
             Move::Resign => write!(f, "Resign"),
@@ -243,6 +404,26 @@ pub trait Evaluate: Sized {
     /// Apply a move to the board for evaluation.
     fn apply_eval_move(&self, m: Move) -> Self;
 
+    /// Get the value of the board for a given color when the current
+    /// player has no legal moves left.
+    ///
+    /// By default, the player who is unable to move has lost the game.
+    fn get_terminal_value_for(&self, color: Color) -> f64 {
+        if self.get_current_player_color() == color {
+            -999999.0
+        } else {
+            999999.0
+        }
+    }
+
+    /// Get a hash of the position, which lets a search recognise
+    /// positions it has already seen.
+    ///
+    /// By default, positions are not hashed.
+    fn get_eval_hash_key(&self) -> Option<u64> {
+        None
+    }
+
     /// Get the best move for the current player with `depth` number of moves
     /// of lookahead.
     ///
@@ -254,29 +435,82 @@ pub trait Evaluate: Sized {
     /// It's best not to use the rating value by itself for anything, as it
     /// is relative to the other player's move ratings as well.
     fn get_best_next_move(&self, depth: i32) -> (Move, u64, f64) {
-        let legal_moves = self.get_legal_moves();
-        let mut best_move_value = -999999.0;
-        let mut best_move = Move::Resign;
+        search::get_best_next_move(self, depth)
+    }
 
-        let color = self.get_current_player_color();
+    /// Get the best move for the current player like `get_best_next_move`,
+    /// while `monitor` follows the search and can stop it early.
+    ///
+    /// This searches one depth at a time up to `depth`, and reports the
+    /// best line of play after every depth. When the monitor stops the
+    /// search, the best move of the deepest finished depth is returned.
+    fn get_best_next_move_with<M: SearchMonitor>(
+        &self,
+        depth: i32,
+        monitor: &mut M,
+    ) -> (Move, u64, f64) {
+        search::get_best_next_move_with(self, depth, monitor)
+    }
 
-        let mut board_count = 0;
-        for m in &legal_moves {
-            let child_board_value = self.apply_eval_move(*m).minimax(
-                depth,
-                -1000000.0,
-                1000000.0,
-                false,
-                color,
-                &mut board_count,
-            );
-            if child_board_value >= best_move_value {
-                best_move = *m;
-                best_move_value = child_board_value;
-            }
-        }
+    /// Think about the position after the opponent's `expected_reply`
+    /// while they are still thinking, which is called pondering.
+    ///
+    /// This searches like `get_best_next_move_with`, except that `monitor`
+    /// is only asked whether to stop after `ponder.ponderhit()` is called,
+    /// so a time limit can be started then. The search keeps everything
+    /// it found before the ponderhit. If `ponder.miss()` is called instead,
+    /// the search stops and this returns `None`, and the position the
+    /// opponent really reached has to be searched from the start.
+    ///
+    /// A search that finishes before the opponent moves returns right
+    /// away, and its move should only be played after the ponderhit.
+    fn ponder<M: SearchMonitor>(
+        &self,
+        expected_reply: Move,
+        depth: i32,
+        ponder: &Ponder,
+        monitor: &mut M,
+    ) -> Option<(Move, u64, f64)> {
+        search::ponder(
+            &self.apply_eval_move(expected_reply),
+            depth,
+            ponder,
+            monitor,
+        )
+    }
 
-        (best_move, board_count, best_move_value)
+    /// Get the best move for the current player like `get_best_next_move`,
+    /// searching with `threads` threads at once.
+    ///
+    /// The threads share what they find through `table`, which can be
+    /// kept between moves. With a single thread, the search does not
+    /// start any threads, and always finds the same move.
+    #[cfg(feature = "threads")]
+    fn get_best_next_move_parallel(
+        &self,
+        depth: i32,
+        threads: usize,
+        table: &TranspositionTable,
+    ) -> (Move, u64, f64)
+    where
+        Self: Sync,
+    {
+        smp::get_best_next_move_parallel(self, depth, threads, table)
+    }
+
+    /// Get the `count` best moves for the current player with `depth`
+    /// number of moves of lookahead, from best to worst.
+    ///
+    /// This method returns
+    /// 1. The best moves, each with its rating and the line of play
+    ///    that follows it
+    /// 2. The number of boards evaluated to come to a conclusion
+    ///
+    /// Fewer lines are returned if the player has fewer legal moves.
+    fn get_best_next_moves(&self, depth: i32, count: usize) -> (Vec<PrincipalVariation>, u64) {
+        let mut board_count = 0;
+        let lines = search::get_principal_variations(self, depth, count, &mut board_count);
+        (lines, board_count)
     }
 
     /// Get the best move for the current player with `depth` number of moves
@@ -339,6 +573,10 @@ pub trait Evaluate: Sized {
         }
 
         let legal_moves = self.get_legal_moves();
+        if legal_moves.is_empty() {
+            return self.get_terminal_value_for(getting_move_for);
+        }
+
         let mut best_move_value;
 
         if is_maximizing {
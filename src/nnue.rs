@@ -0,0 +1,477 @@
+use super::{Board, Color, Evaluate, Move, Piece, Position, Variant};
+use alloc::{string::String, vec::Vec};
+
+/// The number of inputs to the network: one for every
+/// type of piece of either color on every square.
+const INPUTS: usize = 768;
+/// The largest hidden layer a network file may have.
+const MAX_HIDDEN: usize = 4096;
+/// The magic bytes at the start of every network file.
+const MAGIC: &[u8; 4] = b"CENN";
+
+/// The value of a fully activated neuron in the hidden layer.
+const QA: i32 = 255;
+/// The quantisation factor of the output weights.
+const QB: i32 = 64;
+/// The evaluation of the network is scaled to centipawns by this factor.
+const SCALE: i32 = 400;
+
+/// A small quantised neural network that evaluates positions.
+///
+/// The network is a perceptron with 768 inputs, one for every type of
+/// piece of either color on every square, and a single hidden layer
+/// that is computed once from each player's point of view. The output
+/// combines the side being evaluated's half with the opponent's, so the
+/// network never favors either color. Pockets in Crazyhouse are not
+/// seen by the network.
+///
+/// Networks are loaded from bytes in this format, with every number
+/// in little-endian order:
+///
+/// | Bytes | Contents |
+/// |-|-|
+/// | 4 | The magic bytes `CENN` |
+/// | 4 | The size `H` of the hidden layer, as a `u32` |
+/// | 768 * H * 2 | The weights of each input to the hidden layer, as `i16`s |
+/// | H * 2 | The biases of the hidden layer, as `i16`s |
+/// | 2 * H * 2 | The output weights, as `i16`s, with the evaluated side's half first |
+/// | 4 | The output bias, as an `i32` |
+///
+/// Inputs are numbered from the point of view of the player the hidden
+/// layer belongs to: `384 * side + 64 * piece + square`, where `side` is 0
+/// for their own pieces, `piece` counts pawn, knight, bishop, rook, queen,
+/// and king from 0 to 5, and `square` counts from a1 to h8 for White, with
+/// the ranks flipped for Black. The hidden layer is clipped to between 0
+/// and 255, and the output is multiplied by 400 / (255 * 64) to give
+/// centipawns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Network {
+    hidden: usize,
+    feature_weights: Vec<i16>,
+    feature_biases: Vec<i16>,
+    output_weights: Vec<i16>,
+    output_bias: i32,
+}
+
+impl Network {
+    /// Load a network from the contents of a network file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < 8 || &bytes[..4] != MAGIC {
+            return Err(String::from("not a network file"));
+        }
+
+        let hidden = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+        if hidden == 0 || hidden > MAX_HIDDEN {
+            return Err(format!("unsupported hidden layer size {}", hidden));
+        }
+        let expected = 8 + (INPUTS * hidden + hidden + 2 * hidden) * 2 + 4;
+        if bytes.len() != expected {
+            return Err(format!(
+                "network file should be {} bytes, but is {}",
+                expected,
+                bytes.len()
+            ));
+        }
+
+        let mut rest = &bytes[8..];
+        let mut read_i16s = |count: usize| {
+            let (values, remaining) = rest.split_at(count * 2);
+            rest = remaining;
+            values
+                .chunks(2)
+                .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+                .collect::<Vec<i16>>()
+        };
+        let feature_weights = read_i16s(INPUTS * hidden);
+        let feature_biases = read_i16s(hidden);
+        let output_weights = read_i16s(2 * hidden);
+        let output_bias = i32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]);
+
+        Ok(Self {
+            hidden,
+            feature_weights,
+            feature_biases,
+            output_weights,
+            output_bias,
+        })
+    }
+
+    /// Write the network out in the format it is loaded from.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&(self.hidden as u32).to_le_bytes());
+        for value in self
+            .feature_weights
+            .iter()
+            .chain(&self.feature_biases)
+            .chain(&self.output_weights)
+        {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.output_bias.to_le_bytes());
+        bytes
+    }
+
+    /// Get the size of the hidden layer.
+    #[inline]
+    pub fn get_hidden_size(&self) -> usize {
+        self.hidden
+    }
+
+    /// Evaluate a board from the perspective of a given player,
+    /// in the same units as `Board::value_for`.
+    pub fn evaluate(&self, board: &Board, color: Color) -> f64 {
+        self.evaluate_accumulator(&Accumulator::new(self, board), color)
+    }
+
+    fn evaluate_accumulator(&self, accumulator: &Accumulator, color: Color) -> f64 {
+        let (ours, theirs) = self.output_weights.split_at(self.hidden);
+        // The sum of a large hidden layer does not fit in an `i32`
+        let mut sum = self.output_bias as i64;
+        for (values, weights) in [
+            (accumulator.get_values(color), ours),
+            (accumulator.get_values(!color), theirs),
+        ] {
+            for (value, weight) in values.iter().zip(weights) {
+                sum += (*value as i64).clamp(0, QA as i64) * *weight as i64;
+            }
+        }
+
+        let centipawns = sum * SCALE as i64 / (QA * QB) as i64;
+        // A pawn is worth 10 in the evaluation
+        centipawns as f64 / 10.0
+    }
+
+    /// Get the weights of an input to the hidden layer.
+    #[inline]
+    fn get_weights(&self, feature: usize) -> &[i16] {
+        &self.feature_weights[feature * self.hidden..(feature + 1) * self.hidden]
+    }
+}
+
+/// Get the input of the network for a piece from a player's point of view.
+fn get_feature(piece: Piece, perspective: Color) -> usize {
+    let side = if piece.get_color() == perspective {
+        0
+    } else {
+        1
+    };
+    let index = match piece {
+        Piece::Pawn(_, _) => 0,
+        Piece::Knight(_, _) => 1,
+        Piece::Bishop(_, _) => 2,
+        Piece::Rook(_, _) => 3,
+        Piece::Queen(_, _) => 4,
+        Piece::King(_, _) => 5,
+    };
+    let pos = piece.get_pos();
+    let row = match perspective {
+        Color::White => pos.get_row(),
+        Color::Black => 7 - pos.get_row(),
+    };
+    384 * side + 64 * index + (row * 8 + pos.get_col()) as usize
+}
+
+/// The hidden layer of a network before it is clipped, from the
+/// point of view of White and then Black.
+#[derive(Clone, Debug, PartialEq)]
+struct Accumulator {
+    values: Vec<i16>,
+}
+
+impl Accumulator {
+    /// Compute the hidden layer for a board from scratch.
+    fn new(network: &Network, board: &Board) -> Self {
+        let mut result = Self {
+            values: network.feature_biases.repeat(2),
+        };
+        for square in 0..64 {
+            if let Some(piece) = board.get_piece(Position::new(square / 8, square % 8)) {
+                result.add(network, piece);
+            }
+        }
+        result
+    }
+
+    /// Get the hidden layer from a player's point of view.
+    #[inline]
+    fn get_values(&self, color: Color) -> &[i16] {
+        let hidden = self.values.len() / 2;
+        &self.values[color as usize * hidden..][..hidden]
+    }
+
+    /// Update the hidden layer after a move is applied to a board.
+    ///
+    /// Only the squares that the move can change are compared between
+    /// the two boards: the squares it moves from and to, the pawn taken
+    /// en-passant, the back rank when castling, and the squares around
+    /// an explosion in Atomic.
+    fn update(&mut self, network: &Network, before: &Board, m: Move, after: &Board) {
+        // A move changes at most 11 squares: an explosion around a pawn
+        // that captures en-passant, and the squares the pawn moves from
+        // and captures on
+        let mut squares = [Position::new(-1, -1); 11];
+        let mut count = 0;
+        let mut update_square = |pos: Position| {
+            if !squares[..count].contains(&pos) {
+                squares[count] = pos;
+                count += 1;
+            }
+        };
+
+        match m {
+            Move::KingSideCastle | Move::QueenSideCastle => {
+                let row = match before.get_turn_color() {
+                    Color::White => 0,
+                    Color::Black => 7,
+                };
+                for col in 0..8 {
+                    update_square(Position::new(row, col));
+                }
+            }
+            Move::Piece(from, to) | Move::Promotion(from, to, _) => {
+                update_square(from);
+                update_square(to);
+                if from.get_col() != to.get_col()
+                    && before.get_piece(from).is_some_and(|piece| piece.is_pawn())
+                {
+                    // The pawn that may have been captured en-passant
+                    update_square(Position::new(from.get_row(), to.get_col()));
+                }
+                if before.get_variant() == Variant::Atomic {
+                    for row in to.get_row() - 1..=to.get_row() + 1 {
+                        for col in to.get_col() - 1..=to.get_col() + 1 {
+                            update_square(Position::new(row, col));
+                        }
+                    }
+                }
+            }
+            Move::Drop(_, to) => update_square(to),
+            // Resigning replaces every piece on the board
+            Move::Resign => {
+                *self = Self::new(network, after);
+                return;
+            }
+        }
+
+        for pos in &squares[..count] {
+            let (old, new) = (before.get_piece(*pos), after.get_piece(*pos));
+            if old != new {
+                if let Some(piece) = old {
+                    self.remove(network, piece);
+                }
+                if let Some(piece) = new {
+                    self.add(network, piece);
+                }
+            }
+        }
+    }
+
+    fn add(&mut self, network: &Network, piece: Piece) {
+        let hidden = network.hidden;
+        for (color, values) in [Color::White, Color::Black]
+            .iter()
+            .zip(self.values.chunks_mut(hidden))
+        {
+            let weights = network.get_weights(get_feature(piece, *color));
+            for (value, weight) in values.iter_mut().zip(weights) {
+                *value = value.wrapping_add(*weight);
+            }
+        }
+    }
+
+    fn remove(&mut self, network: &Network, piece: Piece) {
+        let hidden = network.hidden;
+        for (color, values) in [Color::White, Color::Black]
+            .iter()
+            .zip(self.values.chunks_mut(hidden))
+        {
+            let weights = network.get_weights(get_feature(piece, *color));
+            for (value, weight) in values.iter_mut().zip(weights) {
+                *value = value.wrapping_sub(*weight);
+            }
+        }
+    }
+}
+
+/// A board that is evaluated by a neural network.
+///
+/// This can be searched just like a board. The hidden layer of the
+/// network is computed once for the starting board, and then updated
+/// with only the pieces that changed as each move is applied.
+#[derive(Clone, Debug)]
+pub struct NnueEvaluator<'a> {
+    board: Board,
+    network: &'a Network,
+    accumulator: Accumulator,
+}
+
+impl<'a> NnueEvaluator<'a> {
+    /// Create an evaluator for a board.
+    pub fn new(board: Board, network: &'a Network) -> Self {
+        Self {
+            board,
+            network,
+            accumulator: Accumulator::new(network, &board),
+        }
+    }
+
+    /// Get the board being evaluated.
+    #[inline]
+    pub fn get_board(&self) -> Board {
+        self.board
+    }
+
+    /// Get the network used to evaluate the board.
+    #[inline]
+    pub fn get_network(&self) -> &'a Network {
+        self.network
+    }
+}
+
+impl Evaluate for NnueEvaluator<'_> {
+    #[inline]
+    fn value_for(&self, color: Color) -> f64 {
+        self.network.evaluate_accumulator(&self.accumulator, color)
+    }
+
+    fn get_terminal_value_for(&self, color: Color) -> f64 {
+        self.board.get_terminal_value_for(color)
+    }
+
+    #[inline]
+    fn get_current_player_color(&self) -> Color {
+        self.board.get_turn_color()
+    }
+
+    #[inline]
+    fn get_eval_hash_key(&self) -> Option<u64> {
+        Some(self.board.get_hash_key())
+    }
+
+    #[inline]
+    fn get_legal_moves(&self) -> Vec<Move> {
+        self.board.get_legal_moves()
+    }
+
+    fn apply_eval_move(&self, m: Move) -> Self {
+        let board = self.board.apply_eval_move(m);
+        let mut accumulator = self.accumulator.clone();
+        accumulator.update(self.network, &self.board, m, &board);
+        Self {
+            board,
+            network: self.network,
+            accumulator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_fen;
+    use crate::{BoardBuilder, BLACK, WHITE};
+
+    /// Build a network with random weights.
+    fn random_network(hidden: usize) -> Network {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 129) as i16 - 64
+        };
+        Network {
+            hidden,
+            feature_weights: (0..INPUTS * hidden).map(|_| next()).collect(),
+            feature_biases: (0..hidden).map(|_| next()).collect(),
+            output_weights: (0..2 * hidden).map(|_| next()).collect(),
+            output_bias: next() as i32,
+        }
+    }
+
+    #[test]
+    fn test_network_file() {
+        let network = random_network(8);
+        let bytes = network.to_bytes();
+        assert_eq!(Network::from_bytes(&bytes), Ok(network));
+
+        assert!(Network::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Network::from_bytes(b"NNUE\x08\0\0\0").is_err());
+    }
+
+    #[test]
+    fn test_material_network() {
+        // A single hidden neuron counts a player's own material
+        // in quarters of a pawn, so it stays under the clipping limit
+        let mut network = Network {
+            hidden: 1,
+            feature_weights: vec![0; INPUTS],
+            feature_biases: vec![0],
+            output_weights: vec![QB as i16, -QB as i16],
+            output_bias: 0,
+        };
+        for (index, value) in [4, 12, 12, 20, 36].iter().enumerate() {
+            for square in 0..64 {
+                network.feature_weights[64 * index + square] = *value;
+            }
+        }
+
+        // White has 24 quarters of a pawn to Black's 4, which the output
+        // scales to 20 * 400 / 255 centipawns
+        let board = parse_fen("4k3/3p4/8/8/8/8/3P4/3RK3 w - - 0 1").unwrap();
+        assert_eq!(network.evaluate(&board, WHITE), 3.1);
+        assert_eq!(network.evaluate(&board, BLACK), -3.1);
+
+        // The largest outputs of a large hidden layer do not overflow
+        let network = Network {
+            hidden: 512,
+            feature_weights: vec![0; INPUTS * 512],
+            feature_biases: vec![QA as i16; 512],
+            output_weights: [vec![i16::MAX; 512], vec![0; 512]].concat(),
+            output_bias: 0,
+        };
+        let value = 512 * i16::MAX as i64 * SCALE as i64 / QB as i64;
+        assert_eq!(network.evaluate(&board, WHITE), value as f64 / 10.0);
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let network = random_network(16);
+        for fen in &[
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            "8/8/8/3k4/1pP5/8/8/4K3 b - c3 0 1",
+        ] {
+            let board = parse_fen(fen).unwrap();
+            let atomic = BoardBuilder::from(board)
+                .set_variant(Variant::Atomic)
+                .build();
+            for board in &[board, atomic] {
+                let evaluator = NnueEvaluator::new(*board, &network);
+                for m in evaluator.get_legal_moves() {
+                    let next = evaluator.apply_eval_move(m);
+                    for reply in next.get_legal_moves() {
+                        let updated = next.apply_eval_move(reply);
+                        let refreshed = NnueEvaluator::new(updated.get_board(), &network);
+                        assert_eq!(
+                            updated.accumulator, refreshed.accumulator,
+                            "{} {}",
+                            m, reply
+                        );
+                        assert_eq!(updated.value_for(WHITE), refreshed.value_for(WHITE));
+                    }
+                }
+            }
+        }
+
+        // The network never favors either color
+        let board =
+            parse_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+        assert_eq!(
+            network.evaluate(&board, WHITE),
+            network.evaluate(&board.flip_colors(), BLACK)
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use super::{Board, Color, EvalParams, Position, Score, DEFAULT_EVAL_PARAMS};
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+/// Random keys for every pawn on every square, used to hash the pawn structure.
+const PAWN_KEYS: [[u64; 64]; 2] = generate_pawn_keys();
+
+/// Generate the pawn keys with a xorshift generator at compile time.
+const fn generate_pawn_keys() -> [[u64; 64]; 2] {
+    let mut keys = [[0; 64]; 2];
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut color = 0;
+    while color < 2 {
+        let mut square = 0;
+        while square < 64 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            keys[color][square] = state;
+            square += 1;
+        }
+        color += 1;
+    }
+    keys
+}
+
+#[inline]
+fn get_color_index(color: Color) -> usize {
+    match color {
+        Color::White => 0,
+        Color::Black => 1,
+    }
+}
+
+#[inline]
+fn get_square_index(pos: Position) -> usize {
+    (pos.get_row() * 8 + pos.get_col()) as usize
+}
+
+/// Get the hash of the placement of the pawns on a board.
+///
+/// Every other piece is ignored, so boards with the same pawns share a key.
+pub fn get_pawn_key(board: &Board) -> u64 {
+    let mut key = 0;
+    for row in 0..8 {
+        for col in 0..8 {
+            let pos = Position::new(row, col);
+            if let Some(piece) = board.get_piece(pos) {
+                if piece.is_pawn() {
+                    key ^= PAWN_KEYS[get_color_index(piece.get_color())][get_square_index(pos)];
+                }
+            }
+        }
+    }
+    key
+}
+
+/// The evaluation of the pawn structure of a board.
+///
+/// Everything in here only depends on where the pawns are, so it can be
+/// cached in a `PawnHashTable` and reused for every board with the same pawns.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PawnEntry {
+    key: u64,
+    scores: [Score; 2],
+    passed: [u64; 2],
+}
+
+impl PawnEntry {
+    /// Analyse the pawn structure of a board.
+    pub fn new(board: &Board, params: &EvalParams) -> Self {
+        let mut pawns = [[[false; 8]; 8]; 2];
+        for row in 0..8 {
+            for col in 0..8 {
+                if let Some(piece) = board.get_piece(Position::new(row, col)) {
+                    if piece.is_pawn() {
+                        pawns[get_color_index(piece.get_color())][row as usize][col as usize] =
+                            true;
+                    }
+                }
+            }
+        }
+
+        let mut result = Self {
+            key: get_pawn_key(board),
+            ..Self::default()
+        };
+        for color in &[Color::White, Color::Black] {
+            let structure = PawnStructure {
+                pawns: &pawns,
+                color: *color,
+            };
+
+            let mut score = Score::default();
+            for row in 0..8 {
+                for col in 0..8 {
+                    if !structure.has_pawn(*color, row, col) {
+                        continue;
+                    }
+                    let rank = structure.get_relative_rank(row);
+
+                    if structure.is_passed(row, col) {
+                        result.passed[get_color_index(*color)] |=
+                            1 << get_square_index(Position::new(row, col));
+                    } else if structure.is_candidate_passer(row, col) {
+                        score += params.candidate_passer[rank];
+                    }
+
+                    if structure.is_doubled(row, col) {
+                        score += params.doubled_pawn;
+                    }
+
+                    if structure.is_isolated(col) {
+                        score += params.isolated_pawn;
+                    } else if structure.is_backward(row, col) {
+                        score += params.backward_pawn;
+                    }
+
+                    if structure.is_supported(row, col) {
+                        score += params.pawn_chain;
+                    }
+                }
+            }
+
+            result.scores[get_color_index(*color)] = score;
+        }
+
+        result
+    }
+
+    /// Get the hash of the pawns this entry was created for.
+    #[inline]
+    pub fn get_key(&self) -> u64 {
+        self.key
+    }
+
+    /// Get the score of a player's pawn structure.
+    ///
+    /// This does not include the bonus for passed pawns, which
+    /// depends on whether their path is blocked by other pieces.
+    #[inline]
+    pub fn get_score(&self, color: Color) -> Score {
+        self.scores[get_color_index(color)]
+    }
+
+    /// Is the pawn on a given square passed?
+    #[inline]
+    pub fn is_passed(&self, pos: Position) -> bool {
+        [Color::White, Color::Black]
+            .iter()
+            .any(|color| self.passed[get_color_index(*color)] & (1 << get_square_index(pos)) != 0)
+    }
+
+    /// Get the positions of a player's passed pawns.
+    pub fn get_passed_pawns(&self, color: Color) -> Vec<Position> {
+        let passed = self.passed[get_color_index(color)];
+        (0..64)
+            .filter(|square| passed & (1 << square) != 0)
+            .map(|square| Position::new(square / 8, square % 8))
+            .collect()
+    }
+}
+
+/// The pawns of both players, seen from one player's side.
+struct PawnStructure<'a> {
+    pawns: &'a [[[bool; 8]; 8]; 2],
+    color: Color,
+}
+
+impl PawnStructure<'_> {
+    fn has_pawn(&self, color: Color, row: i32, col: i32) -> bool {
+        (0..8).contains(&row)
+            && (0..8).contains(&col)
+            && self.pawns[get_color_index(color)][row as usize][col as usize]
+    }
+
+    /// Get the rank of a row counted from the player's own side, starting at zero.
+    fn get_relative_rank(&self, row: i32) -> usize {
+        match self.color {
+            Color::White => row as usize,
+            Color::Black => 7 - row as usize,
+        }
+    }
+
+    /// Get the rows in front of a given row, from the player's point of view.
+    fn rows_ahead(&self, row: i32) -> impl Iterator<Item = i32> {
+        match self.color {
+            Color::White => (row + 1)..8,
+            Color::Black => 0..row,
+        }
+    }
+
+    /// Get the rows level with or behind a given row, from the player's point of view.
+    fn rows_behind(&self, row: i32) -> impl Iterator<Item = i32> {
+        match self.color {
+            Color::White => 0..(row + 1),
+            Color::Black => row..8,
+        }
+    }
+
+    fn forward(&self) -> i32 {
+        match self.color {
+            Color::White => 1,
+            Color::Black => -1,
+        }
+    }
+
+    /// Count the pawns of a given color on a set of files in a set of rows.
+    fn count(&self, color: Color, rows: impl Iterator<Item = i32>, cols: &[i32]) -> usize {
+        rows.map(|row| {
+            cols.iter()
+                .filter(|col| self.has_pawn(color, row, **col))
+                .count()
+        })
+        .sum()
+    }
+
+    /// Can no enemy pawn stop or capture this pawn on its way to promotion?
+    fn is_passed(&self, row: i32, col: i32) -> bool {
+        self.count(!self.color, self.rows_ahead(row), &[col - 1, col, col + 1]) == 0
+    }
+
+    /// Does this pawn have a free path ahead, with at least as many friendly
+    /// pawns beside it as there are enemy pawns guarding its path?
+    fn is_candidate_passer(&self, row: i32, col: i32) -> bool {
+        let sides = [col - 1, col + 1];
+        self.count(!self.color, self.rows_ahead(row), &[col]) == 0
+            && self.count(self.color, self.rows_behind(row), &sides)
+                >= self.count(!self.color, self.rows_ahead(row), &sides)
+    }
+
+    /// Is there another friendly pawn in front of this one on the same file?
+    fn is_doubled(&self, row: i32, col: i32) -> bool {
+        self.count(self.color, self.rows_ahead(row), &[col]) > 0
+    }
+
+    /// Are there no friendly pawns on the neighboring files?
+    fn is_isolated(&self, col: i32) -> bool {
+        self.count(self.color, 0..8, &[col - 1, col + 1]) == 0
+    }
+
+    /// Has this pawn been left behind by the pawns on the neighboring files,
+    /// with the square in front of it controlled by an enemy pawn?
+    fn is_backward(&self, row: i32, col: i32) -> bool {
+        let stop = row + self.forward();
+        self.count(self.color, self.rows_behind(row), &[col - 1, col + 1]) == 0
+            && (self.has_pawn(!self.color, stop + self.forward(), col - 1)
+                || self.has_pawn(!self.color, stop + self.forward(), col + 1))
+    }
+
+    /// Is this pawn defended by another pawn?
+    fn is_supported(&self, row: i32, col: i32) -> bool {
+        let behind = row - self.forward();
+        self.has_pawn(self.color, behind, col - 1) || self.has_pawn(self.color, behind, col + 1)
+    }
+}
+
+/// A cache of evaluated pawn structures.
+///
+/// Pawns move rarely compared to the other pieces, so most of the boards
+/// in a search share their pawn structure with many others. A table should
+/// only be used with one set of evaluation parameters at a time.
+pub struct PawnHashTable {
+    entries: Vec<Cell<PawnEntry>>,
+}
+
+impl Default for PawnHashTable {
+    fn default() -> Self {
+        Self::new(1 << 14)
+    }
+}
+
+impl PawnHashTable {
+    /// Create a table with room for a given number of pawn structures.
+    pub fn new(size: usize) -> Self {
+        Self {
+            entries: (0..size.max(1))
+                .map(|_| Cell::new(PawnEntry::default()))
+                .collect(),
+        }
+    }
+
+    /// Get the evaluation of the pawn structure of a board, analysing
+    /// it and storing it in the table if it has not been seen before.
+    pub fn probe(&self, board: &Board, params: &EvalParams) -> PawnEntry {
+        let key = get_pawn_key(board);
+        let slot = &self.entries[(key % self.entries.len() as u64) as usize];
+
+        // An empty slot has the key of a board without pawns,
+        // which correctly has no pawn structure to speak of.
+        let entry = slot.get();
+        if entry.key == key {
+            return entry;
+        }
+
+        let entry = PawnEntry::new(board, params);
+        slot.set(entry);
+        entry
+    }
+
+    /// Remove every pawn structure from the table.
+    pub fn clear(&self) {
+        for entry in &self.entries {
+            entry.set(PawnEntry::default());
+        }
+    }
+}
+
+/// Get the evaluation of the pawn structure of a board with
+/// the default parameters, as used by `Board::value_for`.
+///
+/// With the standard library, every thread keeps its own `PawnHashTable`
+/// for this. Without it there is nowhere to keep a cache, so the pawns are
+/// analysed from scratch; wrap the board in an `Evaluator` to cache them.
+pub(crate) fn get_default_pawn_entry(board: &Board) -> PawnEntry {
+    #[cfg(feature = "std")]
+    {
+        std::thread_local! {
+            static PAWNS: PawnHashTable = PawnHashTable::default();
+        }
+        PAWNS.with(|pawns| pawns.probe(board, &DEFAULT_EVAL_PARAMS))
+    }
+
+    #[cfg(not(feature = "std"))]
+    PawnEntry::new(board, &DEFAULT_EVAL_PARAMS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::*;
+    use crate::util::parse_fen;
+    use crate::{EvalTerm, Evaluate, Evaluator, BLACK, DEFAULT_EVAL_PARAMS, WHITE};
+
+    fn analyse(fen: &str) -> PawnEntry {
+        PawnEntry::new(&parse_fen(fen).unwrap(), &DEFAULT_EVAL_PARAMS)
+    }
+
+    #[test]
+    fn test_pawn_structure() {
+        let params = DEFAULT_EVAL_PARAMS;
+        assert_eq!(
+            analyse("4k3/8/8/8/8/8/8/4K3 w - - 0 1"),
+            PawnEntry::default()
+        );
+
+        // The pawns on the a-file are doubled and isolated, and every pawn is passed
+        let entry = analyse("4k3/8/8/4P3/8/P7/P7/4K3 w - - 0 1");
+        assert_eq!(entry.get_passed_pawns(WHITE), vec![A2, A3, E5]);
+        assert!(entry.is_passed(E5));
+        assert_eq!(
+            entry.get_score(WHITE),
+            params.doubled_pawn + params.isolated_pawn * 3.0
+        );
+        assert_eq!(entry.get_score(BLACK), Score::default());
+
+        // The pawns on c4 and c7 are backward, and support the pawns in front of them
+        let entry = analyse("4k3/2p5/3p4/3P4/2P5/8/8/4K3 w - - 0 1");
+        assert!(entry.get_passed_pawns(WHITE).is_empty());
+        assert!(entry.get_passed_pawns(BLACK).is_empty());
+        assert_eq!(
+            entry.get_score(WHITE),
+            params.backward_pawn + params.pawn_chain
+        );
+        assert_eq!(entry.get_score(BLACK), entry.get_score(WHITE));
+
+        // Two pawns against one on the queenside make a candidate passer
+        let entry = analyse("4k3/p7/8/8/1P6/P7/8/4K3 w - - 0 1");
+        assert!(entry.get_passed_pawns(WHITE).is_empty());
+        assert_eq!(
+            entry.get_score(WHITE),
+            params.candidate_passer[3] + params.pawn_chain
+        );
+        assert_eq!(entry.get_score(BLACK), params.isolated_pawn);
+    }
+
+    #[test]
+    fn test_passed_pawn() {
+        let params = DEFAULT_EVAL_PARAMS;
+        let free = parse_fen("4k3/8/8/8/2P5/8/8/4K3 w - - 0 1").unwrap();
+        let blocked = parse_fen("4k3/8/8/2n5/2P5/8/8/4K3 w - - 0 1").unwrap();
+        let stopped = parse_fen("4k3/8/2p5/2n5/2P5/8/8/4K3 w - - 0 1").unwrap();
+        let score = |board: &Board| {
+            let pawns = PawnEntry::new(board, &params);
+            params.get_term_score(board, &pawns, EvalTerm::Pawns, WHITE)
+        };
+        assert_eq!(score(&free), params.passed_pawn[3] + params.isolated_pawn);
+        assert_eq!(
+            score(&blocked),
+            params.blocked_passed_pawn[3] + params.isolated_pawn
+        );
+        assert_eq!(score(&stopped), params.isolated_pawn);
+
+        // The table gives the same evaluation as analysing the board every time
+        let table = PawnHashTable::new(16);
+        for board in &[free, blocked, stopped, free] {
+            let evaluator = Evaluator::new(*board, &params, &table);
+            assert_eq!(evaluator.value_for(WHITE), board.value_for(WHITE));
+            assert_eq!(table.probe(board, &params), PawnEntry::new(board, &params));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Board, Color, Move, Position};
+use super::{Board, Color, Move, Position, DEFAULT_EVAL_PARAMS};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
@@ -22,136 +22,11 @@ pub enum Piece {
     Pawn(Color, Position),
 }
 
-const WHITE_KING_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-2.0, -3.0, -3.0, -4.0, -4.0, -3.0, -3.0, -2.0],
-    [-1.0, -2.0, -2.0, -2.0, -2.0, -2.0, -2.0, -1.0],
-    [2.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 2.0],
-    [2.0, 3.0, 1.0, 0.0, 0.0, 1.0, 3.0, 2.0],
-];
-
-const BLACK_KING_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [2.0, 3.0, 1.0, 0.0, 0.0, 1.0, 3.0, 2.0],
-    [2.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 2.0],
-    [-1.0, -2.0, -2.0, -2.0, -2.0, -2.0, -2.0, -1.0],
-    [-2.0, -3.0, -3.0, -4.0, -4.0, -3.0, -3.0, -2.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-    [-3.0, -4.0, -4.0, -5.0, -5.0, -4.0, -4.0, -3.0],
-];
-
-const WHITE_QUEEN_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-2.0, -1.0, -1.0, -0.5, -0.5, -1.0, -1.0, -2.0],
-    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
-    [-1.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
-    [-0.5, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -0.5],
-    [0.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -0.5],
-    [-1.0, 0.5, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
-    [-1.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, -1.0],
-    [-1.0, -0.0, -1.0, -0.5, -0.5, -0.5, -1.0, -2.0],
-];
-const BLACK_QUEEN_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-1.0, -0.0, -1.0, -0.5, -0.5, -0.5, -1.0, -2.0],
-    [-1.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, -1.0],
-    [-1.0, 0.5, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
-    [0.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -0.5],
-    [-0.5, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -0.5],
-    [-1.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.0, -1.0],
-    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
-    [-2.0, -1.0, -1.0, -0.5, -0.5, -1.0, -1.0, -2.0],
-];
-
-const WHITE_ROOK_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
-    [0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [0.0, 0.0, 0.0, 0.5, 0.5, 0.0, 0.0, 0.0],
-];
-
-const BLACK_ROOK_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [0.0, 0.0, 0.0, 0.5, 0.5, 0.0, 0.0, 0.0],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [-0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -0.5],
-    [0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5],
-    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
-];
-
-const WHITE_BISHOP_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
-    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
-    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
-    [-1.0, 0.5, 0.5, 1.0, 1.0, 0.5, 0.5, -1.0],
-    [-1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, -1.0],
-    [-1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
-    [-1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.5, -1.0],
-    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
-];
-
-const BLACK_BISHOP_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
-    [-1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.5, -1.0],
-    [-1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
-    [-1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, -1.0],
-    [-1.0, 0.5, 0.5, 1.0, 1.0, 0.5, 0.5, -1.0],
-    [-1.0, 0.0, 0.5, 1.0, 1.0, 0.5, 0.0, -1.0],
-    [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0],
-    [-2.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -2.0],
-];
-
-const WHITE_KNIGHT_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
-    [-4.0, -2.0, 0.0, 0.0, 0.0, 0.0, -2.0, -4.0],
-    [-3.0, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -3.0],
-    [-3.0, 0.5, 1.5, 2.0, 2.0, 1.5, 0.5, -3.0],
-    [-3.0, 0.0, 1.5, 2.0, 2.0, 1.5, 0.0, -3.0],
-    [-3.0, 0.5, 1.0, 1.5, 1.5, 1.0, 0.5, -3.0],
-    [-4.0, -2.0, 0.0, 0.5, 0.5, 0.0, -2.0, -4.0],
-    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
-];
-
-const BLACK_KNIGHT_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
-    [-4.0, -2.0, 0.0, 0.5, 0.5, 0.0, -2.0, -4.0],
-    [-3.0, 0.5, 1.0, 1.5, 1.5, 1.0, 0.5, -3.0],
-    [-3.0, 0.0, 1.5, 2.0, 2.0, 1.5, 0.0, -3.0],
-    [-3.0, 0.5, 1.5, 2.0, 2.0, 1.5, 0.5, -3.0],
-    [-3.0, 0.0, 1.0, 1.5, 1.5, 1.0, 0.0, -3.0],
-    [-4.0, -2.0, 0.0, 0.0, 0.0, 0.0, -2.0, -4.0],
-    [-5.0, -4.0, -3.0, -3.0, -3.0, -3.0, -4.0, -5.0],
-];
-
-const WHITE_PAWN_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
-    [5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0],
-    [1.0, 1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 1.0],
-    [0.5, 0.5, 1.0, 2.5, 2.5, 1.0, 0.5, 0.5],
-    [0.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0],
-    [0.5, -0.5, -1.0, 0.0, 0.0, -1.0, -0.5, 0.5],
-    [0.5, 1.5, -1.0, -2.0, -2.0, 1.0, 1.5, 0.5],
-    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
-];
-
-const BLACK_PAWN_POSITION_WEIGHTS: [[f64; 8]; 8] = [
-    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
-    [0.5, 1.5, -1.0, -2.0, -2.0, 1.0, 1.5, 0.5],
-    [0.5, -0.5, -1.0, 0.0, 0.0, -1.0, -0.5, 0.5],
-    [0.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0],
-    [0.5, 0.5, 1.0, 2.5, 2.5, 1.0, 0.5, 0.5],
-    [1.0, 1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 1.0],
-    [5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0],
-    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
-];
+/// The directions that rooks and queens slide in, as row and column steps.
+pub(crate) const ORTHOGONAL_DIRECTIONS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// The directions that bishops and queens slide in, as row and column steps.
+pub(crate) const DIAGONAL_DIRECTIONS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
 
 impl core::fmt::Display for Piece {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
@@ -222,6 +97,10 @@ impl Piece {
     /// | Bishop | 3 |
     /// | Knight | 3 |
     /// | Pawn | 1 |
+    ///
+    /// These are the traditional values used to count material and
+    /// judge exchanges. The evaluation uses `EvalParams::get_material`
+    /// instead, which can be changed at runtime.
     #[inline]
     pub fn get_material_value(&self) -> i32 {
         match self {
@@ -240,38 +119,13 @@ impl Piece {
     /// a king in the center of the board is highly unfavorable compared to
     /// a king its respective side.
     ///
-    /// Additionally, the weighted value of the piece is 10 times greater than
-    /// its material value, plus or minus a weight ranging between 5.0 and -5.0.
+    /// This is the middlegame value of the piece under the default
+    /// evaluation parameters, which is roughly 10 times its material value,
+    /// plus or minus a weight ranging between 5.0 and -5.0. The evaluation
+    /// of a board also accounts for the game phase; see `EvalParams`.
     #[inline]
     pub fn get_weighted_value(&self) -> f64 {
-        let weights = match self {
-            Self::King(c, _) => match c {
-                Color::White => WHITE_KING_POSITION_WEIGHTS,
-                Color::Black => BLACK_KING_POSITION_WEIGHTS,
-            },
-            Self::Queen(c, _) => match c {
-                Color::White => WHITE_QUEEN_POSITION_WEIGHTS,
-                Color::Black => BLACK_QUEEN_POSITION_WEIGHTS,
-            },
-            Self::Rook(c, _) => match c {
-                Color::White => WHITE_ROOK_POSITION_WEIGHTS,
-                Color::Black => BLACK_ROOK_POSITION_WEIGHTS,
-            },
-            Self::Bishop(c, _) => match c {
-                Color::White => WHITE_BISHOP_POSITION_WEIGHTS,
-                Color::Black => BLACK_BISHOP_POSITION_WEIGHTS,
-            },
-            Self::Knight(c, _) => match c {
-                Color::White => WHITE_KNIGHT_POSITION_WEIGHTS,
-                Color::Black => BLACK_KNIGHT_POSITION_WEIGHTS,
-            },
-            Self::Pawn(c, _) => match c {
-                Color::White => WHITE_PAWN_POSITION_WEIGHTS,
-                Color::Black => BLACK_PAWN_POSITION_WEIGHTS,
-            },
-        };
-        weights[(7 - self.get_pos().get_row()) as usize][self.get_pos().get_col() as usize]
-            + (self.get_material_value() * 10) as f64
+        DEFAULT_EVAL_PARAMS.get_piece_value(*self).get_middlegame()
     }
 
     /// Get the color of a given piece.
@@ -406,11 +260,13 @@ impl Piece {
         }
     }
 
-    /// Get the exhaustive list of legal moves for a given piece.
+    /// Get the exhaustive list of pseudo-legal moves for a given piece.
     ///
-    /// This is used for move generation.
+    /// These moves follow the movement rules of the piece, but they may
+    /// leave the player's own king in check. The board is responsible for
+    /// filtering out the moves that are not legal.
     #[inline]
-    pub(crate) fn get_legal_moves(&self, board: &Board) -> Vec<Move> {
+    pub(crate) fn get_pseudo_legal_moves(&self, board: &Board) -> Vec<Move> {
         let mut result = Vec::new();
         match *self {
             Self::Pawn(ally_color, pos) => {
@@ -419,30 +275,53 @@ impl Piece {
                 let up_left = up.next_left();
                 let up_right = up.next_right();
 
+                let mut targets = Vec::new();
                 if let Some(en_passant) = board.get_en_passant() {
                     if en_passant == up_left || en_passant == up_right {
-                        result.push(Move::Piece(pos, en_passant));
+                        targets.push(en_passant);
                     }
                 }
 
                 if next_up.is_on_board()
-                    && self.is_starting_pawn()
+                    && board.get_variant().is_starting_pawn(*self)
                     && board.has_no_piece(up)
                     && board.has_no_piece(next_up)
                 {
-                    result.push(Move::Piece(pos, next_up))
+                    targets.push(next_up)
                 }
 
                 if up.is_on_board() && board.has_no_piece(up) {
-                    result.push(Move::Piece(pos, up))
+                    targets.push(up)
                 }
 
                 if up_left.is_on_board() && board.has_enemy_piece(up_left, ally_color) {
-                    result.push(Move::Piece(pos, up_left))
+                    targets.push(up_left)
                 }
 
                 if up_right.is_on_board() && board.has_enemy_piece(up_right, ally_color) {
-                    result.push(Move::Piece(pos, up_right))
+                    targets.push(up_right)
+                }
+
+                for to in targets {
+                    if to.get_row() == 0 || to.get_row() == 7 {
+                        // every possible promotion is a separate move
+                        let offboard = Position::new(-1, -1);
+                        let mut promotions = vec![
+                            Self::Queen(ally_color, offboard),
+                            Self::Rook(ally_color, offboard),
+                            Self::Bishop(ally_color, offboard),
+                            Self::Knight(ally_color, offboard),
+                        ];
+                        if board.get_variant().allows_king_promotion() {
+                            promotions.push(Self::King(ally_color, offboard));
+                        }
+
+                        for promotion in promotions {
+                            result.push(Move::Promotion(pos, to, promotion))
+                        }
+                    } else {
+                        result.push(Move::Piece(pos, to))
+                    }
                 }
             }
 
@@ -463,77 +342,23 @@ impl Piece {
                 }
                 if board.can_kingside_castle(ally_color) {
                     result.push(Move::KingSideCastle);
-                } else if board.can_queenside_castle(ally_color) {
+                }
+                if board.can_queenside_castle(ally_color) {
                     result.push(Move::QueenSideCastle);
                 }
             }
 
-            Self::Queen(ally_color, pos) => {
-                for row in 0..8 {
-                    let new_pos = Position::new(row, pos.get_col());
-                    if new_pos != pos
-                        && !board.has_ally_piece(new_pos, ally_color)
-                        && new_pos.is_orthogonal_to(pos)
-                    {
-                        result.push(Move::Piece(pos, new_pos));
-                    }
-                }
-                for col in 0..8 {
-                    let new_pos = Position::new(pos.get_row(), col);
-                    if new_pos != pos
-                        && !board.has_ally_piece(new_pos, ally_color)
-                        && new_pos.is_orthogonal_to(pos)
-                    {
-                        result.push(Move::Piece(pos, new_pos));
-                    }
-                }
-
-                for row in 0..8 {
-                    for col in 0..8 {
-                        let new_pos = Position::new(row, col);
-                        if new_pos != pos
-                            && !board.has_ally_piece(new_pos, ally_color)
-                            && new_pos.is_diagonal_to(pos)
-                        {
-                            result.push(Move::Piece(pos, new_pos));
-                        }
-                    }
-                }
+            Self::Queen(_, _) => {
+                result.extend(self.get_sliding_moves(board, &ORTHOGONAL_DIRECTIONS));
+                result.extend(self.get_sliding_moves(board, &DIAGONAL_DIRECTIONS));
             }
 
-            Self::Rook(ally_color, pos) => {
-                for row in 0..8 {
-                    let new_pos = Position::new(row, pos.get_col());
-                    if new_pos != pos
-                        && !board.has_ally_piece(new_pos, ally_color)
-                        && new_pos.is_orthogonal_to(pos)
-                    {
-                        result.push(Move::Piece(pos, new_pos));
-                    }
-                }
-                for col in 0..8 {
-                    let new_pos = Position::new(pos.get_row(), col);
-                    if new_pos != pos
-                        && !board.has_ally_piece(new_pos, ally_color)
-                        && new_pos.is_orthogonal_to(pos)
-                    {
-                        result.push(Move::Piece(pos, new_pos));
-                    }
-                }
+            Self::Rook(_, _) => {
+                result.extend(self.get_sliding_moves(board, &ORTHOGONAL_DIRECTIONS))
             }
 
-            Self::Bishop(ally_color, pos) => {
-                for row in 0..8 {
-                    for col in 0..8 {
-                        let new_pos = Position::new(row, col);
-                        if new_pos != pos
-                            && !board.has_ally_piece(new_pos, ally_color)
-                            && new_pos.is_diagonal_to(pos)
-                        {
-                            result.push(Move::Piece(pos, new_pos));
-                        }
-                    }
-                }
+            Self::Bishop(_, _) => {
+                result.extend(self.get_sliding_moves(board, &DIAGONAL_DIRECTIONS))
             }
 
             Self::Knight(ally_color, pos) => {
@@ -554,20 +379,27 @@ impl Piece {
             }
         }
 
-        let color = self.get_color();
         result
-            .into_iter()
-            .filter(|x| match x {
-                Move::Piece(from, to) => {
-                    if from.is_on_board() && to.is_on_board() {
-                        board.is_legal_move(*x, color)
-                    } else {
-                        false
-                    }
+    }
+
+    /// Get the moves of a sliding piece along the given directions.
+    ///
+    /// Each ray stops at the first piece in the way, which
+    /// is included in the moves if it can be captured.
+    fn get_sliding_moves(&self, board: &Board, directions: &[(i32, i32)]) -> Vec<Move> {
+        let pos = self.get_pos();
+        let mut result = Vec::new();
+        for (row_step, col_step) in directions {
+            let mut to = Position::new(pos.get_row() + row_step, pos.get_col() + col_step);
+            while to.is_on_board() && !board.has_ally_piece(to, self.get_color()) {
+                result.push(Move::Piece(pos, to));
+                if board.has_piece(to) {
+                    break;
                 }
-                _ => board.is_legal_move(*x, color),
-            })
-            .collect::<Vec<Move>>()
+                to = Position::new(to.get_row() + row_step, to.get_col() + col_step);
+            }
+        }
+        result
     }
 
     /// Verify that moving to a new position is a legal move.
@@ -587,7 +419,7 @@ impl Piece {
                     (en_passant == up_left || en_passant == up_right) && (new_pos == en_passant)
                 } else {
                     false
-                }) || (self.is_starting_pawn()
+                }) || (board.get_variant().is_starting_pawn(*self)
                     && board.has_no_piece(new_pos)
                     && board.has_no_piece(up)
                     && new_pos == up.pawn_up(ally_color))
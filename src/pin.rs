@@ -0,0 +1,52 @@
+use super::{Piece, Position};
+use alloc::vec::Vec;
+
+/// A piece that stands on a line between a sliding piece and a king.
+///
+/// When the king and the piece in between belong to the same player,
+/// the piece is absolutely pinned: it can only move along the ray
+/// without exposing its king to the pinning piece.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pin {
+    pinned: Piece,
+    pinner: Piece,
+    ray: Vec<Position>,
+}
+
+impl Pin {
+    pub(crate) fn new(pinned: Piece, pinner: Piece, ray: Vec<Position>) -> Self {
+        Self {
+            pinned,
+            pinner,
+            ray,
+        }
+    }
+
+    /// Get the piece that stands between the king and the sliding piece.
+    #[inline]
+    pub fn get_pinned(&self) -> Piece {
+        self.pinned
+    }
+
+    /// Get the sliding piece that pins the pinned piece.
+    #[inline]
+    pub fn get_pinner(&self) -> Piece {
+        self.pinner
+    }
+
+    /// Get the positions on the line from the king to the pinning piece.
+    ///
+    /// This does _not_ include the king's position, and includes the
+    /// pinning piece's position. The pinned piece can only move to
+    /// these positions.
+    #[inline]
+    pub fn get_ray(&self) -> &[Position] {
+        &self.ray
+    }
+
+    /// Can the pinned piece move to a given position without leaving the ray?
+    #[inline]
+    pub fn allows(&self, pos: Position) -> bool {
+        self.ray.contains(&pos)
+    }
+}
@@ -0,0 +1,93 @@
+use super::{Color, Piece, Position};
+use alloc::vec::Vec;
+
+/// The pieces a player has captured in Crazyhouse.
+///
+/// Captured pieces change color and go into the capturing player's
+/// pocket, from where they can be dropped back onto the board as the
+/// player's own pieces. Kings can never be held in a pocket, and promoted
+/// pieces revert to pawns when they are captured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pocket {
+    queens: u8,
+    rooks: u8,
+    bishops: u8,
+    knights: u8,
+    pawns: u8,
+}
+
+impl Pocket {
+    /// Get the number of pieces of the same type as a given piece in the pocket.
+    #[inline]
+    pub fn count(&self, piece: Piece) -> u8 {
+        match piece {
+            Piece::King(_, _) => 0,
+            Piece::Queen(_, _) => self.queens,
+            Piece::Rook(_, _) => self.rooks,
+            Piece::Bishop(_, _) => self.bishops,
+            Piece::Knight(_, _) => self.knights,
+            Piece::Pawn(_, _) => self.pawns,
+        }
+    }
+
+    /// Does this pocket hold no pieces at all?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queens == 0
+            && self.rooks == 0
+            && self.bishops == 0
+            && self.knights == 0
+            && self.pawns == 0
+    }
+
+    /// Get one piece of every type held in the pocket for a given color.
+    ///
+    /// The pieces are not on the board, so their positions are off of the board.
+    pub fn get_pieces(&self, color: Color) -> Vec<Piece> {
+        let offboard = Position::new(-1, -1);
+        [
+            Piece::Queen(color, offboard),
+            Piece::Rook(color, offboard),
+            Piece::Bishop(color, offboard),
+            Piece::Knight(color, offboard),
+            Piece::Pawn(color, offboard),
+        ]
+        .iter()
+        .filter(|piece| self.count(**piece) > 0)
+        .copied()
+        .collect()
+    }
+
+    /// Get the total material value of the pieces in the pocket.
+    pub fn get_material_value(&self) -> i32 {
+        self.get_pieces(Color::White)
+            .iter()
+            .map(|piece| piece.get_material_value() * self.count(*piece) as i32)
+            .sum()
+    }
+
+    /// Put a piece into the pocket. Kings are ignored.
+    pub(crate) fn add(&mut self, piece: Piece) {
+        match piece {
+            Piece::King(_, _) => {}
+            Piece::Queen(_, _) => self.queens += 1,
+            Piece::Rook(_, _) => self.rooks += 1,
+            Piece::Bishop(_, _) => self.bishops += 1,
+            Piece::Knight(_, _) => self.knights += 1,
+            Piece::Pawn(_, _) => self.pawns += 1,
+        }
+    }
+
+    /// Take a piece out of the pocket, if the pocket holds one.
+    pub(crate) fn remove(&mut self, piece: Piece) {
+        let count = match piece {
+            Piece::King(_, _) => return,
+            Piece::Queen(_, _) => &mut self.queens,
+            Piece::Rook(_, _) => &mut self.rooks,
+            Piece::Bishop(_, _) => &mut self.bishops,
+            Piece::Knight(_, _) => &mut self.knights,
+            Piece::Pawn(_, _) => &mut self.pawns,
+        };
+        *count = count.saturating_sub(1);
+    }
+}
@@ -0,0 +1,467 @@
+use super::{Color, Evaluate, Move};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// The number of boards searched between every check of whether
+/// a search should stop.
+const BOARDS_PER_POLL: u64 = 256;
+
+/// The states of a `Ponder`.
+const PONDERING: u8 = 0;
+const PONDER_HIT: u8 = 1;
+const PONDER_MISS: u8 = 2;
+
+/// One of the best lines of play found by a search.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct PrincipalVariation {
+    moves: Vec<Move>,
+    value: f64,
+}
+
+impl PrincipalVariation {
+    /// Get the moves of this line, starting with the move
+    /// for the current player.
+    #[inline]
+    pub fn get_moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Get the first move of this line.
+    #[inline]
+    pub fn get_move(&self) -> Move {
+        self.moves[0]
+    }
+
+    /// Get the rating of this line for the current player.
+    #[inline]
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the reply the opponent is expected to make to the first
+    /// move of this line, if the line goes that far.
+    #[inline]
+    pub fn get_ponder_move(&self) -> Option<Move> {
+        self.moves.get(1).copied()
+    }
+}
+
+/// How far a search has come, which is reported every time
+/// it finishes searching another depth.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct SearchProgress {
+    depth: i32,
+    board_count: u64,
+    line: PrincipalVariation,
+    margin: Option<f64>,
+}
+
+impl SearchProgress {
+    /// Get the depth that was finished, counted like the `depth`
+    /// given to `get_best_next_move`.
+    #[inline]
+    pub fn get_depth(&self) -> i32 {
+        self.depth
+    }
+
+    /// Get the number of boards evaluated so far.
+    #[inline]
+    pub fn get_board_count(&self) -> u64 {
+        self.board_count
+    }
+
+    /// Get the best line of play found at this depth.
+    #[inline]
+    pub fn get_line(&self) -> &PrincipalVariation {
+        &self.line
+    }
+
+    /// Get how much better the best move is than any other move, at
+    /// least, or `None` if the current player has only one legal move.
+    #[inline]
+    pub fn get_margin(&self) -> Option<f64> {
+        self.margin
+    }
+}
+
+/// Watches over a search, to stop it early and to follow its progress.
+///
+/// This works without the standard library, so a stop button can be
+/// an `AtomicBool` set from another thread, or a check of the time.
+pub trait SearchMonitor {
+    /// Get ready for the search, which is about to start. When pondering,
+    /// this is called after the ponderhit instead.
+    fn start(&mut self) {}
+
+    /// Should the search stop as soon as possible?
+    ///
+    /// This is checked every few hundred boards. After stopping, the
+    /// search plays the best move of the deepest depth it finished.
+    fn should_stop(&mut self) -> bool {
+        false
+    }
+
+    /// Follow the progress of the search, every time it
+    /// finishes searching another depth.
+    fn report(&mut self, _progress: &SearchProgress) {}
+}
+
+/// A monitor that never stops the search.
+impl SearchMonitor for () {}
+
+/// A monitor that stops the search once the flag is set.
+impl SearchMonitor for &AtomicBool {
+    fn should_stop(&mut self) -> bool {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+/// Tells a pondering search what the opponent did, usually
+/// from another thread.
+///
+/// While the opponent is thinking, `Evaluate::ponder` searches the
+/// position after the reply they are expected to make.
+#[derive(Debug, Default)]
+pub struct Ponder {
+    state: AtomicU8,
+}
+
+impl Ponder {
+    /// Create a ponder for a search that is about to start.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The opponent made the expected reply, so the pondering search
+    /// carries on as a normal search, and its monitor can stop it.
+    pub fn ponderhit(&self) {
+        let _ = self.state.compare_exchange(
+            PONDERING,
+            PONDER_HIT,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// The opponent made another move, so the pondering search
+    /// stops as soon as possible and its result is thrown away.
+    pub fn miss(&self) {
+        let _ = self.state.compare_exchange(
+            PONDERING,
+            PONDER_MISS,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Is the opponent still thinking?
+    #[inline]
+    pub fn is_pondering(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == PONDERING
+    }
+
+    /// Did the opponent make the expected reply?
+    #[inline]
+    pub fn is_hit(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == PONDER_HIT
+    }
+}
+
+/// A monitor for a pondering search, which only lets the real monitor
+/// stop the search after the ponderhit.
+struct PonderMonitor<'a, M> {
+    ponder: &'a Ponder,
+    monitor: &'a mut M,
+    is_started: bool,
+}
+
+impl<M: SearchMonitor> SearchMonitor for PonderMonitor<'_, M> {
+    fn should_stop(&mut self) -> bool {
+        match self.ponder.state.load(Ordering::Relaxed) {
+            PONDERING => false,
+            PONDER_HIT => {
+                if !self.is_started {
+                    self.is_started = true;
+                    self.monitor.start();
+                }
+                self.monitor.should_stop()
+            }
+            _ => true,
+        }
+    }
+
+    fn report(&mut self, progress: &SearchProgress) {
+        self.monitor.report(progress)
+    }
+}
+
+/// Search a position that is expected to be reached while the opponent
+/// is thinking, until `ponder` says what they did.
+///
+/// This returns `None` if the opponent made another move.
+pub(crate) fn ponder<E: Evaluate, M: SearchMonitor>(
+    board: &E,
+    depth: i32,
+    ponder: &Ponder,
+    monitor: &mut M,
+) -> Option<(Move, u64, f64)> {
+    let mut monitor = PonderMonitor {
+        ponder,
+        monitor,
+        is_started: false,
+    };
+    let result = get_best_next_move_with(board, depth, &mut monitor);
+    if ponder.state.load(Ordering::Relaxed) == PONDER_MISS {
+        None
+    } else {
+        Some(result)
+    }
+}
+
+/// Search for the `count` best moves for the current player, along
+/// with the line of play that follows each of them.
+///
+/// Every line is found with its own alpha-beta search over the moves
+/// that are not already the first move of a better line.
+pub(crate) fn get_principal_variations<E: Evaluate>(
+    board: &E,
+    depth: i32,
+    count: usize,
+    board_count: &mut u64,
+) -> Vec<PrincipalVariation> {
+    let mut monitor = ();
+    let mut searcher = Searcher::new(board, &mut monitor);
+    let mut legal_moves = board.get_legal_moves();
+    let mut lines = Vec::new();
+
+    while lines.len() < count && !legal_moves.is_empty() {
+        match searcher.search_root(board, depth, &legal_moves) {
+            Some((line, _)) => {
+                legal_moves.retain(|m| *m != line.get_move());
+                lines.push(line);
+            }
+            None => break,
+        }
+    }
+
+    *board_count += searcher.board_count;
+    lines
+}
+
+/// Search for the best move with a minimax search of every legal move.
+///
+/// This is the search behind `Evaluate::get_best_next_move`, for
+/// boards that replace that method but still fall back on it.
+pub(crate) fn get_best_next_move<E: Evaluate>(board: &E, depth: i32) -> (Move, u64, f64) {
+    let legal_moves = board.get_legal_moves();
+    let mut best_move_value = -999999.0;
+    let mut best_move = Move::Resign;
+
+    let color = board.get_current_player_color();
+
+    let mut board_count = 0;
+    for m in &legal_moves {
+        let child_board_value = board.apply_eval_move(*m).minimax(
+            depth,
+            -1000000.0,
+            1000000.0,
+            false,
+            color,
+            &mut board_count,
+        );
+        if child_board_value >= best_move_value {
+            best_move = *m;
+            best_move_value = child_board_value;
+        }
+    }
+
+    (best_move, board_count, best_move_value)
+}
+
+/// Search for the best move one depth at a time, up to `depth`, while
+/// reporting to `monitor` and stopping when it says so.
+///
+/// The first depth is always finished, so there is always a move to play.
+pub(crate) fn get_best_next_move_with<E: Evaluate, M: SearchMonitor>(
+    board: &E,
+    depth: i32,
+    monitor: &mut M,
+) -> (Move, u64, f64) {
+    let mut legal_moves = board.get_legal_moves();
+    if legal_moves.is_empty() {
+        return (Move::Resign, 0, -999999.0);
+    }
+
+    monitor.start();
+    let mut searcher = Searcher::new(board, monitor);
+    let mut best: Option<PrincipalVariation> = None;
+    for current_depth in 0..=depth {
+        let (line, second_best_value) =
+            match searcher.search_root(board, current_depth, &legal_moves) {
+                Some(result) => result,
+                None => break,
+            };
+
+        // Try the best move first at the next depth, to prune more
+        legal_moves.retain(|m| *m != line.get_move());
+        legal_moves.insert(0, line.get_move());
+
+        searcher.monitor.report(&SearchProgress {
+            depth: current_depth,
+            board_count: searcher.board_count,
+            line: line.clone(),
+            margin: second_best_value.map(|value| line.get_value() - value),
+        });
+        best = Some(line);
+        searcher.can_stop = true;
+    }
+
+    let best = best.expect("the first depth is never stopped");
+    (best.get_move(), searcher.board_count, best.get_value())
+}
+
+/// The state of a search for principal variations.
+struct Searcher<'a, M> {
+    monitor: &'a mut M,
+    getting_move_for: Color,
+    board_count: u64,
+    /// Is the monitor allowed to stop the search yet?
+    can_stop: bool,
+    is_stopped: bool,
+}
+
+impl<'a, M: SearchMonitor> Searcher<'a, M> {
+    fn new<E: Evaluate>(board: &E, monitor: &'a mut M) -> Self {
+        Self {
+            monitor,
+            getting_move_for: board.get_current_player_color(),
+            board_count: 0,
+            can_stop: false,
+            is_stopped: false,
+        }
+    }
+
+    /// Has the monitor stopped the search?
+    fn poll(&mut self) -> bool {
+        if self.can_stop && !self.is_stopped && self.board_count % BOARDS_PER_POLL == 0 {
+            self.is_stopped = self.monitor.should_stop();
+        }
+        self.is_stopped
+    }
+
+    /// Find the best of the given moves with an alpha-beta search,
+    /// or `None` if the search was stopped.
+    ///
+    /// This also returns the most that any other move could be worth,
+    /// if there are other moves.
+    fn search_root<E: Evaluate>(
+        &mut self,
+        board: &E,
+        depth: i32,
+        moves: &[Move],
+    ) -> Option<(PrincipalVariation, Option<f64>)> {
+        let mut alpha = -1000000.0;
+        let mut best: Option<PrincipalVariation> = None;
+        // Moves that are not better than alpha return at least their
+        // true value, so this is never less than the second best value.
+        let mut second_best_value: Option<f64> = None;
+        let mut child_pv = Vec::new();
+        for m in moves {
+            let value = self.minimax_pv(
+                &board.apply_eval_move(*m),
+                depth,
+                alpha,
+                1000000.0,
+                false,
+                &mut child_pv,
+            );
+            if self.is_stopped {
+                return None;
+            }
+            let other_value = if best.is_none() || value > alpha {
+                let mut moves = vec![*m];
+                moves.extend_from_slice(&child_pv);
+                alpha = value;
+                best.replace(PrincipalVariation { moves, value })
+                    .map(|line| line.value)
+            } else {
+                Some(value)
+            };
+            if let Some(other_value) = other_value {
+                second_best_value = Some(match second_best_value {
+                    Some(second_best_value) => second_best_value.max(other_value),
+                    None => other_value,
+                });
+            }
+        }
+        best.map(|line| (line, second_best_value))
+    }
+
+    /// Perform minimax like `Evaluate::minimax`, and also store the
+    /// line of play that leads to the value in `pv`.
+    fn minimax_pv<E: Evaluate>(
+        &mut self,
+        board: &E,
+        depth: i32,
+        mut alpha: f64,
+        mut beta: f64,
+        is_maximizing: bool,
+        pv: &mut Vec<Move>,
+    ) -> f64 {
+        self.board_count += 1;
+        pv.clear();
+
+        if self.poll() {
+            return 0.0;
+        }
+
+        if depth == 0 {
+            return board.value_for(self.getting_move_for);
+        }
+
+        let legal_moves = board.get_legal_moves();
+        if legal_moves.is_empty() {
+            return board.get_terminal_value_for(self.getting_move_for);
+        }
+
+        let mut best_move_value = if is_maximizing { -999999.0 } else { 999999.0 };
+        let mut child_pv = Vec::new();
+        for m in legal_moves {
+            let child_board_value = self.minimax_pv(
+                &board.apply_eval_move(m),
+                depth - 1,
+                alpha,
+                beta,
+                !is_maximizing,
+                &mut child_pv,
+            );
+            if self.is_stopped {
+                return 0.0;
+            }
+
+            let is_better = if is_maximizing {
+                child_board_value > best_move_value
+            } else {
+                child_board_value < best_move_value
+            };
+            if is_better || pv.is_empty() {
+                best_move_value = child_board_value;
+                pv.clear();
+                pv.push(m);
+                pv.extend_from_slice(&child_pv);
+            }
+
+            if is_maximizing && best_move_value > alpha {
+                alpha = best_move_value;
+            } else if !is_maximizing && best_move_value < beta {
+                beta = best_move_value;
+            }
+
+            if beta <= alpha {
+                break;
+            }
+        }
+
+        best_move_value
+    }
+}
@@ -0,0 +1,408 @@
+use super::{Color, Evaluate, Move, Piece};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+
+/// The number of words used to store each entry of a transposition table.
+const ENTRY_WORDS: usize = 3;
+
+/// How the value stored for a position relates to its true value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bound {
+    /// The value is exact.
+    Exact = 1,
+    /// The true value is at least the stored value.
+    Lower = 2,
+    /// The true value is at most the stored value.
+    Upper = 3,
+}
+
+/// What a search found out about a position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Entry {
+    value: f64,
+    depth: i32,
+    bound: Bound,
+    /// The code of the best move found, from `get_move_code`.
+    code: u16,
+}
+
+/// A table of positions that have already been searched, which can be
+/// shared between threads without locking.
+///
+/// Every entry is stored as its value, its other data, and a check word,
+/// which is the position's key xor both of them. If another thread
+/// overwrites an entry while it is being read, the check no longer
+/// matches and the entry is ignored.
+pub struct TranspositionTable {
+    entries: Vec<[AtomicU64; ENTRY_WORDS]>,
+}
+
+impl TranspositionTable {
+    /// Create a table that takes up about this many megabytes.
+    pub fn new(megabytes: usize) -> Self {
+        let len = (megabytes * 1024 * 1024 / (ENTRY_WORDS * 8)).max(1);
+        Self {
+            entries: (0..len)
+                .map(|_| [AtomicU64::new(0), AtomicU64::new(0), AtomicU64::new(0)])
+                .collect(),
+        }
+    }
+
+    /// Forget every position in the table.
+    pub fn clear(&self) {
+        for entry in &self.entries {
+            for word in entry {
+                word.store(0, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn get_slot(&self, key: u64) -> &[AtomicU64; ENTRY_WORDS] {
+        &self.entries[(key % self.entries.len() as u64) as usize]
+    }
+
+    fn probe(&self, key: u64) -> Option<Entry> {
+        let slot = self.get_slot(key);
+        let value = slot[0].load(Ordering::Relaxed);
+        let data = slot[1].load(Ordering::Relaxed);
+        let check = slot[2].load(Ordering::Relaxed);
+        if check ^ value ^ data != key {
+            return None;
+        }
+
+        let bound = match (data >> 16) & 3 {
+            1 => Bound::Exact,
+            2 => Bound::Lower,
+            3 => Bound::Upper,
+            // An empty slot
+            _ => return None,
+        };
+        Some(Entry {
+            value: f64::from_bits(value),
+            depth: (data >> 24) as i32,
+            bound,
+            code: data as u16,
+        })
+    }
+
+    fn store(&self, key: u64, entry: Entry) {
+        let slot = self.get_slot(key);
+        let value = entry.value.to_bits();
+        let data =
+            ((entry.depth.max(0) as u64) << 24) | ((entry.bound as u64) << 16) | entry.code as u64;
+        slot[0].store(value, Ordering::Relaxed);
+        slot[1].store(data, Ordering::Relaxed);
+        slot[2].store(key ^ value ^ data, Ordering::Relaxed);
+    }
+}
+
+impl Default for TranspositionTable {
+    /// Create a table that takes up 16 megabytes.
+    fn default() -> Self {
+        Self::new(16)
+    }
+}
+
+/// Get a code for a move that is different for every legal move in a
+/// position, and never zero.
+fn get_move_code(m: Move) -> u16 {
+    let get_kind = |piece: Piece| match piece {
+        Piece::King(_, _) => 0,
+        Piece::Queen(_, _) => 1,
+        Piece::Rook(_, _) => 2,
+        Piece::Bishop(_, _) => 3,
+        Piece::Knight(_, _) => 4,
+        Piece::Pawn(_, _) => 5,
+    };
+    let get_square = |pos: crate::Position| (pos.get_row() * 8 + pos.get_col()) as u16;
+
+    match m {
+        Move::Piece(from, to) => (1 << 12) | (get_square(from) << 6) | get_square(to),
+        Move::Promotion(from, to, piece) => {
+            ((2 + get_kind(piece)) << 12) | (get_square(from) << 6) | get_square(to)
+        }
+        Move::Drop(piece, to) => (8 << 12) | (get_kind(piece) << 6) | get_square(to),
+        Move::KingSideCastle => 9 << 12,
+        Move::QueenSideCastle => 10 << 12,
+        Move::Resign => 11 << 12,
+    }
+}
+
+/// Search for the best move with Lazy SMP.
+///
+/// Every thread searches the same position with iterative deepening,
+/// and they only cooperate through the transposition table. To spread
+/// the threads out, helper threads with odd numbers skip the first
+/// depth, and every helper tries the moves in a different order. The
+/// helpers stop as soon as the main thread has finished, and the main
+/// thread's move is returned.
+pub(crate) fn get_best_next_move_parallel<E: Evaluate + Sync>(
+    board: &E,
+    depth: i32,
+    threads: usize,
+    table: &TranspositionTable,
+) -> (Move, u64, f64) {
+    let stop = AtomicBool::new(false);
+    let board_count = AtomicU64::new(0);
+
+    let (best_move, best_move_value) = thread::scope(|scope| {
+        for id in 1..threads {
+            let (stop, board_count) = (&stop, &board_count);
+            scope.spawn(move || {
+                let mut search = Search::new(board, table, stop);
+                // Helpers look one move further, in case the main thread
+                // is still busy by the time they are done.
+                search.iterate(board, id, depth + 1);
+                board_count.fetch_add(search.board_count, Ordering::Relaxed);
+            });
+        }
+
+        let mut search = Search::new(board, table, &stop);
+        let result = search.iterate(board, 0, depth);
+        stop.store(true, Ordering::Relaxed);
+        board_count.fetch_add(search.board_count, Ordering::Relaxed);
+        result
+    });
+
+    (
+        best_move,
+        board_count.load(Ordering::Relaxed),
+        best_move_value,
+    )
+}
+
+/// The state of the search on one thread.
+struct Search<'a> {
+    table: &'a TranspositionTable,
+    stop: &'a AtomicBool,
+    getting_move_for: Color,
+    board_count: u64,
+}
+
+impl<'a> Search<'a> {
+    fn new<E: Evaluate>(board: &E, table: &'a TranspositionTable, stop: &'a AtomicBool) -> Self {
+        Self {
+            table,
+            stop,
+            getting_move_for: board.get_current_player_color(),
+            board_count: 0,
+        }
+    }
+
+    #[inline]
+    fn is_stopped(&self) -> bool {
+        self.stop.load(Ordering::Relaxed)
+    }
+
+    /// Search the moves of the thread with the given number, one depth
+    /// at a time, and get the best move of the deepest finished search.
+    fn iterate<E: Evaluate>(&mut self, board: &E, id: usize, depth: i32) -> (Move, f64) {
+        let mut legal_moves = board.get_legal_moves();
+        if legal_moves.is_empty() {
+            return (Move::Resign, -999999.0);
+        }
+        let len = legal_moves.len();
+        legal_moves.rotate_left(id % len);
+
+        let mut best = (legal_moves[0], -999999.0);
+        for current_depth in (id % 2) as i32..=depth {
+            let mut alpha = -1000000.0;
+            let mut iteration_best = None;
+            for m in &legal_moves {
+                let value =
+                    self.search(&board.apply_eval_move(*m), current_depth, alpha, 1000000.0);
+                if self.is_stopped() {
+                    return best;
+                }
+                if iteration_best.is_none() || value > alpha {
+                    iteration_best = Some((*m, value));
+                    alpha = value;
+                }
+            }
+
+            if let Some(iteration_best) = iteration_best {
+                best = iteration_best;
+            }
+            // Try the best move first in the next iteration
+            if let Some(i) = legal_moves.iter().position(|m| *m == best.0) {
+                let m = legal_moves.remove(i);
+                legal_moves.insert(0, m);
+            }
+        }
+        best
+    }
+
+    /// Perform minimax like `Evaluate::minimax`, using and filling in
+    /// the transposition table.
+    fn search<E: Evaluate>(&mut self, board: &E, depth: i32, mut alpha: f64, mut beta: f64) -> f64 {
+        self.board_count += 1;
+
+        if depth == 0 {
+            return board.value_for(self.getting_move_for);
+        }
+        if self.is_stopped() {
+            return 0.0;
+        }
+
+        // Values are stored for the player the search is for, so the
+        // same position searched for the other player needs another key.
+        let key = board
+            .get_eval_hash_key()
+            .map(|key| match self.getting_move_for {
+                Color::White => key,
+                Color::Black => !key,
+            });
+        let mut hash_code = 0;
+        if let Some(entry) = key.and_then(|key| self.table.probe(key)) {
+            hash_code = entry.code;
+            let is_usable = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => entry.value >= beta,
+                Bound::Upper => entry.value <= alpha,
+            };
+            if entry.depth >= depth && is_usable {
+                return entry.value;
+            }
+        }
+
+        let mut legal_moves = board.get_legal_moves();
+        if legal_moves.is_empty() {
+            return board.get_terminal_value_for(self.getting_move_for);
+        }
+        // Try the best move from the last search of this position first
+        if let Some(i) = legal_moves
+            .iter()
+            .position(|m| get_move_code(*m) == hash_code)
+        {
+            let m = legal_moves.remove(i);
+            legal_moves.insert(0, m);
+        }
+
+        let is_maximizing = board.get_current_player_color() == self.getting_move_for;
+        let (original_alpha, original_beta) = (alpha, beta);
+        let mut best_move_value = if is_maximizing { -999999.0 } else { 999999.0 };
+        let mut best_move = legal_moves[0];
+        for m in legal_moves {
+            let child_board_value = self.search(&board.apply_eval_move(m), depth - 1, alpha, beta);
+
+            if (is_maximizing && child_board_value > best_move_value)
+                || (!is_maximizing && child_board_value < best_move_value)
+            {
+                best_move_value = child_board_value;
+                best_move = m;
+            }
+
+            if is_maximizing && best_move_value > alpha {
+                alpha = best_move_value;
+            } else if !is_maximizing && best_move_value < beta {
+                beta = best_move_value;
+            }
+
+            if beta <= alpha {
+                break;
+            }
+        }
+
+        // The values of a search that was stopped halfway are meaningless
+        if self.is_stopped() {
+            return 0.0;
+        }
+
+        if let Some(key) = key {
+            let bound = if best_move_value <= original_alpha {
+                Bound::Upper
+            } else if best_move_value >= original_beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            self.table.store(
+                key,
+                Entry {
+                    value: best_move_value,
+                    depth,
+                    bound,
+                    code: get_move_code(best_move),
+                },
+            );
+        }
+        best_move_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_fen;
+    use crate::{Board, Position};
+
+    #[test]
+    fn test_table() {
+        let table = TranspositionTable::new(1);
+        assert_eq!(table.probe(0), None);
+        assert_eq!(table.probe(12345), None);
+
+        let entry = Entry {
+            value: -13.375,
+            depth: 3,
+            bound: Bound::Lower,
+            code: get_move_code(Move::Piece(Position::new(1, 4), Position::new(3, 4))),
+        };
+        table.store(12345, entry);
+        assert_eq!(table.probe(12345), Some(entry));
+
+        // A torn write does not match the key
+        table.get_slot(12345)[0].store(1, Ordering::Relaxed);
+        assert_eq!(table.probe(12345), None);
+        table.clear();
+        assert_eq!(table.probe(12345), None);
+    }
+
+    #[test]
+    fn test_move_codes() {
+        let board = parse_fen("r3k2r/1P4P1/8/8/8/8/8/R3K2R[QNp] w KQkq - 0 1").unwrap();
+        let mut codes = board
+            .get_legal_moves()
+            .into_iter()
+            .map(get_move_code)
+            .collect::<Vec<_>>();
+        let len = codes.len();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), len);
+        assert!(!codes.contains(&0));
+    }
+
+    #[test]
+    fn test_parallel_search() {
+        for fen in &[
+            "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 0 1",
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3",
+            "8/8/3k4/3p4/8/2N5/8/K7 b - - 0 1",
+        ] {
+            let board: Board = parse_fen(fen).unwrap();
+            let (_, _, value) = board.get_best_next_move(2);
+
+            // A single thread finds the same value every time
+            let table = TranspositionTable::new(1);
+            let result = board.get_best_next_move_parallel(2, 1, &table);
+            assert_eq!(result.2, value);
+            table.clear();
+            assert_eq!(board.get_best_next_move_parallel(2, 1, &table), result);
+
+            let table = TranspositionTable::new(1);
+            let (m, board_count, _) = board.get_best_next_move_parallel(2, 4, &table);
+            assert!(board.get_legal_moves().contains(&m));
+            assert!(board_count > result.1);
+        }
+
+        let board =
+            parse_fen("r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 0 1")
+                .unwrap();
+        let table = TranspositionTable::default();
+        let (m, _, value) = board.get_best_next_move_parallel(3, 4, &table);
+        assert_eq!(m, Move::Piece(Position::new(4, 7), Position::new(6, 5)));
+        assert_eq!(value, 999999.0);
+    }
+}
@@ -0,0 +1,1540 @@
+use super::{search, Board, Color, Evaluate, Move, Piece, Position, Variant};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// The magic bytes at the start of every WDL table.
+const WDL_MAGIC: [u8; 4] = [0x71, 0xe8, 0x23, 0x5d];
+/// The magic bytes at the start of every DTZ table.
+const DTZ_MAGIC: [u8; 4] = [0xd7, 0x66, 0x0c, 0xa5];
+
+/// The table stores positions for both players to move.
+const SPLIT: u8 = 1;
+/// The table has pawns, and is split into one part for each file
+/// the leading pawn can be on.
+const HAS_PAWNS: u8 = 2;
+
+/// Which player to move a DTZ table stores.
+const STM: u8 = 1;
+/// The DTZ values are mapped through a table.
+const MAPPED: u8 = 2;
+/// Wins are stored in plies rather than moves.
+const WIN_PLIES: u8 = 4;
+/// Losses are stored in plies rather than moves.
+const LOSS_PLIES: u8 = 8;
+/// The DTZ map stores 16 bit values.
+const WIDE: u8 = 16;
+/// Every position in the table has the same value.
+const SINGLE_VALUE: u8 = 128;
+
+/// The most pieces a table can have.
+const MAX_PIECES: usize = 7;
+
+/// A tablebase win is worth less than checkmate, so the CPU
+/// still prefers to mate when it can see it.
+const TABLEBASE_WIN: f64 = 100000.0;
+
+/// The outcome of a position with perfect play, from the point of
+/// view of the player to move.
+///
+/// Cursed wins and blessed losses are positions that are won or lost,
+/// but where the win takes too long to convert under the fifty-move rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Wdl {
+    Loss,
+    BlessedLoss,
+    Draw,
+    CursedWin,
+    Win,
+}
+
+impl Wdl {
+    fn from_i32(value: i32) -> Self {
+        match value {
+            i32::MIN..=-2 => Self::Loss,
+            -1 => Self::BlessedLoss,
+            0 => Self::Draw,
+            1 => Self::CursedWin,
+            _ => Self::Win,
+        }
+    }
+
+    fn get_sign(self) -> i32 {
+        match self {
+            Self::Loss | Self::BlessedLoss => -1,
+            Self::Draw => 0,
+            Self::CursedWin | Self::Win => 1,
+        }
+    }
+}
+
+/// The outcome for the other player.
+impl core::ops::Neg for Wdl {
+    type Output = Self;
+    fn neg(self) -> Self {
+        match self {
+            Self::Loss => Self::Win,
+            Self::BlessedLoss => Self::CursedWin,
+            Self::Draw => Self::Draw,
+            Self::CursedWin => Self::BlessedLoss,
+            Self::Win => Self::Loss,
+        }
+    }
+}
+
+/// The two kinds of table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Wdl,
+    Dtz,
+}
+
+/// The result of looking up a position in a single table.
+enum TableValue {
+    Value(i32),
+    /// The DTZ table only stores the other player to move.
+    ChangeStm,
+}
+
+/// Whether the best move in a position is known to be a capture or pawn
+/// move, in which case the DTZ table does not store it.
+#[derive(PartialEq)]
+enum SearchState {
+    Ok,
+    ZeroingBestMove,
+}
+
+/// A collection of Syzygy endgame tablebases, read from local files.
+///
+/// Tablebases store the outcome of every position with only a few
+/// pieces left on the board, so the CPU plays those endings perfectly
+/// instead of searching them. WDL tables (`.rtbw` files) say whether a
+/// position is won, drawn or lost, and DTZ tables (`.rtbz` files) say
+/// how many moves it takes to make progress by capturing or moving a
+/// pawn, which is how a won position is actually converted.
+///
+/// Tables are found by their names, like `KQvK.rtbw`, and read into
+/// memory the first time a position needs them. Only regular chess
+/// positions without castling rights can be probed.
+#[derive(Debug, Default)]
+pub struct Tablebase {
+    tables: BTreeMap<String, TableFiles>,
+    max_pieces: usize,
+}
+
+/// The files found for one combination of material.
+#[derive(Debug, Default)]
+struct TableFiles {
+    wdl_path: Option<PathBuf>,
+    dtz_path: Option<PathBuf>,
+    wdl: OnceLock<Option<Table>>,
+    dtz: OnceLock<Option<Table>>,
+}
+
+impl Tablebase {
+    /// Create a tablebase with the tables in a directory.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let mut result = Self::default();
+        result.add_directory(path)?;
+        Ok(result)
+    }
+
+    /// Add the tables in a directory, and return the number of files found.
+    pub fn add_directory(&mut self, path: impl AsRef<Path>) -> Result<usize, String> {
+        let path = path.as_ref();
+        let entries = fs::read_dir(path)
+            .map_err(|e| format!("could not read directory {}: {}", path.display(), e))?;
+
+        let mut count = 0;
+        for entry in entries.flatten() {
+            let file_path = entry.path();
+            let (name, kind) = match (
+                file_path.file_stem().and_then(|s| s.to_str()),
+                file_path.extension().and_then(|s| s.to_str()),
+            ) {
+                (Some(name), Some("rtbw")) => (name, Kind::Wdl),
+                (Some(name), Some("rtbz")) => (name, Kind::Dtz),
+                _ => continue,
+            };
+            let pieces = match parse_material(name) {
+                Some(material) => material[0].len() + material[1].len(),
+                None => continue,
+            };
+
+            let files = self.tables.entry(String::from(name)).or_default();
+            match kind {
+                Kind::Wdl => {
+                    files.wdl_path = Some(file_path);
+                    self.max_pieces = self.max_pieces.max(pieces);
+                }
+                Kind::Dtz => files.dtz_path = Some(file_path),
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Get the number of pieces in the largest tables that were found,
+    /// including both kings.
+    #[inline]
+    pub fn get_max_pieces(&self) -> usize {
+        self.max_pieces
+    }
+
+    /// Get the outcome of a position for the player to move.
+    ///
+    /// This returns `None` if the position has too many pieces,
+    /// or its tables are missing.
+    pub fn probe_wdl(&self, board: &Board) -> Option<Wdl> {
+        if !self.can_probe(board) {
+            return None;
+        }
+        self.search(board, false).map(|(wdl, _)| wdl)
+    }
+
+    /// Get the distance to the next capture or pawn move in plies,
+    /// with perfect play, for the player to move.
+    ///
+    /// The distance is positive when the player to move is winning,
+    /// negative when they are losing, and zero when the position is
+    /// drawn. Cursed wins and blessed losses are counted 100 plies
+    /// further away than they really are. This returns `None` if the
+    /// position has too many pieces, or its tables are missing.
+    pub fn probe_dtz(&self, board: &Board) -> Option<i32> {
+        if !self.can_probe(board) {
+            return None;
+        }
+        self.dtz(board)
+    }
+
+    /// Get the move that makes the most progress with perfect play.
+    ///
+    /// When winning, this is the move that leads to the next capture or
+    /// pawn move the soonest, and when losing, the move that puts it off
+    /// the longest. This returns `None` if any of the tables needed are
+    /// missing, or the position has no legal moves.
+    pub fn get_best_move(&self, board: &Board) -> Option<Move> {
+        if !self.can_probe(board) {
+            return None;
+        }
+
+        let mut best = None;
+        for m in board.get_legal_moves() {
+            let next = board.apply_eval_move(m);
+            let mut dtz = if is_zeroing(board, m) {
+                get_dtz_before_zeroing(-self.search(&next, false)?.0)
+            } else {
+                let dtz = -self.dtz(&next)?;
+                dtz + dtz.signum()
+            };
+            if next.is_checkmate() {
+                dtz = 1;
+            }
+
+            // Win as fast as possible, and lose as slowly as possible
+            let rank = match dtz {
+                0 => 0,
+                dtz if dtz > 0 => 10000 - dtz,
+                dtz => -10000 - dtz,
+            };
+            if best.map_or(true, |(_, best_rank)| rank > best_rank) {
+                best = Some((m, rank));
+            }
+        }
+        best.map(|(m, _)| m)
+    }
+
+    /// Can a board be looked up in the tables at all?
+    fn can_probe(&self, board: &Board) -> bool {
+        board.get_variant() == Variant::Standard
+            && get_pieces(board).len() <= self.max_pieces
+            && !has_castling(board)
+            && get_material(board, Color::White).starts_with('K')
+            && get_material(board, Color::Black).starts_with('K')
+    }
+
+    /// Get the table for a board, and whether Black is the side
+    /// the table calls White.
+    fn get_table(&self, board: &Board, kind: Kind) -> Option<(&Table, bool)> {
+        let white = get_material(board, Color::White);
+        let black = get_material(board, Color::Black);
+        let (name, black_stronger) = match format!("{}v{}", white, black) {
+            name if self.tables.contains_key(&name) => (name, false),
+            _ => (format!("{}v{}", black, white), true),
+        };
+        let files = self.tables.get(&name)?;
+
+        let (table, path) = match kind {
+            Kind::Wdl => (&files.wdl, &files.wdl_path),
+            Kind::Dtz => (&files.dtz, &files.dtz_path),
+        };
+        let table = table.get_or_init(|| {
+            let bytes = fs::read(path.as_ref()?).ok()?;
+            Table::new(bytes, kind, &name).ok()
+        });
+        table.as_ref().map(|table| (table, black_stronger))
+    }
+
+    /// Get the outcome of a position, taking captures into account.
+    ///
+    /// Tables store "don't care" values for positions where a capture is
+    /// the best move, because this makes them compress better, so the
+    /// captures have to be searched as well. When `check_zeroing` is set,
+    /// pawn moves are searched too, because DTZ tables do not store
+    /// positions where a winning pawn move is the best move.
+    fn search(&self, board: &Board, check_zeroing: bool) -> Option<(Wdl, SearchState)> {
+        let moves = board.get_legal_moves();
+        let mut best = Wdl::Loss;
+        let mut count = 0;
+
+        for m in &moves {
+            if !board.is_capture(*m) && (!check_zeroing || !is_zeroing(board, *m)) {
+                continue;
+            }
+            count += 1;
+
+            let value = -self.search(&board.apply_eval_move(*m), false)?.0;
+            if value > best {
+                best = value;
+                if value == Wdl::Win {
+                    return Some((value, SearchState::ZeroingBestMove));
+                }
+            }
+        }
+
+        // If every move was searched, the table might be wrong
+        // about this position, such as when en-passant is possible
+        let searched_all = count > 0 && count == moves.len();
+        let value = if searched_all {
+            best
+        } else {
+            match self.probe_table(board, Kind::Wdl, Wdl::Draw)? {
+                TableValue::Value(value) => Wdl::from_i32(value - 2),
+                TableValue::ChangeStm => return None,
+            }
+        };
+
+        if best >= value {
+            let state = if best > Wdl::Draw || searched_all {
+                SearchState::ZeroingBestMove
+            } else {
+                SearchState::Ok
+            };
+            Some((best, state))
+        } else {
+            Some((value, SearchState::Ok))
+        }
+    }
+
+    fn dtz(&self, board: &Board) -> Option<i32> {
+        let (wdl, state) = self.search(board, true)?;
+        if wdl == Wdl::Draw {
+            return Some(0);
+        }
+        if state == SearchState::ZeroingBestMove {
+            return Some(get_dtz_before_zeroing(wdl));
+        }
+
+        match self.probe_table(board, Kind::Dtz, wdl)? {
+            TableValue::Value(dtz) => {
+                let cursed = matches!(wdl, Wdl::CursedWin | Wdl::BlessedLoss);
+                Some((dtz + if cursed { 100 } else { 0 }) * wdl.get_sign())
+            }
+            TableValue::ChangeStm => {
+                // The table stores the other player to move,
+                // so find the best reply to each move
+                let mut min_dtz = i32::MAX;
+                for m in board.get_legal_moves() {
+                    let next = board.apply_eval_move(m);
+                    let zeroing = is_zeroing(board, m);
+                    let mut dtz = if zeroing {
+                        -get_dtz_before_zeroing(self.search(&next, false)?.0)
+                    } else {
+                        -self.dtz(&next)?
+                    };
+
+                    if dtz == 1 && next.is_checkmate() {
+                        min_dtz = 1;
+                    }
+                    if !zeroing {
+                        dtz += dtz.signum();
+                    }
+                    if dtz < min_dtz && dtz.signum() == wdl.get_sign() {
+                        min_dtz = dtz;
+                    }
+                }
+                Some(if min_dtz == i32::MAX { -1 } else { min_dtz })
+            }
+        }
+    }
+
+    /// Look up a position in a single table, without searching captures.
+    fn probe_table(&self, board: &Board, kind: Kind, wdl: Wdl) -> Option<TableValue> {
+        let mut board_pieces = get_pieces(board);
+        if board_pieces.len() == 2 {
+            // Only the kings are left
+            return Some(TableValue::Value(2));
+        }
+
+        let (table, black_stronger) = self.get_table(board, kind)?;
+        let indices = get_indices();
+
+        // Tables are stored with the stronger side as White, and symmetric
+        // tables only with White to move, so the board may need flipping
+        let black_to_move = board.get_turn_color() == Color::Black;
+        let flip = black_stronger || (table.symmetric && black_to_move);
+        let (flip_color, flip_squares) = if flip { (8, 56) } else { (0, 0) };
+        let stm = (flip ^ black_to_move) as usize;
+
+        let mut squares = Vec::with_capacity(board_pieces.len());
+        let mut pieces = Vec::with_capacity(board_pieces.len());
+        let mut lead_pawns = 0;
+        let mut file = 0;
+
+        if table.has_pawns {
+            // The leading pawns come first, and the one nearest the
+            // edge is used to pick which part of the table to use
+            let lead = table.get(0, 0).pieces[0] ^ flip_color;
+            board_pieces.retain(|(piece, square)| {
+                if *piece == lead {
+                    squares.push(square ^ flip_squares);
+                    pieces.push(lead ^ flip_color);
+                }
+                *piece != lead
+            });
+            lead_pawns = squares.len();
+
+            let mut leader = 0;
+            for i in 1..lead_pawns {
+                if indices.map_pawns[squares[i]] > indices.map_pawns[squares[leader]] {
+                    leader = i;
+                }
+            }
+            squares.swap(0, leader);
+            file = (squares[0] & 7).min(7 - (squares[0] & 7));
+        }
+
+        if kind == Kind::Dtz {
+            let flags = table.get(stm, file).flags;
+            if (flags & STM) as usize != stm && (!table.symmetric || table.has_pawns) {
+                return Some(TableValue::ChangeStm);
+            }
+        }
+
+        for (piece, square) in board_pieces {
+            squares.push(square ^ flip_squares);
+            pieces.push(piece ^ flip_color);
+        }
+
+        let data = table.get(stm, file);
+
+        // Put the pieces in the same order as the table
+        let size = squares.len();
+        for i in lead_pawns..size.saturating_sub(1) {
+            for j in i + 1..size {
+                if data.pieces[i] == pieces[j] {
+                    pieces.swap(i, j);
+                    squares.swap(i, j);
+                    break;
+                }
+            }
+        }
+
+        // Mirror the board so the leading piece is on files A to D
+        if squares[0] & 7 > 3 {
+            for square in squares.iter_mut() {
+                *square ^= 7;
+            }
+        }
+
+        let mut index;
+        if table.has_pawns {
+            index = indices.lead_pawn_index[lead_pawns][squares[0]];
+            squares[1..lead_pawns].sort_by_key(|square| indices.map_pawns[*square]);
+            for (i, square) in squares.iter().enumerate().take(lead_pawns).skip(1) {
+                index += indices.binomial[i][indices.map_pawns[*square]];
+            }
+        } else {
+            // Mirror the board so the leading piece is on ranks 1 to 4
+            if squares[0] >> 3 > 3 {
+                for square in squares.iter_mut() {
+                    *square ^= 56;
+                }
+            }
+
+            // And then so the first leading piece not on the A1-H8
+            // diagonal is below it
+            for i in 0..data.group_len[0] {
+                match get_diagonal_offset(squares[i]) {
+                    0 => continue,
+                    offset if offset > 0 => {
+                        for square in squares.iter_mut().skip(i) {
+                            *square = ((*square >> 3) | (*square << 3)) & 63;
+                        }
+                    }
+                    _ => {}
+                }
+                break;
+            }
+
+            index = if table.has_unique_pieces {
+                get_unique_pieces_index(&squares)
+            } else {
+                indices.map_kk[indices.map_a1d1d4[squares[0]]][squares[1]]
+            };
+        }
+
+        // Then the remaining groups of pieces
+        index *= data.group_index[0];
+        let mut start = data.group_len[0];
+        let mut remaining_pawns = table.has_pawns && table.pawn_count[1] > 0;
+        let mut next = 1;
+        while data.group_len[next] != 0 {
+            let end = start + data.group_len[next];
+            squares[start..end].sort_unstable();
+
+            let mut n = 0;
+            for i in 0..data.group_len[next] {
+                let square = squares[start + i];
+                let adjust = squares[..start].iter().filter(|s| square > **s).count();
+                let pawn_adjust = if remaining_pawns { 8 } else { 0 };
+                n += indices.binomial[i + 1][square - adjust - pawn_adjust];
+            }
+
+            remaining_pawns = false;
+            index += n * data.group_index[next];
+            start = end;
+            next += 1;
+        }
+
+        let value = table.decompress(data, index)?;
+        Some(TableValue::Value(match kind {
+            Kind::Wdl => value,
+            Kind::Dtz => table.map_dtz(file, value, wdl)?,
+        }))
+    }
+}
+
+/// The index of the leading group of pieces, when it has at least three
+/// pieces that are all different.
+fn get_unique_pieces_index(squares: &[usize]) -> u64 {
+    let indices = get_indices();
+    let (s0, s1, s2) = (squares[0], squares[1], squares[2]);
+    let adjust1 = (s1 > s0) as usize;
+    let adjust2 = (s2 > s0) as usize + (s2 > s1) as usize;
+    let rank = |square: usize| (square >> 3) as u64;
+
+    if get_diagonal_offset(s0) != 0 {
+        ((indices.map_a1d1d4[s0] * 63 + s1 - adjust1) * 62 + s2 - adjust2) as u64
+    } else if get_diagonal_offset(s1) != 0 {
+        (6 * 63 + rank(s0) * 28 + indices.map_b1h1h7[s1]) * 62 + (s2 - adjust2) as u64
+    } else if get_diagonal_offset(s2) != 0 {
+        6 * 63 * 62
+            + 4 * 28 * 62
+            + rank(s0) * 7 * 28
+            + (rank(s1) - adjust1 as u64) * 28
+            + indices.map_b1h1h7[s2]
+    } else {
+        6 * 63 * 62
+            + 4 * 28 * 62
+            + 4 * 7 * 28
+            + rank(s0) * 7 * 6
+            + (rank(s1) - adjust1 as u64) * 6
+            + (rank(s2) - adjust2 as u64)
+    }
+}
+
+/// How far a square is above the A1-H8 diagonal.
+#[inline]
+fn get_diagonal_offset(square: usize) -> i32 {
+    (square >> 3) as i32 - (square & 7) as i32
+}
+
+/// Does a move reset the fifty-move counter?
+fn is_zeroing(board: &Board, m: Move) -> bool {
+    board.is_capture(m)
+        || match m {
+            Move::Piece(from, _) | Move::Promotion(from, _, _) => {
+                matches!(board.get_piece(from), Some(piece) if piece.is_pawn())
+            }
+            _ => false,
+        }
+}
+
+/// The DTZ of a position where the best move resets the fifty-move counter.
+fn get_dtz_before_zeroing(wdl: Wdl) -> i32 {
+    match wdl {
+        Wdl::Loss => -1,
+        Wdl::BlessedLoss => -101,
+        Wdl::Draw => 0,
+        Wdl::CursedWin => 101,
+        Wdl::Win => 1,
+    }
+}
+
+/// Get the pieces on the board as table piece codes and square numbers,
+/// in order of square.
+fn get_pieces(board: &Board) -> Vec<(u8, usize)> {
+    let mut result = Vec::new();
+    for square in 0..64 {
+        if let Some(piece) = board.get_piece(Position::new(square as i32 / 8, square as i32 % 8)) {
+            result.push((get_piece_code(piece), square));
+        }
+    }
+    result
+}
+
+/// Tables number pieces from 1 to 6 for White, and from 9 to 14 for Black.
+fn get_piece_code(piece: Piece) -> u8 {
+    let kind = match piece {
+        Piece::Pawn(_, _) => 1,
+        Piece::Knight(_, _) => 2,
+        Piece::Bishop(_, _) => 3,
+        Piece::Rook(_, _) => 4,
+        Piece::Queen(_, _) => 5,
+        Piece::King(_, _) => 6,
+    };
+    match piece.get_color() {
+        Color::White => kind,
+        Color::Black => kind + 8,
+    }
+}
+
+/// Get a player's pieces as they are written in table names, like `KRP`.
+fn get_material(board: &Board, color: Color) -> String {
+    let black = color == Color::Black;
+    let mut letters = get_pieces(board)
+        .iter()
+        .filter(|(piece, _)| (piece & 8 != 0) == black)
+        .map(|(piece, _)| b"PNBRQK"[(piece & 7) as usize - 1] as char)
+        .collect::<Vec<char>>();
+    letters.sort_by_key(|letter| "KQRBNP".find(*letter));
+    letters.into_iter().collect()
+}
+
+/// Split the name of a table like `KRPvKR` into each side's pieces.
+fn parse_material(name: &str) -> Option<[&str; 2]> {
+    let (white, black) = name.split_once('v')?;
+    for side in &[white, black] {
+        if !side.starts_with('K')
+            || side.matches('K').count() != 1
+            || !side.chars().all(|c| "KQRBNP".contains(c))
+        {
+            return None;
+        }
+    }
+    if white.len() + black.len() > MAX_PIECES {
+        return None;
+    }
+    Some([white, black])
+}
+
+/// Could either player still castle?
+fn has_castling(board: &Board) -> bool {
+    [Color::White, Color::Black].iter().any(|color| {
+        let row = match color {
+            Color::White => 0,
+            Color::Black => 7,
+        };
+        let rights = board.get_castling_rights(*color);
+        let has_rook = |col| {
+            board.get_piece(Position::new(row, col))
+                == Some(Piece::Rook(*color, Position::new(row, col)))
+        };
+        board.get_piece(Position::new(row, 4)) == Some(Piece::King(*color, Position::new(row, 4)))
+            && ((rights.can_kingside_castle() && has_rook(7))
+                || (rights.can_queenside_castle() && has_rook(0)))
+    })
+}
+
+/// The tables used to turn the squares of pieces into an index.
+struct Indices {
+    /// The number of ways to choose `k` of `n` squares.
+    binomial: [[u64; 64]; 6],
+    /// The index of the leading pawns, when the first is on a square.
+    lead_pawn_index: [[u64; 64]; 6],
+    /// The number of ways to place the leading pawns on each file.
+    lead_pawns_size: [[u64; 4]; 6],
+    /// Numbers the squares a pawn can be on, with the highest
+    /// numbers nearest the edge and the first rank.
+    map_pawns: [usize; 64],
+    /// Numbers the squares below the A1-H8 diagonal.
+    map_b1h1h7: [u64; 64],
+    /// Numbers the squares in the A1-D1-D4 triangle.
+    map_a1d1d4: [usize; 64],
+    /// Numbers the 462 ways to place two kings, with the
+    /// first in the A1-D1-D4 triangle.
+    map_kk: [[u64; 64]; 10],
+}
+
+fn get_indices() -> &'static Indices {
+    static INDICES: OnceLock<Indices> = OnceLock::new();
+    INDICES.get_or_init(Indices::new)
+}
+
+impl Indices {
+    fn new() -> Self {
+        let mut result = Self {
+            binomial: [[0; 64]; 6],
+            lead_pawn_index: [[0; 64]; 6],
+            lead_pawns_size: [[0; 4]; 6],
+            map_pawns: [0; 64],
+            map_b1h1h7: [0; 64],
+            map_a1d1d4: [0; 64],
+            map_kk: [[0; 64]; 10],
+        };
+
+        let mut code = 0;
+        for square in 0..64 {
+            if get_diagonal_offset(square) < 0 {
+                result.map_b1h1h7[square] = code;
+                code += 1;
+            }
+        }
+
+        // The squares on the diagonal come last
+        let mut diagonal = vec![];
+        let mut code = 0;
+        for square in 0..=27 {
+            if square & 7 > 3 {
+                continue;
+            }
+            match get_diagonal_offset(square) {
+                offset if offset < 0 => {
+                    result.map_a1d1d4[square] = code;
+                    code += 1;
+                }
+                0 => diagonal.push(square),
+                _ => {}
+            }
+        }
+        for square in diagonal {
+            result.map_a1d1d4[square] = code;
+            code += 1;
+        }
+
+        // Both kings on the diagonal come last
+        let mut both_on_diagonal = vec![];
+        let mut code = 0;
+        for index in 0..10 {
+            for first in 0..=27 {
+                // Every square outside the triangle is also mapped to zero
+                if result.map_a1d1d4[first] != index || (index == 0 && first != 1) {
+                    continue;
+                }
+                for second in 0..64 {
+                    let rows = ((first >> 3) as i32 - (second >> 3) as i32).abs();
+                    let cols = ((first & 7) as i32 - (second & 7) as i32).abs();
+                    if rows <= 1 && cols <= 1 {
+                        continue;
+                    }
+                    let first_on_diagonal = get_diagonal_offset(first) == 0;
+                    if first_on_diagonal && get_diagonal_offset(second) > 0 {
+                        continue;
+                    } else if first_on_diagonal && get_diagonal_offset(second) == 0 {
+                        both_on_diagonal.push((index, second));
+                    } else {
+                        result.map_kk[index][second] = code;
+                        code += 1;
+                    }
+                }
+            }
+        }
+        for (index, second) in both_on_diagonal {
+            result.map_kk[index][second] = code;
+            code += 1;
+        }
+
+        result.binomial[0][0] = 1;
+        for n in 1..64 {
+            for k in 0..6.min(n + 1) {
+                result.binomial[k][n] = if k > 0 {
+                    result.binomial[k - 1][n - 1]
+                } else {
+                    0
+                } + if k < n { result.binomial[k][n - 1] } else { 0 };
+            }
+        }
+
+        let mut available = 47;
+        for lead_pawns in 1..=5 {
+            for file in 0..4 {
+                let mut index = 0;
+                for rank in 1..7 {
+                    let square = rank * 8 + file;
+                    if lead_pawns == 1 {
+                        result.map_pawns[square] = available;
+                        result.map_pawns[square ^ 7] = available - 1;
+                        available = available.saturating_sub(2);
+                    }
+                    result.lead_pawn_index[lead_pawns][square] = index;
+                    index += result.binomial[lead_pawns - 1][result.map_pawns[square]];
+                }
+                result.lead_pawns_size[lead_pawns][file] = index;
+            }
+        }
+
+        result
+    }
+}
+
+/// How one part of a table is compressed, and where it is in the file.
+#[derive(Clone, Debug, Default)]
+struct PairsData {
+    flags: u8,
+    /// The pieces in the order they are indexed.
+    pieces: [u8; MAX_PIECES],
+    /// The sizes of the groups of pieces that are indexed together,
+    /// ending with a zero.
+    group_len: [usize; MAX_PIECES + 1],
+    /// What each group's index is multiplied by, and the size of the table.
+    group_index: [u64; MAX_PIECES + 1],
+    /// Where DTZ values for each outcome start in the map.
+    map_index: [usize; 4],
+    block_size: usize,
+    span: u64,
+    sparse_index_size: usize,
+    blocks: usize,
+    block_length_size: usize,
+    min_sym_len: u8,
+    lowest_sym: usize,
+    base64: Vec<u64>,
+    symlen: Vec<u32>,
+    btree: usize,
+    sparse_index: usize,
+    block_length: usize,
+    data: usize,
+}
+
+/// A table read from a file.
+struct Table {
+    data: Vec<u8>,
+    symmetric: bool,
+    has_pawns: bool,
+    has_unique_pieces: bool,
+    /// The number of pawns of the leading color, and of the other.
+    pawn_count: [usize; 2],
+    /// The parts of the table, for each player to move and each file.
+    pairs: Vec<Vec<PairsData>>,
+    /// Where the DTZ map starts.
+    map: usize,
+}
+
+impl core::fmt::Debug for Table {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "Table({} bytes)", self.data.len())
+    }
+}
+
+impl Table {
+    fn new(data: Vec<u8>, kind: Kind, name: &str) -> Result<Self, String> {
+        let material =
+            parse_material(name).ok_or_else(|| format!("invalid table name {}", name))?;
+        let magic = match kind {
+            Kind::Wdl => WDL_MAGIC,
+            Kind::Dtz => DTZ_MAGIC,
+        };
+        if data.len() < 5 || data[..4] != magic {
+            return Err(format!("{} is not a tablebase file", name));
+        }
+
+        let count = |side: usize, letter: char| material[side].matches(letter).count();
+        let (white_pawns, black_pawns) = (count(0, 'P'), count(1, 'P'));
+        // The color with fewer pawns leads, which compresses better
+        let white_leads = black_pawns == 0 || (white_pawns > 0 && black_pawns >= white_pawns);
+        let pawn_count = if white_leads {
+            [white_pawns, black_pawns]
+        } else {
+            [black_pawns, white_pawns]
+        };
+
+        let mut result = Self {
+            symmetric: material[0] == material[1],
+            has_pawns: white_pawns + black_pawns > 0,
+            has_unique_pieces: (0..2).any(|side| "QRBN".chars().any(|c| count(side, c) == 1)),
+            pawn_count,
+            pairs: vec![],
+            map: 0,
+            data,
+        };
+
+        let flags = result.data[4];
+        if (flags & HAS_PAWNS != 0) != result.has_pawns || (flags & SPLIT != 0) == result.symmetric
+        {
+            return Err(format!("{} does not match its name", name));
+        }
+
+        result
+            .read_header(kind, material[0].len() + material[1].len())
+            .ok_or_else(|| format!("{} is truncated", name))?;
+        Ok(result)
+    }
+
+    #[inline]
+    fn get(&self, stm: usize, file: usize) -> &PairsData {
+        let pairs = &self.pairs[stm % self.pairs.len()];
+        &pairs[if self.has_pawns { file } else { 0 }]
+    }
+
+    fn byte(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    fn u16_le(&self, offset: usize) -> Option<u16> {
+        Some(u16::from_le_bytes([
+            self.byte(offset)?,
+            self.byte(offset + 1)?,
+        ]))
+    }
+
+    fn u32_le(&self, offset: usize) -> Option<u32> {
+        Some(u32::from_le_bytes([
+            self.byte(offset)?,
+            self.byte(offset + 1)?,
+            self.byte(offset + 2)?,
+            self.byte(offset + 3)?,
+        ]))
+    }
+
+    /// Read big-endian bytes from a compressed block, treating
+    /// anything past the end of the file as zero.
+    fn read_be(&self, offset: usize, len: usize) -> u64 {
+        (offset..offset + len).fold(0, |value, i| value << 8 | self.byte(i).unwrap_or(0) as u64)
+    }
+
+    /// Read the layout of the table from the start of the file.
+    fn read_header(&mut self, kind: Kind, piece_count: usize) -> Option<()> {
+        let files = if self.has_pawns { 4 } else { 1 };
+        let sides = if kind == Kind::Wdl && !self.symmetric {
+            2
+        } else {
+            1
+        };
+        let both_pawns = self.has_pawns && self.pawn_count[1] > 0;
+        self.pairs = vec![vec![PairsData::default(); files]; sides];
+
+        let mut p = 5;
+        for file in 0..files {
+            let first = self.byte(p)?;
+            let second = if both_pawns { self.byte(p + 1)? } else { 0xff };
+            let order = [[first & 0xf, second & 0xf], [first >> 4, second >> 4]];
+            p += 1 + both_pawns as usize;
+
+            for k in 0..piece_count {
+                let byte = self.byte(p)?;
+                for (side, pairs) in self.pairs.iter_mut().enumerate() {
+                    pairs[file].pieces[k] = if side == 0 { byte & 0xf } else { byte >> 4 };
+                }
+                p += 1;
+            }
+
+            for (side, order) in order.iter().enumerate().take(sides) {
+                self.set_groups(side, file, *order, piece_count);
+            }
+        }
+        p += p & 1;
+
+        for file in 0..files {
+            for side in 0..sides {
+                p = self.set_sizes(side, file, p)?;
+            }
+        }
+
+        if kind == Kind::Dtz {
+            self.map = p;
+            for file in 0..files {
+                let flags = self.pairs[0][file].flags;
+                if flags & MAPPED == 0 {
+                    continue;
+                }
+                for i in 0..4 {
+                    if flags & WIDE != 0 {
+                        p += p & 1;
+                        self.pairs[0][file].map_index[i] = (p - self.map) / 2 + 1;
+                        p += 2 * self.u16_le(p)? as usize + 2;
+                    } else {
+                        self.pairs[0][file].map_index[i] = p - self.map + 1;
+                        p += self.byte(p)? as usize + 1;
+                    }
+                }
+            }
+            p += p & 1;
+        }
+
+        for file in 0..files {
+            for side in 0..sides {
+                self.pairs[side][file].sparse_index = p;
+                p += self.pairs[side][file].sparse_index_size * 6;
+            }
+        }
+        for file in 0..files {
+            for side in 0..sides {
+                self.pairs[side][file].block_length = p;
+                p += self.pairs[side][file].block_length_size * 2;
+            }
+        }
+        for file in 0..files {
+            for side in 0..sides {
+                p = (p + 0x3f) & !0x3f;
+                self.pairs[side][file].data = p;
+                p += self.pairs[side][file].blocks * self.pairs[side][file].block_size;
+            }
+        }
+
+        if p > self.data.len() {
+            return None;
+        }
+        Some(())
+    }
+
+    /// Work out how the pieces are grouped, and what each
+    /// group's index is multiplied by.
+    fn set_groups(&mut self, side: usize, file: usize, order: [u8; 2], piece_count: usize) {
+        let indices = get_indices();
+        let (has_pawns, has_unique_pieces) = (self.has_pawns, self.has_unique_pieces);
+        let both_pawns = has_pawns && self.pawn_count[1] > 0;
+        let d = &mut self.pairs[side][file];
+
+        // The kings, and a third unique piece, are always indexed together
+        let mut first_len: i32 = if has_pawns {
+            0
+        } else if has_unique_pieces {
+            3
+        } else {
+            2
+        };
+        let mut n = 0;
+        d.group_len[0] = 1;
+        for i in 1..piece_count {
+            first_len -= 1;
+            if first_len > 0 || d.pieces[i] == d.pieces[i - 1] {
+                d.group_len[n] += 1;
+            } else {
+                n += 1;
+                d.group_len[n] = 1;
+            }
+        }
+        n += 1;
+        d.group_len[n] = 0;
+
+        let mut next = if both_pawns { 2 } else { 1 };
+        let mut free_squares = 64 - d.group_len[0] - if both_pawns { d.group_len[1] } else { 0 };
+        let mut index = 1;
+
+        let mut k = 0;
+        while next < n || k == order[0] as usize || k == order[1] as usize {
+            if k == order[0] as usize {
+                d.group_index[0] = index;
+                index *= if has_pawns {
+                    indices.lead_pawns_size[d.group_len[0]][file]
+                } else if has_unique_pieces {
+                    31332
+                } else {
+                    462
+                };
+            } else if k == order[1] as usize {
+                d.group_index[1] = index;
+                index *= indices.binomial[d.group_len[1]][48 - d.group_len[0]];
+            } else {
+                d.group_index[next] = index;
+                index *= indices.binomial[d.group_len[next]][free_squares];
+                free_squares -= d.group_len[next];
+                next += 1;
+            }
+            k += 1;
+        }
+        d.group_index[n] = index;
+    }
+
+    /// Read how a part of the table is compressed.
+    fn set_sizes(&mut self, side: usize, file: usize, mut p: usize) -> Option<usize> {
+        let flags = self.byte(p)?;
+        p += 1;
+        if flags & SINGLE_VALUE != 0 {
+            let value = self.byte(p)?;
+            let d = &mut self.pairs[side][file];
+            d.flags = flags;
+            // The single value is stored in place of the symbol length
+            d.min_sym_len = value;
+            return Some(p + 1);
+        }
+
+        let d = &self.pairs[side][file];
+        let groups = d.group_len.iter().position(|len| *len == 0)?;
+        let table_size = d.group_index[groups];
+
+        let block_size = 1usize.checked_shl(self.byte(p)? as u32)?;
+        let span = 1u64.checked_shl(self.byte(p + 1)? as u32)?;
+        let padding = self.byte(p + 2)? as usize;
+        let blocks = self.u32_le(p + 3)? as usize;
+        let max_sym_len = self.byte(p + 7)?;
+        let min_sym_len = self.byte(p + 8)?;
+        p += 9;
+        if min_sym_len == 0 || max_sym_len < min_sym_len || max_sym_len > 32 {
+            return None;
+        }
+
+        // The symbols are a canonical Huffman code, where longer codes
+        // have lower values, so the length of a code can be found by
+        // comparing it with the lowest code of each length
+        let lowest_sym = p;
+        let lengths = (max_sym_len - min_sym_len + 1) as usize;
+        let mut base64 = vec![0u64; lengths];
+        for i in (0..lengths - 1).rev() {
+            let lowest = self.u16_le(lowest_sym + 2 * i)? as u64;
+            let next_lowest = self.u16_le(lowest_sym + 2 * i + 2)? as u64;
+            base64[i] = base64[i + 1].wrapping_add(lowest).wrapping_sub(next_lowest) / 2;
+        }
+        for (i, base) in base64.iter_mut().enumerate() {
+            *base = base
+                .checked_shl((64 - i - min_sym_len as usize) as u32)
+                .unwrap_or(0);
+        }
+        p += lengths * 2;
+
+        // Every symbol stands for a pair of other symbols, or a value
+        let symbols = self.u16_le(p)? as usize;
+        p += 2;
+        let btree = p;
+        if btree + symbols * 3 > self.data.len() {
+            return None;
+        }
+        let mut symlen = vec![0; symbols];
+        let mut visited = vec![false; symbols];
+        for sym in 0..symbols {
+            if !visited[sym] {
+                symlen[sym] = self.set_symlen(btree, sym, &mut symlen, &mut visited)?;
+            }
+        }
+
+        let d = &mut self.pairs[side][file];
+        d.flags = flags;
+        d.block_size = block_size;
+        d.span = span;
+        d.sparse_index_size = table_size.div_ceil(span) as usize;
+        d.blocks = blocks;
+        d.block_length_size = blocks + padding;
+        d.min_sym_len = min_sym_len;
+        d.lowest_sym = lowest_sym;
+        d.base64 = base64;
+        d.symlen = symlen;
+        d.btree = btree;
+        Some(p + symbols * 3 + (symbols & 1))
+    }
+
+    /// Get the number of values a symbol stands for, minus one.
+    fn set_symlen(
+        &self,
+        btree: usize,
+        sym: usize,
+        symlen: &mut [u32],
+        visited: &mut [bool],
+    ) -> Option<u32> {
+        visited[sym] = true;
+        let (left, right) = self.get_pair(btree, sym as u16)?;
+        if right == 0xfff {
+            return Some(0);
+        }
+        for child in &[left as usize, right as usize] {
+            if *child >= visited.len() {
+                return None;
+            }
+            if !visited[*child] {
+                symlen[*child] = self.set_symlen(btree, *child, symlen, visited)?;
+            }
+        }
+        Some(symlen[left as usize] + symlen[right as usize] + 1)
+    }
+
+    /// Get the pair of symbols a symbol stands for.
+    fn get_pair(&self, btree: usize, sym: u16) -> Option<(u16, u16)> {
+        let p = btree + sym as usize * 3;
+        let (a, b, c) = (self.byte(p)?, self.byte(p + 1)?, self.byte(p + 2)?);
+        let left = ((b as u16 & 0xf) << 8) | a as u16;
+        let right = ((c as u16) << 4) | (b as u16 >> 4);
+        Some((left, right))
+    }
+
+    /// Get the value stored at an index in a part of the table.
+    fn decompress(&self, d: &PairsData, index: u64) -> Option<i32> {
+        if d.flags & SINGLE_VALUE != 0 {
+            return Some(d.min_sym_len as i32);
+        }
+
+        // The sparse index says which block holds every `span`th value,
+        // and how far into the block it is
+        let k = (index / d.span) as usize;
+        if k >= d.sparse_index_size {
+            return None;
+        }
+        let mut block = self.u32_le(d.sparse_index + 6 * k)? as usize;
+        let mut offset = self.u16_le(d.sparse_index + 6 * k + 4)? as i64;
+        offset += (index % d.span) as i64 - (d.span / 2) as i64;
+
+        // Each block holds one more value than its length
+        let block_length = |block: usize| -> Option<i64> {
+            if block >= d.block_length_size {
+                return None;
+            }
+            Some(self.u16_le(d.block_length + 2 * block)? as i64)
+        };
+        while offset < 0 {
+            block = block.checked_sub(1)?;
+            offset += block_length(block)? + 1;
+        }
+        while offset > block_length(block)? {
+            offset -= block_length(block)? + 1;
+            block += 1;
+        }
+
+        // Read symbols until finding the one that covers the offset
+        let mut p = d.data + block * d.block_size;
+        let mut buffer = self.read_be(p, 8);
+        p += 8;
+        let mut buffer_size = 64;
+        let min_sym_len = d.min_sym_len as usize;
+        let mut sym;
+        loop {
+            let mut len = 0;
+            while buffer < d.base64[len] {
+                len += 1;
+                if len >= d.base64.len() {
+                    return None;
+                }
+            }
+            sym = ((buffer - d.base64[len]) >> (64 - len - min_sym_len)) as u16;
+            sym = sym.wrapping_add(self.u16_le(d.lowest_sym + 2 * len)?);
+
+            let values = *d.symlen.get(sym as usize)? as i64 + 1;
+            if offset < values {
+                break;
+            }
+            offset -= values;
+            len += min_sym_len;
+            buffer <<= len;
+            buffer_size -= len;
+            if buffer_size <= 32 {
+                buffer_size += 32;
+                buffer |= self.read_be(p, 4) << (64 - buffer_size);
+                p += 4;
+            }
+        }
+
+        // Then expand the symbol into its pairs to find the value
+        while *d.symlen.get(sym as usize)? != 0 {
+            let (left, right) = self.get_pair(d.btree, sym)?;
+            let left_values = *d.symlen.get(left as usize)? as i64 + 1;
+            if offset < left_values {
+                sym = left;
+            } else {
+                offset -= left_values;
+                sym = right;
+            }
+        }
+        Some(self.get_pair(d.btree, sym)?.0 as i32)
+    }
+
+    /// Turn a value from a DTZ table into plies.
+    fn map_dtz(&self, file: usize, mut value: i32, wdl: Wdl) -> Option<i32> {
+        let d = self.get(0, file);
+        if d.flags & MAPPED != 0 {
+            let map_index = d.map_index[match wdl {
+                Wdl::Loss => 1,
+                Wdl::BlessedLoss => 3,
+                Wdl::CursedWin => 2,
+                Wdl::Draw | Wdl::Win => 0,
+            }] + value as usize;
+            value = if d.flags & WIDE != 0 {
+                self.u16_le(self.map + 2 * map_index)? as i32
+            } else {
+                self.byte(self.map + map_index)? as i32
+            };
+        }
+
+        let in_moves = match wdl {
+            Wdl::Win => d.flags & WIN_PLIES == 0,
+            Wdl::Loss => d.flags & LOSS_PLIES == 0,
+            _ => true,
+        };
+        Some(if in_moves { value * 2 + 1 } else { value + 1 })
+    }
+}
+
+/// A board that is searched with the help of endgame tablebases.
+///
+/// Once a position has few enough pieces to be in the tables, its
+/// outcome is looked up instead of being searched any further. If the
+/// board being searched is already in the tables, the best move is
+/// picked from them directly.
+#[derive(Clone, Copy, Debug)]
+pub struct TablebaseEvaluator<'a> {
+    board: Board,
+    tablebase: &'a Tablebase,
+    wdl: Option<Wdl>,
+}
+
+impl<'a> TablebaseEvaluator<'a> {
+    /// Create an evaluator for a board.
+    pub fn new(board: Board, tablebase: &'a Tablebase) -> Self {
+        Self {
+            board,
+            tablebase,
+            wdl: None,
+        }
+    }
+
+    /// Get the board being evaluated.
+    #[inline]
+    pub fn get_board(&self) -> Board {
+        self.board
+    }
+
+    /// Get the tablebase used to look up endgames.
+    #[inline]
+    pub fn get_tablebase(&self) -> &'a Tablebase {
+        self.tablebase
+    }
+
+    fn get_tablebase_value(&self, wdl: Wdl, color: Color) -> f64 {
+        let wdl = if self.board.get_turn_color() == color {
+            wdl
+        } else {
+            -wdl
+        };
+        match wdl {
+            Wdl::Win => TABLEBASE_WIN,
+            Wdl::CursedWin => 1.0,
+            Wdl::Draw => 0.0,
+            Wdl::BlessedLoss => -1.0,
+            Wdl::Loss => -TABLEBASE_WIN,
+        }
+    }
+}
+
+impl Evaluate for TablebaseEvaluator<'_> {
+    fn value_for(&self, color: Color) -> f64 {
+        match self.wdl {
+            Some(wdl) => self.get_tablebase_value(wdl, color),
+            None => self.board.value_for(color),
+        }
+    }
+
+    fn get_terminal_value_for(&self, color: Color) -> f64 {
+        match self.wdl {
+            Some(wdl) => self.get_tablebase_value(wdl, color),
+            None => self.board.get_terminal_value_for(color),
+        }
+    }
+
+    #[inline]
+    fn get_current_player_color(&self) -> Color {
+        self.board.get_turn_color()
+    }
+
+    #[inline]
+    fn get_eval_hash_key(&self) -> Option<u64> {
+        Some(self.board.get_hash_key())
+    }
+
+    fn get_legal_moves(&self) -> Vec<Move> {
+        // Positions in the tables are not searched any further
+        match self.wdl {
+            Some(_) => vec![],
+            None => self.board.get_legal_moves(),
+        }
+    }
+
+    fn apply_eval_move(&self, m: Move) -> Self {
+        let board = self.board.apply_eval_move(m);
+        Self {
+            board,
+            tablebase: self.tablebase,
+            wdl: self.tablebase.probe_wdl(&board),
+        }
+    }
+
+    fn get_best_next_move(&self, depth: i32) -> (Move, u64, f64) {
+        let color = self.get_current_player_color();
+        if let Some(m) = self.tablebase.get_best_move(&self.board) {
+            return (m, 1, self.apply_eval_move(m).value_for(color));
+        }
+
+        search::get_best_next_move(self, depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_fen;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_indices() {
+        let indices = get_indices();
+
+        // Every way to place the two kings gets its own number
+        let mut codes = vec![];
+        for index in 0..10 {
+            for square in 0..64 {
+                if indices.map_kk[index][square] != 0 {
+                    codes.push(indices.map_kk[index][square]);
+                }
+            }
+        }
+        codes.sort_unstable();
+        assert_eq!(codes, (1..462).collect::<Vec<u64>>());
+
+        let mut pawns = (8..56)
+            .map(|s| indices.map_pawns[s])
+            .collect::<Vec<usize>>();
+        pawns.sort_unstable();
+        assert_eq!(pawns, (0..48).collect::<Vec<usize>>());
+        // A2 is nearest the edge and the first rank
+        assert_eq!(indices.map_pawns[8], 47);
+        assert_eq!(indices.map_pawns[15], 46);
+
+        assert_eq!(indices.map_a1d1d4[1], 0);
+        assert_eq!(indices.map_a1d1d4[27], 9);
+        assert_eq!(indices.map_b1h1h7[55], 27);
+        assert_eq!(indices.binomial[2][62], 1891);
+        assert_eq!(indices.lead_pawns_size[1], [6, 6, 6, 6]);
+    }
+
+    #[test]
+    fn test_material() {
+        let board = parse_fen("8/8/8/8/8/2k5/8/KQ6 w - - 0 1").unwrap();
+        assert_eq!(get_material(&board, Color::White), "KQ");
+        assert_eq!(get_material(&board, Color::Black), "K");
+
+        let board = board.flip_colors();
+        assert_eq!(get_material(&board, Color::White), "K");
+        assert_eq!(get_material(&board, Color::Black), "KQ");
+
+        assert_eq!(parse_material("KRPvKR"), Some(["KRP", "KR"]));
+        assert_eq!(parse_material("KQvKK"), None);
+        assert_eq!(parse_material("QvK"), None);
+        assert_eq!(parse_material("KQK"), None);
+    }
+
+    #[test]
+    fn test_tablebase_files() {
+        let dir = std::env::temp_dir().join(format!("syzygy-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("KQvK.rtbw"), b"not a table").unwrap();
+        fs::write(dir.join("KQvK.txt"), b"").unwrap();
+
+        let tablebase = Tablebase::open(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(tablebase.get_max_pieces(), 3);
+
+        // Bad tables are not used
+        let board = parse_fen("8/8/8/8/8/2k5/8/KQ6 w - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&board), None);
+        // Bare kings do not need a table
+        let board = parse_fen("8/8/8/8/8/2k5/8/K7 w - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&board), Some(Wdl::Draw));
+        // Neither do positions where a capture is the only move
+        let board = parse_fen("8/8/8/8/8/5k2/1q6/K7 w - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&board), Some(Wdl::Draw));
+        // Too many pieces
+        assert_eq!(tablebase.probe_wdl(&Board::default()), None);
+
+        assert!(Tablebase::open(dir).is_err());
+    }
+
+    #[test]
+    fn test_single_value_table() {
+        // A KQvK table where White to move always wins
+        let mut bytes = WDL_MAGIC.to_vec();
+        bytes.push(SPLIT);
+        // The leading group of pieces is indexed first
+        bytes.push(0xf0);
+        // White's king and queen, then Black's king
+        bytes.extend_from_slice(&[0xe6, 0x55, 0x6e]);
+        bytes.push(0);
+        bytes.extend_from_slice(&[SINGLE_VALUE, 4, SINGLE_VALUE, 0]);
+        bytes.resize(64, 0);
+
+        let dir = std::env::temp_dir().join(format!("syzygy-single-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("KQvK.rtbw"), bytes).unwrap();
+        let tablebase = Tablebase::open(&dir).unwrap();
+        let board = parse_fen("8/8/8/8/8/2k5/8/KQ6 w - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&board), Some(Wdl::Win));
+        assert_eq!(tablebase.probe_wdl(&board.change_turn()), Some(Wdl::Loss));
+        assert_eq!(tablebase.probe_wdl(&board.flip_colors()), Some(Wdl::Win));
+        // Black can capture the queen
+        let board = parse_fen("8/8/8/8/8/8/1k6/1Q5K b - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&board), Some(Wdl::Draw));
+        fs::remove_dir_all(&dir).unwrap();
+
+        let evaluator = TablebaseEvaluator::new(
+            parse_fen("8/8/8/8/8/2k5/8/KQ6 w - - 0 1").unwrap(),
+            &tablebase,
+        );
+        let (m, _, value) = evaluator.get_best_next_move(2);
+        assert_ne!(m, Move::Resign);
+        assert!(value > 0.0);
+    }
+
+    /// These need the 3, 4 and 5 piece tables, in the
+    /// directory named by `SYZYGY_PATH`.
+    #[test]
+    #[ignore]
+    fn test_probe_tables() {
+        let path = std::env::var("SYZYGY_PATH").expect("SYZYGY_PATH is not set");
+        let tablebase = Tablebase::open(path).unwrap();
+        assert_eq!(tablebase.get_max_pieces(), 5);
+
+        for (fen, wdl) in &[
+            ("8/8/8/8/8/2k5/8/KQ6 w - - 0 1", Wdl::Win),
+            ("8/8/8/8/8/2k5/8/KQ6 b - - 0 1", Wdl::Loss),
+            ("8/8/8/8/4k3/8/8/4K2R b - - 0 1", Wdl::Loss),
+            ("4k3/8/4K3/4P3/8/8/8/8 b - - 0 1", Wdl::Loss),
+            ("4k3/8/4K3/4P3/8/8/8/8 w - - 0 1", Wdl::Draw),
+            ("8/8/8/8/8/2k5/8/K1n5 w - - 0 1", Wdl::Draw),
+            ("8/8/8/8/5k2/8/8/KBN5 w - - 0 1", Wdl::Win),
+            ("8/8/8/3k4/8/8/8/KQ3r2 w - - 0 1", Wdl::Win),
+            ("8/8/8/3k4/8/8/8/KR3r2 b - - 0 1", Wdl::Draw),
+            ("8/8/8/8/8/8/2k5/K6r w - - 0 1", Wdl::Loss),
+        ] {
+            let board = parse_fen(fen).unwrap();
+            assert_eq!(tablebase.probe_wdl(&board), Some(*wdl), "{}", fen);
+            assert_eq!(
+                tablebase.probe_wdl(&board.flip_colors()),
+                Some(*wdl),
+                "{}",
+                fen
+            );
+
+            let dtz = tablebase.probe_dtz(&board).unwrap();
+            assert_eq!(dtz.signum(), wdl.get_sign(), "{}", fen);
+        }
+
+        // Mate in one
+        let board = parse_fen("k7/8/1K6/8/8/8/8/7Q w - - 0 1").unwrap();
+        let m = tablebase.get_best_move(&board).unwrap();
+        assert!(board.apply_eval_move(m).is_checkmate(), "{}", m.to_string());
+
+        // Converting the win makes progress every move
+        let mut board = parse_fen("8/8/8/8/4k3/8/8/4K2Q w - - 0 1").unwrap();
+        let mut dtz = tablebase.probe_dtz(&board).unwrap();
+        while !board.is_checkmate() {
+            let evaluator = TablebaseEvaluator::new(board, &tablebase);
+            let (m, _, _) = evaluator.get_best_next_move(2);
+            board = board.apply_eval_move(m);
+            let (reply, _, _) = TablebaseEvaluator::new(board, &tablebase).get_best_next_move(2);
+            if reply == Move::Resign {
+                break;
+            }
+            board = board.apply_eval_move(reply);
+            let next_dtz = tablebase.probe_dtz(&board).unwrap();
+            assert!(next_dtz < dtz);
+            dtz = next_dtz;
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use super::{Move, SearchMonitor, SearchProgress};
+use core::time::Duration;
+
+/// The time kept back on every move for the time it takes to
+/// actually play the move, such as sending it over a network.
+const MOVE_OVERHEAD: Duration = Duration::from_millis(30);
+
+/// The number of moves the rest of the game is expected to take
+/// when the time control does not say.
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+/// How much the rating of the best move has to drop from one depth
+/// to the next to spend more time on the move. This is half a pawn.
+const SCORE_DROP: f64 = 5.0;
+
+/// How much better the best move has to be than every other move
+/// to play it without using all of the time. This is three pawns.
+const FORCED_MARGIN: f64 = 30.0;
+
+/// How long a search may take for one move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeLimits {
+    soft: Duration,
+    hard: Duration,
+}
+
+impl TimeLimits {
+    /// Get the time after which the search should not start
+    /// searching another depth.
+    #[inline]
+    pub fn get_soft(&self) -> Duration {
+        self.soft
+    }
+
+    /// Get the time after which the search has to stop right away.
+    #[inline]
+    pub fn get_hard(&self) -> Duration {
+        self.hard
+    }
+}
+
+/// Decides how long to think about a move in a game with a clock.
+///
+/// The time manager starts with limits based on the time left, and
+/// is updated after every depth of the search. It gives the search
+/// more time when the best move keeps changing or its rating drops,
+/// and less time when the best move is clearly forced.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeManager {
+    base: TimeLimits,
+    limits: TimeLimits,
+    best_move: Option<Move>,
+    best_move_value: Option<f64>,
+    /// How often the best move changed recently, with older
+    /// changes counting for less.
+    instability: f64,
+}
+
+impl TimeManager {
+    /// Create a time manager for a move, given the time the player has
+    /// left, the time they get back for the move, and the number of
+    /// moves they have to make before they are given more time.
+    ///
+    /// Without a number of moves to go, the rest of the game is
+    /// assumed to take a fixed number of moves.
+    pub fn new(remaining: Duration, increment: Duration, moves_to_go: Option<u32>) -> Self {
+        let available = remaining.saturating_sub(MOVE_OVERHEAD);
+        let moves_to_go = moves_to_go.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+
+        // Never spend more than half the time on one move, unless
+        // more time is given after this one.
+        let hard_cap = if moves_to_go == 1 {
+            available
+        } else {
+            available / 2
+        };
+        let soft = available / moves_to_go + increment * 3 / 4;
+        let hard = (soft * 4).min(hard_cap);
+        let base = TimeLimits {
+            soft: soft.min(hard),
+            hard,
+        };
+
+        Self {
+            base,
+            limits: base,
+            best_move: None,
+            best_move_value: None,
+            instability: 0.0,
+        }
+    }
+
+    /// Get the limits for the search, as of the last update.
+    #[inline]
+    pub fn get_limits(&self) -> TimeLimits {
+        self.limits
+    }
+
+    /// Update the limits after the search finishes another depth.
+    pub fn update(&mut self, progress: &SearchProgress) {
+        let line = progress.get_line();
+        let has_changed = self.best_move.is_some_and(|m| m != line.get_move());
+        self.instability = self.instability / 2.0 + if has_changed { 1.0 } else { 0.0 };
+        let has_dropped = self
+            .best_move_value
+            .is_some_and(|value| value - line.get_value() > SCORE_DROP);
+        self.best_move = Some(line.get_move());
+        self.best_move_value = Some(line.get_value());
+
+        let mut scale = 1.0 + self.instability;
+        if has_dropped {
+            scale *= 1.5;
+        }
+        match progress.get_margin() {
+            // There is only one legal move
+            None => scale = 0.0,
+            Some(margin) if margin >= FORCED_MARGIN && progress.get_depth() > 0 => {
+                scale = scale.min(0.25)
+            }
+            _ => {}
+        }
+
+        self.limits.soft = self.base.soft.mul_f64(scale).min(self.base.hard);
+    }
+}
+
+/// A monitor that stops a search when a time manager says so.
+///
+/// The time is read with a function that returns the time on a
+/// monotonic clock supplied by the caller, so this works without
+/// the standard library.
+pub struct TimedMonitor<F> {
+    manager: TimeManager,
+    now: F,
+    start: Option<Duration>,
+    is_out_of_time: bool,
+}
+
+impl<F: FnMut() -> Duration> TimedMonitor<F> {
+    /// Create a monitor for a time manager and a clock.
+    pub fn new(manager: TimeManager, now: F) -> Self {
+        Self {
+            manager,
+            now,
+            start: None,
+            is_out_of_time: false,
+        }
+    }
+
+    /// Get the time manager of this monitor.
+    #[inline]
+    pub fn get_manager(&self) -> &TimeManager {
+        &self.manager
+    }
+
+    /// Get the time spent on the search since it started.
+    fn get_elapsed(&mut self) -> Option<Duration> {
+        let start = self.start?;
+        Some((self.now)().saturating_sub(start))
+    }
+}
+
+impl<F: FnMut() -> Duration> SearchMonitor for TimedMonitor<F> {
+    fn start(&mut self) {
+        self.start = Some((self.now)());
+    }
+
+    fn should_stop(&mut self) -> bool {
+        let hard = self.manager.get_limits().get_hard();
+        self.is_out_of_time || self.get_elapsed().is_some_and(|elapsed| elapsed >= hard)
+    }
+
+    fn report(&mut self, progress: &SearchProgress) {
+        self.manager.update(progress);
+        let soft = self.manager.get_limits().get_soft();
+        if self.get_elapsed().is_some_and(|elapsed| elapsed >= soft) {
+            self.is_out_of_time = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_fen;
+    use crate::{Board, Evaluate};
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_limits() {
+        let limits = TimeManager::new(secs(300), secs(0), None).get_limits();
+        assert!(limits.get_soft() > secs(5) && limits.get_soft() < secs(15));
+        assert!(limits.get_hard() > limits.get_soft());
+
+        // The increment can be spent on every move
+        let with_increment = TimeManager::new(secs(300), secs(10), None).get_limits();
+        assert!(with_increment.get_soft() > limits.get_soft() + secs(5));
+
+        // The last move before the next period can use everything
+        let limits = TimeManager::new(secs(60), secs(0), Some(1)).get_limits();
+        assert!(limits.get_hard() > secs(59) && limits.get_hard() < secs(60));
+
+        // Never more than half the time
+        let limits = TimeManager::new(secs(2), secs(30), None).get_limits();
+        assert!(limits.get_hard() < secs(1));
+        assert!(limits.get_soft() <= limits.get_hard());
+
+        let limits = TimeManager::new(Duration::from_millis(10), secs(0), None).get_limits();
+        assert_eq!(limits.get_hard(), secs(0));
+    }
+
+    fn get_reports(board: &Board, depth: i32) -> Vec<SearchProgress> {
+        struct Reports(Vec<SearchProgress>);
+        impl SearchMonitor for Reports {
+            fn report(&mut self, progress: &SearchProgress) {
+                self.0.push(progress.clone());
+            }
+        }
+
+        let mut reports = Reports(vec![]);
+        board.get_best_next_move_with(depth, &mut reports);
+        reports.0
+    }
+
+    #[test]
+    fn test_updates() {
+        let mut manager = TimeManager::new(secs(300), secs(0), None);
+        let base = manager.get_limits();
+        let progress = get_reports(&Board::default(), 0);
+        manager.update(&progress[0]);
+        assert_eq!(manager.get_limits().get_hard(), base.get_hard());
+        assert!(manager.get_limits().get_soft() >= base.get_soft() - Duration::from_millis(1));
+
+        // A dropping score takes more time
+        let board = parse_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+        let winning =
+            parse_fen("rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+        let mut manager = TimeManager::new(secs(300), secs(0), None);
+        manager.update(&get_reports(&winning, 0)[0]);
+        manager.update(&get_reports(&board, 0)[0]);
+        assert!(manager.get_limits().get_soft() > base.get_soft());
+        assert_eq!(manager.get_limits().get_hard(), base.get_hard());
+
+        // A capture that has to be made takes less time
+        let board = parse_fen("4k3/8/8/8/8/3q4/3Q4/4K3 w - - 0 1").unwrap();
+        let mut manager = TimeManager::new(secs(300), secs(0), None);
+        for progress in get_reports(&board, 1) {
+            manager.update(&progress);
+        }
+        assert!(manager.get_limits().get_soft() < base.get_soft());
+
+        // A single legal move is played right away
+        let board = parse_fen("7k/8/8/8/8/8/6q1/K7 w - - 0 1").unwrap();
+        let now = Cell::new(secs(0));
+        let mut monitor = TimedMonitor::new(TimeManager::new(secs(300), secs(0), None), || {
+            now.set(now.get() + Duration::from_millis(1));
+            now.get()
+        });
+        let (m, _, _) = board.get_best_next_move_with(10, &mut monitor);
+        assert_eq!(m, Move::Piece(crate::A1, crate::B1));
+        assert_eq!(monitor.get_manager().get_limits().get_soft(), secs(0));
+    }
+
+    #[test]
+    fn test_timed_search() {
+        // Every call to the clock takes a second
+        let board = Board::default();
+        let now = Cell::new(secs(0));
+        let mut monitor = TimedMonitor::new(TimeManager::new(secs(60), secs(0), None), || {
+            now.set(now.get() + secs(1));
+            now.get()
+        });
+        let (m, _, _) = board.get_best_next_move_with(20, &mut monitor);
+        assert!(board.get_legal_moves().contains(&m));
+        assert!(now.get() < secs(12));
+    }
+}
@@ -0,0 +1,305 @@
+use super::{Board, EvalParams, WHITE};
+use crate::parse_fen;
+use alloc::{string::String, vec::Vec};
+use core::f64::consts::{LN_10, LN_2};
+
+/// A position from a game, along with how the game ended.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TuningPosition {
+    board: Board,
+    result: f64,
+}
+
+impl TuningPosition {
+    /// Create a tuning position from a board and the result of
+    /// the game for White: 1.0 for a win, 0.5 for a draw, and
+    /// 0.0 for a loss.
+    pub fn new(board: Board, result: f64) -> Self {
+        Self { board, result }
+    }
+
+    /// Get the board of the position.
+    #[inline]
+    pub fn get_board(&self) -> Board {
+        self.board
+    }
+
+    /// Get the result of the game for White.
+    #[inline]
+    pub fn get_result(&self) -> f64 {
+        self.result
+    }
+}
+
+/// Parse a list of tuning positions, one per line.
+///
+/// Each line is a FEN string followed by the result of the game,
+/// such as `1-0`, `[0.5]`, or `c9 "0-1";` as in the EPD files that
+/// tuning positions are usually shared in. Blank lines and lines
+/// starting with `#` are skipped.
+///
+/// Boards are parsed as standard chess (or Crazyhouse, if they have
+/// a pocket). To tune a variant, set the variant of each board with
+/// a `BoardBuilder` and create the positions with `TuningPosition::new`.
+pub fn parse_tuning_positions(text: &str) -> Result<Vec<TuningPosition>, String> {
+    let mut positions = vec![];
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields: Vec<&str> = line
+            .split_ascii_whitespace()
+            .map(|field| field.trim_end_matches(';'))
+            .filter(|field| !field.is_empty())
+            .collect();
+        let result = match fields.pop() {
+            Some(result) => parse_result(result)
+                .ok_or_else(|| format!("line {}: unknown result `{}`", number + 1, result))?,
+            None => continue,
+        };
+        if fields.last() == Some(&"c9") {
+            fields.pop();
+        }
+
+        let board =
+            parse_fen(&fields.join(" ")).map_err(|e| format!("line {}: {}", number + 1, e))?;
+        positions.push(TuningPosition::new(board, result));
+    }
+    Ok(positions)
+}
+
+/// Parse the result of a game for White.
+fn parse_result(result: &str) -> Option<f64> {
+    match result.trim_matches(|c| c == '"' || c == '[' || c == ']') {
+        "1-0" | "1" | "1.0" => Some(1.0),
+        "0-1" | "0" | "0.0" => Some(0.0),
+        "1/2-1/2" | "1/2" | "0.5" => Some(0.5),
+        _ => None,
+    }
+}
+
+/// Tunes evaluation parameters to predict the results of games.
+///
+/// This is Texel's tuning method: the evaluation of every position
+/// is turned into an expected result with a logistic curve, and
+/// each parameter is nudged up or down for as long as it lowers
+/// the mean squared error against the real results. Term weights
+/// are left alone, since the parameters of a term already scale it.
+#[derive(Clone, Debug)]
+pub struct Tuner {
+    positions: Vec<TuningPosition>,
+    params: EvalParams,
+    scaling: f64,
+    step: f64,
+}
+
+impl Tuner {
+    /// Create a tuner for a set of positions, starting from some parameters.
+    pub fn new(positions: Vec<TuningPosition>, params: EvalParams) -> Self {
+        Self {
+            positions,
+            params,
+            scaling: 1.0,
+            step: 0.125,
+        }
+    }
+
+    /// Get the best parameters found so far.
+    #[inline]
+    pub fn get_params(&self) -> &EvalParams {
+        &self.params
+    }
+
+    /// Get the scaling constant of the logistic curve, which controls
+    /// how sure a given evaluation is of winning the game.
+    #[inline]
+    pub fn get_scaling(&self) -> f64 {
+        self.scaling
+    }
+
+    /// Set the amount each parameter is changed by in a single step.
+    #[inline]
+    pub fn set_step(&mut self, step: f64) {
+        self.step = step;
+    }
+
+    /// Get the mean squared error of the current parameters.
+    pub fn get_error(&self) -> f64 {
+        self.get_error_with(&self.params, self.scaling)
+    }
+
+    /// Find the scaling constant that best fits the current parameters,
+    /// which should be done before tuning them.
+    ///
+    /// This returns the error with the new scaling constant.
+    pub fn tune_scaling(&mut self) -> f64 {
+        let mut best = self.get_error();
+        let mut step = 0.5;
+        while step > 0.001 {
+            let mut improved = false;
+            for scaling in [self.scaling + step, self.scaling - step] {
+                if scaling <= 0.0 {
+                    continue;
+                }
+
+                let error = self.get_error_with(&self.params, scaling);
+                if error < best {
+                    best = error;
+                    self.scaling = scaling;
+                    improved = true;
+                    break;
+                }
+            }
+            if !improved {
+                step /= 2.0;
+            }
+        }
+        best
+    }
+
+    /// Tune the parameters for at most a given number of passes over
+    /// all of them, stopping early when no change improves them.
+    ///
+    /// This returns the error of the tuned parameters.
+    pub fn tune(&mut self, passes: usize) -> f64 {
+        let mut best = self.get_error();
+        let count = self.params.get_tunable_values_mut().count();
+        for _ in 0..passes {
+            let mut improved = false;
+            for i in 0..count {
+                for delta in [self.step, -self.step] {
+                    let mut params = self.params;
+                    if let Some(value) = params.get_tunable_value_mut(i) {
+                        *value += delta;
+                    }
+
+                    let error = self.get_error_with(&params, self.scaling);
+                    if error < best {
+                        best = error;
+                        self.params = params;
+                        improved = true;
+                        break;
+                    }
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Get the mean squared error of some parameters.
+    fn get_error_with(&self, params: &EvalParams, scaling: f64) -> f64 {
+        if self.positions.is_empty() {
+            return 0.0;
+        }
+
+        let mut total = 0.0;
+        for position in &self.positions {
+            let board = position.get_board();
+            let mut value = params.evaluate(&board, WHITE);
+            if board.get_variant().has_forced_captures() {
+                // Losing material is the goal in Antichess
+                value = -value;
+            }
+
+            let error = position.get_result() - get_expected_result(value, scaling);
+            total += error * error;
+        }
+        total / self.positions.len() as f64
+    }
+}
+
+/// Get the expected result of a game for White from an evaluation.
+///
+/// A pawn is worth 10 in the evaluation, so with a scaling constant
+/// of 1.0 this is the usual curve over centipawns, `1 / (1 + 10^(-cp / 400))`.
+fn get_expected_result(value: f64, scaling: f64) -> f64 {
+    1.0 / (1.0 + exp(-scaling * value * LN_10 / 40.0))
+}
+
+/// Raise e to a power, since `f64::exp` is not available without `std`.
+fn exp(x: f64) -> f64 {
+    let x = x.clamp(-700.0, 700.0);
+    // Split x into n * ln(2) + r, where r is small enough
+    // for the Taylor series of e^r to converge quickly
+    let n = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+    let r = x - n as f64 * LN_2;
+
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for i in 1..20 {
+        term *= r / i as f64;
+        sum += term;
+    }
+    sum * f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_EVAL_PARAMS;
+
+    #[test]
+    fn test_parse_tuning_positions() {
+        let positions = parse_tuning_positions(
+            "# some positions
+            rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1 [0.5]
+            4k3/8/8/8/8/8/8/3QK3 w - - c9 \"1-0\";
+
+            4k3/8/8/8/8/8/8/3qK3 w - - 0-1",
+        )
+        .unwrap();
+        let results: Vec<f64> = positions.iter().map(|p| p.get_result()).collect();
+        assert_eq!(results, vec![0.5, 1.0, 0.0]);
+        assert_eq!(
+            positions[1].get_board(),
+            parse_fen("4k3/8/8/8/8/8/8/3QK3 w - -").unwrap()
+        );
+
+        assert!(parse_tuning_positions("4k3/8/8/8/8/8/8/3QK3 w - - 2-0").is_err());
+        assert!(parse_tuning_positions("4k3/8/8/8/8/8/8/3QK3 w - 1-0").is_err());
+    }
+
+    #[test]
+    fn test_expected_result() {
+        assert_eq!(get_expected_result(0.0, 1.0), 0.5);
+        // Four pawns up is about a 91% chance of winning
+        assert!((get_expected_result(40.0, 1.0) - 10.0 / 11.0).abs() < 1e-12);
+        assert!((get_expected_result(-40.0, 1.0) - 1.0 / 11.0).abs() < 1e-12);
+        assert!(get_expected_result(1e6, 1.0) <= 1.0);
+    }
+
+    #[test]
+    fn test_tuner() {
+        // White wins every game with an extra knight, and draws
+        // the ones with an extra pawn
+        let positions = parse_tuning_positions(
+            "4k3/p7/8/8/8/8/P7/3NK3 w - - 1-0
+            4k3/p7/8/8/8/8/P7/2N1K3 b - - 1-0
+            4k3/p7/8/8/8/8/P3P3/4K3 w - - 1/2-1/2
+            4k3/p7/8/8/8/3P4/P7/4K3 b - - 1/2-1/2",
+        )
+        .unwrap();
+
+        let mut tuner = Tuner::new(positions, DEFAULT_EVAL_PARAMS);
+        let initial = tuner.get_error();
+        let scaled = tuner.tune_scaling();
+        assert!(scaled <= initial);
+        tuner.set_step(1.0);
+        let tuned = tuner.tune(3);
+        assert!(tuned < scaled);
+        assert_eq!(tuned, tuner.get_error());
+
+        // The knight became more valuable than the pawn
+        let params = tuner.get_params();
+        let advantage = |params: &EvalParams| {
+            params.material[4].get_endgame() - params.material[5].get_endgame()
+        };
+        assert!(advantage(params) > advantage(&DEFAULT_EVAL_PARAMS));
+        assert_eq!(params.term_weights, DEFAULT_EVAL_PARAMS.term_weights);
+    }
+}
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use crate::board::{Board, BoardBuilder};
 use crate::piece::Piece;
 use crate::position::Position;
-use crate::{Color, Evaluate, Move};
+use crate::{Color, Evaluate, Move, Variant};
 
 // generate FEN
 // cannot calculate halfmove or fullmove based on board state,
@@ -43,6 +43,11 @@ pub fn format_fen(
                         Color::White => ch.to_uppercase(),
                         Color::Black => ch,
                     });
+                    if board.get_variant() == Variant::Crazyhouse
+                        && board.is_promoted(piece.get_pos())
+                    {
+                        fen.push("~".to_string());
+                    }
                 }
             };
         }
@@ -55,6 +60,30 @@ pub fn format_fen(
         }
     }
 
+    // crazyhouse pockets
+    if board.get_variant() == Variant::Crazyhouse {
+        fen.push("[".to_string());
+        for color in &[Color::White, Color::Black] {
+            let pocket = board.get_pocket(*color);
+            for piece in pocket.get_pieces(*color) {
+                let ch = match piece {
+                    Piece::Queen(_, _) => "q",
+                    Piece::Rook(_, _) => "r",
+                    Piece::Bishop(_, _) => "b",
+                    Piece::Knight(_, _) => "n",
+                    _ => "p",
+                };
+                for _ in 0..pocket.count(piece) {
+                    fen.push(match color {
+                        Color::White => ch.to_uppercase(),
+                        Color::Black => ch.to_string(),
+                    });
+                }
+            }
+        }
+        fen.push("]".to_string());
+    }
+
     // active color
     fen.push(" ".to_string());
     fen.push(
@@ -115,6 +144,10 @@ pub fn format_fen(
 
 // parse Forsyth–Edwards Notation (FEN) board state
 // more direct than replaying every move from PGN
+//
+// crazyhouse positions are recognized by the pocket after the
+// placement (e.g. "...RNBQKBNR[Qp] w ..."), with promoted pieces
+// marked by a "~" after the piece
 pub fn parse_fen(fen: &str) -> Result<Board, String> {
     let mut parts = fen.split_ascii_whitespace();
     // fen has six parts
@@ -140,10 +173,40 @@ pub fn parse_fen(fen: &str) -> Result<Board, String> {
 
     let mut builder = BoardBuilder::default();
 
+    // split off crazyhouse pocket
+    let mut placement = placement.unwrap();
+    if let Some(start) = placement.find('[') {
+        if !placement.ends_with(']') {
+            return Err(String::from("unterminated pocket"));
+        }
+        let pocket = &placement[start + 1..placement.len() - 1];
+        placement = &placement[..start];
+        builder = builder.set_variant(Variant::Crazyhouse);
+
+        let offboard = Position::new(-1, -1);
+        for c in pocket.chars() {
+            let color = match c.is_uppercase() {
+                true => Color::White,
+                false => Color::Black,
+            };
+            builder = builder.pocket(match c.to_ascii_lowercase() {
+                'q' => Piece::Queen(color, offboard),
+                'r' => Piece::Rook(color, offboard),
+                'b' => Piece::Bishop(color, offboard),
+                'n' => Piece::Knight(color, offboard),
+                'p' => Piece::Pawn(color, offboard),
+                _ => {
+                    return Err(String::from("unexpected pocket piece"));
+                }
+            });
+        }
+    }
+
     // parse placement (from white's perspective)
     let mut row: i32 = 7;
     let mut col: i32 = 0;
-    for c in placement.unwrap().chars() {
+    let mut last_piece = None;
+    for c in placement.chars() {
         if (col > 7 && c != '/') || row < 0 {
             return Err(String::from("too many pieces"));
         }
@@ -167,8 +230,16 @@ pub fn parse_fen(fen: &str) -> Result<Board, String> {
                     }
                 };
                 builder = builder.piece(piece);
+                last_piece = Some(piece);
                 col += 1;
             }
+            '~' => match last_piece {
+                // promoted piece in crazyhouse
+                Some(piece) => builder = builder.promoted_piece(piece),
+                None => {
+                    return Err(String::from("unexpected promotion marker"));
+                }
+            },
             x if x.is_numeric() => {
                 // skip squares
                 let skip = x.to_digit(10).unwrap();
@@ -243,6 +314,32 @@ pub fn parse_fen(fen: &str) -> Result<Board, String> {
 // returns Err(InvalidMove) if there is a parse error or the move is
 // not valid based on the current board position and turn.
 pub fn parse_san_move(board: &Board, move_str: &str) -> Result<Move, String> {
+    // crazyhouse drop, such as "N@e4" or "@e4" for a pawn
+    if let Some(at) = move_str.find('@') {
+        let color = board.get_turn_color();
+        let offboard = Position::new(-1, -1);
+        let piece = match &move_str[..at] {
+            "" | "P" => Piece::Pawn(color, offboard),
+            "N" => Piece::Knight(color, offboard),
+            "B" => Piece::Bishop(color, offboard),
+            "R" => Piece::Rook(color, offboard),
+            "Q" => Piece::Queen(color, offboard),
+            _ => {
+                return Err("invalid drop piece".to_string());
+            }
+        };
+        let drop = match Position::pgn(&move_str[at + 1..]) {
+            Ok(position) => Move::Drop(piece, position),
+            Err(_) => {
+                return Err("invalid to position".to_string());
+            }
+        };
+        return match board.get_legal_moves().contains(&drop) {
+            true => Ok(drop),
+            false => Err("no matching move".to_string()),
+        };
+    }
+
     if move_str == "0-0" {
         return Ok(Move::KingSideCastle {});
     } else if move_str == "0-0-0" {
@@ -333,7 +430,7 @@ pub fn parse_san_move(board: &Board, move_str: &str) -> Result<Move, String> {
     // find moves that end on target square and are correct piece type
     let mut candidates = vec![];
     for legal_move in board.get_legal_moves() {
-        if let Move::Piece(from, to) = legal_move {
+        if let Move::Piece(from, to) | Move::Promotion(from, to, _) = legal_move {
             if move_to == to {
                 if let Some(board_piece) = board.get_piece(from) {
                     // filter based on type
@@ -341,6 +438,8 @@ pub fn parse_san_move(board: &Board, move_str: &str) -> Result<Move, String> {
                     if board_piece.get_name() == piece.get_name()
                         && (column.is_none() || column == Some(pos.get_col()))
                         && (row.is_none() || row == Some(pos.get_row()))
+                        // each promotion is a separate move from the same piece
+                        && !candidates.contains(&board_piece)
                     {
                         candidates.push(board_piece);
                     }
@@ -409,6 +508,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_crazyhouse_fen() {
+        let fen = "rnbqkbQ~1/ppppp2p/5n2/8/8/8/PPPPPPP1/RNBQKBNR[PPp] b KQq - 0 5";
+        let board = parse_fen(fen).unwrap();
+        assert_eq!(board.get_variant(), Variant::Crazyhouse);
+        assert!(board.is_promoted(G8));
+        assert!(!board.is_promoted(H8));
+        assert_eq!(format_fen(&board, 0, 5).unwrap(), String::from(fen));
+
+        assert_eq!(
+            parse_san_move(&board, "@e5").expect("@e5"),
+            Move::Drop(Piece::Pawn(Color::Black, Position::new(-1, -1)), E5)
+        );
+        assert_eq!(
+            parse_san_move(&board, "N@e5").expect_err("N@e5"),
+            "no matching move".to_string()
+        );
+    }
+
     #[test]
     fn test_parse_san_move() {
         let mut board = Board::default();
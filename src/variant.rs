@@ -0,0 +1,616 @@
+use super::endgame::can_checkmate;
+use super::*;
+
+/// The squares in the center of the board that a king must reach
+/// to win a game of King of the Hill.
+const HILL: [Position; 4] = [D4, E4, D5, E5];
+
+/// The number of checks a player must give to win a game of Three-check.
+pub const CHECKS_TO_WIN: u8 = 3;
+
+/// The set of rules a game is played under.
+///
+/// A variant can override the starting position, the conditions
+/// that end the game, and which moves are legal. Every `Board` keeps
+/// track of the variant it is played under, so move generation, search,
+/// and `Board::play_move` all respect the variant's rules.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Variant {
+    /// Regular chess.
+    #[default]
+    Standard,
+    /// White starts with 36 pawns and no king, and Black wins by capturing
+    /// every single one of White's pieces. White wins by checkmate as usual.
+    Horde,
+    /// Regular chess, except that a player also wins by moving their king
+    /// to one of the four center squares (D4, E4, D5, or E5).
+    KingOfTheHill,
+    /// Regular chess, except that a player also wins by putting
+    /// their opponent in check three times.
+    ThreeCheck,
+    /// Both players race their kings to the eighth rank. Nobody is ever
+    /// allowed to give check, and if White reaches the eighth rank first,
+    /// Black gets one last move to reach it as well for a draw.
+    RacingKings,
+    /// Captures explode, removing the capturing piece, the captured piece,
+    /// and every piece surrounding them that is not a pawn. A player wins
+    /// by exploding the enemy king. Kings cannot capture, and kings that
+    /// are touching each other cannot be checked.
+    Atomic,
+    /// Captures are compulsory, the king is an ordinary piece that
+    /// can be captured, and pawns may promote to kings. A player wins
+    /// by losing all of their pieces, or by having no legal moves.
+    Antichess,
+    /// Captured pieces go into the capturing player's pocket, and
+    /// instead of moving, a player may drop a piece from their pocket
+    /// onto any empty square. Pawns cannot be dropped on the first or
+    /// last rank, and promoted pieces revert to pawns when captured.
+    Crazyhouse,
+}
+
+impl core::fmt::Display for Variant {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// Try to parse a Variant from its name, such as `"horde"`
+/// or `"king of the hill"`.
+impl TryFrom<&str> for Variant {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        match name.trim().to_lowercase().as_str() {
+            "standard" | "chess" => Ok(Self::Standard),
+            "horde" => Ok(Self::Horde),
+            "king of the hill" | "kingofthehill" | "koth" => Ok(Self::KingOfTheHill),
+            "three-check" | "threecheck" | "3check" => Ok(Self::ThreeCheck),
+            "racing kings" | "racingkings" => Ok(Self::RacingKings),
+            "atomic" => Ok(Self::Atomic),
+            "antichess" | "giveaway" | "losing chess" => Ok(Self::Antichess),
+            "crazyhouse" | "zh" => Ok(Self::Crazyhouse),
+            other => Err(format!("unknown variant `{}`", other)),
+        }
+    }
+}
+
+impl Variant {
+    /// Get the name of the variant such as `"horde"` or `"three-check"`.
+    /// All names are lowercase.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Horde => "horde",
+            Self::KingOfTheHill => "king of the hill",
+            Self::ThreeCheck => "three-check",
+            Self::RacingKings => "racing kings",
+            Self::Atomic => "atomic",
+            Self::Antichess => "antichess",
+            Self::Crazyhouse => "crazyhouse",
+        }
+    }
+
+    /// Get the board that a game of this variant starts with.
+    pub fn starting_board(&self) -> Board {
+        let board = match self {
+            Self::Standard
+            | Self::KingOfTheHill
+            | Self::ThreeCheck
+            | Self::Atomic
+            | Self::Crazyhouse => Board::default(),
+            Self::Antichess => BoardBuilder::from(Board::default())
+                .disable_castling()
+                .build(),
+            Self::Horde => BoardBuilder::from(Board::default())
+                .row(Piece::Pawn(WHITE, A1))
+                .row(Piece::Pawn(WHITE, A2))
+                .row(Piece::Pawn(WHITE, A3))
+                .row(Piece::Pawn(WHITE, A4))
+                .piece(Piece::Pawn(WHITE, F5))
+                .piece(Piece::Pawn(WHITE, G5))
+                .piece(Piece::Pawn(WHITE, B5))
+                .piece(Piece::Pawn(WHITE, C5))
+                .disable_queenside_castle(WHITE)
+                .disable_kingside_castle(WHITE)
+                .build(),
+            Self::RacingKings => BoardBuilder::default()
+                .piece(Piece::King(BLACK, A2))
+                .piece(Piece::Rook(BLACK, B2))
+                .piece(Piece::Bishop(BLACK, C2))
+                .piece(Piece::Knight(BLACK, D2))
+                .piece(Piece::Queen(BLACK, A1))
+                .piece(Piece::Rook(BLACK, B1))
+                .piece(Piece::Bishop(BLACK, C1))
+                .piece(Piece::Knight(BLACK, D1))
+                .piece(Piece::Knight(WHITE, E2))
+                .piece(Piece::Bishop(WHITE, F2))
+                .piece(Piece::Rook(WHITE, G2))
+                .piece(Piece::King(WHITE, H2))
+                .piece(Piece::Knight(WHITE, E1))
+                .piece(Piece::Bishop(WHITE, F1))
+                .piece(Piece::Rook(WHITE, G1))
+                .piece(Piece::Queen(WHITE, H1))
+                .build(),
+        };
+
+        BoardBuilder::from(board).set_variant(*self).build()
+    }
+
+    /// Can this pawn move two squares forward?
+    ///
+    /// In Horde, White's pawns on the first rank may also move two squares.
+    #[inline]
+    pub(crate) fn is_starting_pawn(&self, piece: Piece) -> bool {
+        piece.is_starting_pawn()
+            || (*self == Self::Horde
+                && piece.is_pawn()
+                && piece.get_color() == WHITE
+                && piece.get_pos().get_row() == 0)
+    }
+
+    /// Are captures compulsory in this variant?
+    #[inline]
+    pub(crate) fn has_forced_captures(&self) -> bool {
+        *self == Self::Antichess
+    }
+
+    /// Can pieces be dropped from a pocket onto the board in this variant?
+    #[inline]
+    pub(crate) fn allows_drops(&self) -> bool {
+        *self == Self::Crazyhouse
+    }
+
+    /// Can pawns promote to kings in this variant?
+    #[inline]
+    pub(crate) fn allows_king_promotion(&self) -> bool {
+        *self == Self::Antichess
+    }
+
+    /// Is a move that follows the movement rules of its piece
+    /// legal under this variant?
+    ///
+    /// For most variants, this just verifies that the move does
+    /// not leave the player's own king in check.
+    pub(crate) fn allows_move(&self, board: &Board, m: Move, player_color: Color) -> bool {
+        if m == Move::Resign {
+            return true;
+        }
+
+        match self {
+            // Nobody can give check in Racing Kings.
+            Self::RacingKings => {
+                let next = board.apply_move(m);
+                !next.is_in_check(player_color) && !next.is_in_check(!player_color)
+            }
+            Self::Atomic => {
+                if let Move::Piece(from, _) | Move::Promotion(from, _, _) = m {
+                    let is_king = match board.get_piece(from) {
+                        Some(piece) => piece.is_king(),
+                        None => false,
+                    };
+                    // Kings cannot capture in Atomic.
+                    if is_king && board.is_capture(m) {
+                        return false;
+                    }
+                }
+
+                let next = board.apply_move(m);
+                if next.get_king_pos(player_color).is_none() {
+                    // The player cannot explode their own king.
+                    false
+                } else if next.get_king_pos(!player_color).is_none() {
+                    // Exploding the enemy king wins, even if in check.
+                    true
+                } else {
+                    !next.is_in_check(player_color)
+                }
+            }
+            // The king can be left en prise in Antichess.
+            Self::Antichess => true,
+            _ => !board.apply_move(m).is_in_check(player_color),
+        }
+    }
+
+    /// Is a move legal whenever it does not leave the player's own king in check?
+    ///
+    /// Variants with other rules about the king, such as Atomic, have
+    /// to try every move instead of checking for pins.
+    pub(crate) fn has_standard_king_safety(&self) -> bool {
+        !matches!(self, Self::RacingKings | Self::Atomic | Self::Antichess)
+    }
+
+    /// Does the respective player have sufficient material to win
+    /// under the rules of this variant?
+    pub(crate) fn has_sufficient_material(&self, board: &Board, color: Color) -> bool {
+        match self {
+            Self::Standard => can_checkmate(board, color),
+            // A lone king can still walk to the hill or to the eighth rank.
+            Self::KingOfTheHill | Self::RacingKings => board.get_king_pos(color).is_some(),
+            // Any piece besides the king is capable of giving check,
+            // or of exploding the enemy king.
+            Self::ThreeCheck | Self::Atomic => board.get_material_count(color) > 1,
+            // A lone black king can still capture every pawn of the horde,
+            // and a lone antichess piece can still be given away.
+            Self::Horde | Self::Antichess => board.get_material_count(color) > 0,
+            // Captured pieces can always be dropped back onto the board.
+            Self::Crazyhouse => {
+                board.get_material_count(color) > 1 || !board.get_pocket(color).is_empty()
+            }
+        }
+    }
+
+    /// Has the game ended under the variant specific rules?
+    ///
+    /// This does not consider checkmate or stalemate, which are the same
+    /// for every variant, and are handled by the board itself.
+    pub(crate) fn get_result(&self, board: &Board) -> Option<GameResult> {
+        match self {
+            Self::Standard | Self::Crazyhouse => None,
+            Self::Atomic => match (board.get_king_pos(WHITE), board.get_king_pos(BLACK)) {
+                (Some(_), None) => Some(GameResult::Victory(WHITE, WinReason::Variant)),
+                (None, Some(_)) => Some(GameResult::Victory(BLACK, WinReason::Variant)),
+                _ => None,
+            },
+            Self::Antichess => {
+                let color = board.get_turn_color();
+                if board.get_material_count(!color) == 0 {
+                    Some(GameResult::Victory(!color, WinReason::Variant))
+                } else if board.get_material_count(color) == 0 || !board.has_legal_moves() {
+                    Some(GameResult::Victory(color, WinReason::Variant))
+                } else {
+                    None
+                }
+            }
+            Self::Horde => {
+                if board.get_material_count(WHITE) == 0 {
+                    Some(GameResult::Victory(BLACK, WinReason::Variant))
+                } else {
+                    None
+                }
+            }
+            Self::KingOfTheHill => {
+                for color in &[WHITE, BLACK] {
+                    if let Some(king_pos) = board.get_king_pos(*color) {
+                        if HILL.contains(&king_pos) {
+                            return Some(GameResult::Victory(*color, WinReason::Variant));
+                        }
+                    }
+                }
+                None
+            }
+            Self::ThreeCheck => {
+                for color in &[WHITE, BLACK] {
+                    if board.get_checks_given(*color) >= CHECKS_TO_WIN {
+                        return Some(GameResult::Victory(*color, WinReason::Variant));
+                    }
+                }
+                None
+            }
+            Self::RacingKings => {
+                let reached_goal = |color| match board.get_king_pos(color) {
+                    Some(king_pos) => king_pos.get_row() == 7,
+                    None => false,
+                };
+
+                match (reached_goal(WHITE), reached_goal(BLACK)) {
+                    (true, true) => Some(GameResult::Draw(DrawReason::Variant)),
+                    (false, true) => Some(GameResult::Victory(BLACK, WinReason::Variant)),
+                    (true, false) => {
+                        // Black gets one more move to catch up with White.
+                        let black_can_reach_goal = board.get_turn_color() == BLACK
+                            && match board.get_king_pos(BLACK) {
+                                Some(king_pos) => board
+                                    .get_legal_piece_moves(BLACK)
+                                    .into_iter()
+                                    .any(|m| match m {
+                                        Move::Piece(from, to) => {
+                                            from == king_pos && to.get_row() == 7
+                                        }
+                                        _ => false,
+                                    }),
+                                None => false,
+                            };
+
+                        if black_can_reach_goal {
+                            None
+                        } else {
+                            Some(GameResult::Victory(WHITE, WinReason::Variant))
+                        }
+                    }
+                    (false, false) => None,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(mut board: Board, moves: &[Move]) -> GameResult {
+        for (i, m) in moves.iter().enumerate() {
+            match board.play_move(*m) {
+                GameResult::Continuing(next_board) => board = next_board,
+                result if i == moves.len() - 1 => return result,
+                result => panic!("unexpected result {:?} after {}", result, m),
+            }
+        }
+        GameResult::Continuing(board)
+    }
+
+    #[test]
+    fn test_king_of_the_hill() {
+        let board = Variant::KingOfTheHill.starting_board();
+        let result = play(
+            board,
+            &[
+                Move::Piece(E2, E4),
+                Move::Piece(E7, E5),
+                Move::Piece(D2, D4),
+                Move::Piece(E5, D4),
+                Move::Piece(E1, E2),
+                Move::Piece(E8, E7),
+                Move::Piece(E2, D3),
+                Move::Piece(E7, E6),
+                Move::Piece(D3, D4),
+            ],
+        );
+        assert_eq!(result, GameResult::Victory(WHITE, WinReason::Variant));
+    }
+
+    #[test]
+    fn test_three_check() {
+        let board = Variant::ThreeCheck.starting_board();
+        let result = play(
+            board,
+            &[
+                Move::Piece(E2, E4),
+                Move::Piece(F7, F6),
+                Move::Piece(D1, H5),
+                Move::Piece(G7, G6),
+                Move::Piece(H5, G6),
+                Move::Piece(H7, G6),
+                Move::Piece(F1, C4),
+                Move::Piece(D7, D5),
+                Move::Piece(C4, D5),
+                Move::Piece(D8, D5),
+                Move::Piece(E4, D5),
+                Move::Piece(E8, D8),
+                Move::Piece(D5, D6),
+                Move::Piece(C8, D7),
+                Move::Piece(D6, C7),
+            ],
+        );
+        assert_eq!(result, GameResult::Victory(WHITE, WinReason::Variant));
+    }
+
+    #[test]
+    fn test_horde() {
+        let board = Variant::Horde.starting_board();
+        assert_eq!(board.get_variant(), Variant::Horde);
+        assert_eq!(board.get_king_pos(WHITE), None);
+
+        // Black wins by capturing the last white piece
+        let board = BoardBuilder::default()
+            .piece(Piece::Pawn(WHITE, A5))
+            .piece(Piece::King(BLACK, B6))
+            .set_turn(BLACK)
+            .set_variant(Variant::Horde)
+            .build();
+        assert_eq!(
+            board.play_move(Move::Piece(B6, A5)),
+            GameResult::Victory(BLACK, WinReason::Variant)
+        );
+
+        // White's pawns on the first rank can move two squares
+        let board = BoardBuilder::default()
+            .piece(Piece::Pawn(WHITE, A1))
+            .piece(Piece::King(BLACK, H8))
+            .set_variant(Variant::Horde)
+            .build();
+        assert!(board.get_legal_moves().contains(&Move::Piece(A1, A3)));
+    }
+
+    #[test]
+    fn test_atomic() {
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Knight(WHITE, D4))
+            .piece(Piece::Pawn(BLACK, F6))
+            .piece(Piece::Rook(BLACK, F7))
+            .piece(Piece::King(BLACK, G8))
+            .set_variant(Variant::Atomic)
+            .build();
+
+        // Capturing the pawn explodes the rook and the black king
+        assert_eq!(
+            board.play_move(Move::Piece(D4, E6)),
+            GameResult::Continuing(board.apply_move(Move::Piece(D4, E6)).change_turn())
+        );
+        let board = board.apply_move(Move::Piece(D4, E6)).change_turn();
+        let board = board.apply_move(Move::Piece(F7, F8)).change_turn();
+        assert_eq!(
+            board.play_move(Move::Piece(E6, F8)),
+            GameResult::Victory(WHITE, WinReason::Variant)
+        );
+
+        // Kings cannot capture, and cannot explode themselves
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(WHITE, D1))
+            .piece(Piece::Knight(BLACK, E2))
+            .piece(Piece::King(BLACK, E8))
+            .set_variant(Variant::Atomic)
+            .build();
+        assert_eq!(
+            board.play_move(Move::Piece(E1, E2)),
+            GameResult::IllegalMove(Move::Piece(E1, E2))
+        );
+        assert_eq!(
+            board.play_move(Move::Piece(D1, D2)),
+            GameResult::Continuing(board.apply_move(Move::Piece(D1, D2)).change_turn())
+        );
+
+        // Kings that are touching cannot be checked
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E4))
+            .piece(Piece::King(BLACK, E5))
+            .piece(Piece::Rook(BLACK, A4))
+            .set_variant(Variant::Atomic)
+            .build();
+        assert!(!board.is_in_check(WHITE));
+    }
+
+    #[test]
+    fn test_antichess() {
+        let board = Variant::Antichess.starting_board();
+        let board = board.apply_move(Move::Piece(E2, E4)).change_turn();
+        let board = board.apply_move(Move::Piece(D7, D5)).change_turn();
+
+        // White is forced to capture
+        assert_eq!(board.get_legal_moves(), vec![Move::Piece(E4, D5)]);
+        assert_eq!(
+            board.play_move(Move::Piece(G1, F3)),
+            GameResult::IllegalMove(Move::Piece(G1, F3))
+        );
+
+        // Losing every piece wins
+        let board = BoardBuilder::default()
+            .piece(Piece::Knight(WHITE, G6))
+            .piece(Piece::Pawn(BLACK, H7))
+            .set_turn(BLACK)
+            .set_variant(Variant::Antichess)
+            .build();
+        assert_eq!(
+            board.play_move(Move::Piece(H7, H6)),
+            GameResult::IllegalMove(Move::Piece(H7, H6))
+        );
+        assert_eq!(
+            board.play_move(Move::Piece(H7, G6)),
+            GameResult::Victory(WHITE, WinReason::Variant)
+        );
+
+        // Having no legal moves wins
+        let board = BoardBuilder::default()
+            .piece(Piece::Pawn(WHITE, A3))
+            .piece(Piece::Pawn(BLACK, A5))
+            .set_turn(BLACK)
+            .set_variant(Variant::Antichess)
+            .build();
+        assert_eq!(
+            board.play_move(Move::Piece(A5, A4)),
+            GameResult::Victory(WHITE, WinReason::Variant)
+        );
+
+        // Pawns can promote to kings
+        let board = BoardBuilder::default()
+            .piece(Piece::Pawn(WHITE, A7))
+            .piece(Piece::Pawn(BLACK, H7))
+            .set_variant(Variant::Antichess)
+            .build();
+        let promotion = Move::Promotion(A7, A8, Piece::King(WHITE, Position::new(-1, -1)));
+        assert!(board.get_legal_moves().contains(&promotion));
+        assert!(matches!(
+            board.play_move(promotion),
+            GameResult::Continuing(_)
+        ));
+    }
+
+    #[test]
+    fn test_crazyhouse() {
+        let board = Variant::Crazyhouse.starting_board();
+        let result = play(
+            board,
+            &[
+                Move::Piece(E2, E4),
+                Move::Piece(D7, D5),
+                Move::Piece(E4, D5),
+                Move::Piece(D8, D5),
+            ],
+        );
+        let board = match result {
+            GameResult::Continuing(board) => board,
+            result => panic!("unexpected result {:?}", result),
+        };
+        let pawn = Piece::Pawn(WHITE, Position::new(-1, -1));
+        assert_eq!(board.get_pocket(WHITE).count(pawn), 1);
+        assert_eq!(board.get_pocket(BLACK).count(pawn), 1);
+
+        // Drop a pawn, but never onto the last rank
+        assert!(board.get_legal_moves().contains(&Move::Drop(pawn, E4)));
+        assert_eq!(
+            board.play_move(Move::Drop(pawn, A8)),
+            GameResult::IllegalMove(Move::Drop(pawn, A8))
+        );
+        let board = match board.play_move(Move::Drop(pawn, E4)) {
+            GameResult::Continuing(board) => board,
+            result => panic!("unexpected result {:?}", result),
+        };
+        assert!(board.get_pocket(WHITE).is_empty());
+        assert_eq!(board.get_piece(E4), Some(Piece::Pawn(WHITE, E4)));
+
+        // Drops are the same move whatever the color of the piece
+        let drop = Move::try_from(String::from("p@e6")).unwrap();
+        assert_eq!(drop, Move::Drop(Piece::Pawn(BLACK, E6), E6));
+        assert!(board.get_legal_moves().contains(&drop));
+
+        // Drops can block checks
+        let knight = Piece::Knight(WHITE, Position::new(-1, -1));
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Rook(BLACK, E8))
+            .piece(Piece::King(BLACK, A8))
+            .pocket(Piece::Knight(WHITE, Position::new(-1, -1)))
+            .set_variant(Variant::Crazyhouse)
+            .build();
+        assert!(board.is_in_check(WHITE));
+        assert!(board.get_legal_moves().contains(&Move::Drop(knight, E4)));
+        assert!(!board.get_legal_moves().contains(&Move::Drop(knight, A4)));
+
+        // Promoted pieces revert to pawns when captured
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, E1))
+            .piece(Piece::Pawn(WHITE, B7))
+            .piece(Piece::Rook(BLACK, A8))
+            .piece(Piece::King(BLACK, H8))
+            .set_variant(Variant::Crazyhouse)
+            .build();
+        let board = board.apply_move(Move::Piece(B7, B8)).change_turn();
+        assert!(board.is_promoted(B8));
+        let board = board.apply_move(Move::Piece(A8, B8)).change_turn();
+        assert!(!board.is_promoted(B8));
+        assert_eq!(board.get_pocket(BLACK).count(pawn), 1);
+        assert_eq!(board.get_pocket(BLACK).get_pieces(BLACK).len(), 1);
+    }
+
+    #[test]
+    fn test_racing_kings() {
+        let board = Variant::RacingKings.starting_board();
+        // Giving check is illegal
+        for m in board.get_legal_moves() {
+            if let GameResult::Continuing(next) = board.play_move(m) {
+                assert!(!next.is_in_check(BLACK));
+            }
+        }
+
+        // Black gets a final move to draw the race
+        let board = BoardBuilder::default()
+            .piece(Piece::King(WHITE, H7))
+            .piece(Piece::King(BLACK, A7))
+            .set_variant(Variant::RacingKings)
+            .build();
+        let board = match board.play_move(Move::Piece(H7, H8)) {
+            GameResult::Continuing(board) => board,
+            result => panic!("unexpected result {:?}", result),
+        };
+        assert_eq!(
+            board.play_move(Move::Piece(A7, A8)),
+            GameResult::Draw(DrawReason::Variant)
+        );
+        assert_eq!(
+            board.play_move(Move::Piece(A7, B7)),
+            GameResult::Victory(WHITE, WinReason::Variant)
+        );
+    }
+}